@@ -123,10 +123,10 @@ impl ErrorHandler {
                 ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
-            ContractError::InvalidStateTransition => (
+            ContractError::InvalidRecipientAddress => (
                 8,
-                SorobanString::from_str(env, "Invalid state transition attempted"),
-                ErrorCategory::State,
+                SorobanString::from_str(env, "Funds cannot be sent to this address"),
+                ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
             ContractError::NoFeesToWithdraw => (
@@ -168,30 +168,34 @@ impl ErrorHandler {
                 ErrorSeverity::Low,
             ),
             
-            // Authorization Errors (15-18)
+            // Authorization Errors (15)
             ContractError::Unauthorized => (
                 15,
                 SorobanString::from_str(env, "Unauthorized: admin access required"),
                 ErrorCategory::Authorization,
                 ErrorSeverity::Medium,
             ),
-            ContractError::AdminAlreadyExists => (
+            
+            // Remittance Cap Errors (16-17)
+            ContractError::TooManyActiveRemittances => (
                 16,
-                SorobanString::from_str(env, "Admin already exists"),
-                ErrorCategory::Resource,
+                SorobanString::from_str(env, "Too many active remittances"),
+                ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
-            ContractError::AdminNotFound => (
+            ContractError::NotAcceptingRemittances => (
                 17,
-                SorobanString::from_str(env, "Admin not found"),
-                ErrorCategory::Resource,
+                SorobanString::from_str(env, "Contract is not accepting new remittances"),
+                ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
-            ContractError::CannotRemoveLastAdmin => (
+            
+            // Signed Payout Errors (18)
+            ContractError::NonceReused => (
                 18,
-                SorobanString::from_str(env, "Cannot remove the last admin"),
-                ErrorCategory::State,
-                ErrorSeverity::Low,
+                SorobanString::from_str(env, "Nonce has already been used"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Medium,
             ),
             
             // Token Whitelist Errors (19-20)
@@ -208,60 +212,102 @@ impl ErrorHandler {
                 ErrorSeverity::Low,
             ),
             
-            // Migration Errors (21-23)
-            ContractError::InvalidMigrationHash => (
-                21,
-                SorobanString::from_str(env, "Migration hash verification failed"),
-                ErrorCategory::System,
-                ErrorSeverity::High,
-            ),
-            ContractError::MigrationInProgress => (
+            // Token Recovery Errors (22)
+            ContractError::CannotRecoverSettlementToken => (
                 22,
-                SorobanString::from_str(env, "Migration already in progress"),
-                ErrorCategory::State,
+                SorobanString::from_str(env, "Token cannot be recovered"),
+                ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::InvalidMigrationBatch => (
+            
+            // Claim Errors (23)
+            ContractError::InvalidClaimCode => (
                 23,
-                SorobanString::from_str(env, "Migration batch is invalid"),
-                ErrorCategory::Validation,
+                SorobanString::from_str(env, "Claim code is invalid"),
+                ErrorCategory::Authorization,
                 ErrorSeverity::Low,
             ),
             
-            // Rate Limiting Errors (24)
-            ContractError::DailySendLimitExceeded => (
+            // Allowance Errors (24)
+            ContractError::AllowanceExceeded => (
                 24,
-                SorobanString::from_str(env, "Daily send limit exceeded"),
+                SorobanString::from_str(env, "Amount exceeds the remaining allowance"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            
+            // Sender Allowlist Errors (26)
+            ContractError::SenderNotAllowed => (
+                26,
+                SorobanString::from_str(env, "Sender is not allowed to create remittances"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            
+            // Conditional Remittance Errors (30-31)
+            ContractError::ConditionNotMet => (
+                30,
+                SorobanString::from_str(env, "Remittance condition has not been met"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::OracleNotSet => (
+                31,
+                SorobanString::from_str(env, "No oracle configured"),
                 ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
             
-            // Arithmetic Errors (25-26)
-            ContractError::Overflow => (
-                25,
-                SorobanString::from_str(env, "Arithmetic overflow occurred"),
+            // Token Migration Errors (32)
+            ContractError::PendingRemittancesExist => (
+                32,
+                SorobanString::from_str(env, "Balances remain in the settlement token"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Refund Request Errors (33-34)
+            ContractError::RefundAlreadyRequested => (
+                33,
+                SorobanString::from_str(env, "Refund already requested"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::NoRefundRequested => (
+                34,
+                SorobanString::from_str(env, "No refund has been requested"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Migration Errors (21)
+            ContractError::InvalidMigrationHash => (
+                21,
+                SorobanString::from_str(env, "Migration hash verification failed"),
                 ErrorCategory::System,
                 ErrorSeverity::High,
             ),
-            ContractError::Underflow => (
-                26,
-                SorobanString::from_str(env, "Arithmetic underflow occurred"),
+            
+            // Arithmetic Errors (25)
+            ContractError::Overflow => (
+                25,
+                SorobanString::from_str(env, "Arithmetic overflow occurred"),
                 ErrorCategory::System,
                 ErrorSeverity::High,
             ),
             
-            // Data Integrity Errors (27-30)
+            // Data Integrity Errors (27-29)
             ContractError::NetSettlementValidationFailed => (
                 27,
                 SorobanString::from_str(env, "Net settlement validation failed"),
                 ErrorCategory::System,
                 ErrorSeverity::High,
             ),
-            ContractError::SettlementCounterOverflow => (
+            ContractError::InsufficientContractBalance => (
                 28,
-                SorobanString::from_str(env, "Settlement counter overflow"),
-                ErrorCategory::System,
-                ErrorSeverity::High,
+                SorobanString::from_str(env, "Contract balance cannot cover the transfer"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
             ),
             ContractError::InvalidBatchSize => (
                 29,
@@ -269,46 +315,128 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::DataCorruption => (
-                30,
-                SorobanString::from_str(env, "Data corruption detected"),
-                ErrorCategory::System,
-                ErrorSeverity::High,
+            
+            // String/Symbol Errors (35)
+            ContractError::InvalidSymbol => (
+                35,
+                SorobanString::from_str(env, "Symbol is invalid or malformed"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
             ),
             
-            // Collection Errors (31-33)
-            ContractError::IndexOutOfBounds => (
-                31,
-                SorobanString::from_str(env, "Index out of bounds"),
+            // Liquidity Pool Errors (36-37)
+            ContractError::InsufficientPoolLiquidity => (
+                36,
+                SorobanString::from_str(env, "Agent pool cannot cover the transfer"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::FxRateNotSet => (
+                37,
+                SorobanString::from_str(env, "FX rate not configured for token"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            
+            // Cancellation Timing Errors (38)
+            ContractError::CancelTooSoon => (
+                38,
+                SorobanString::from_str(env, "Remittance cannot be cancelled yet"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Batch Eligibility Errors (39)
+            ContractError::AgentNotBatchEligible => (
+                39,
+                SorobanString::from_str(env, "Agent not eligible for batch settlement"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Fee Cap Errors (40)
+            ContractError::FeeAboveCap => (
+                40,
+                SorobanString::from_str(env, "Fee exceeds the configured cap"),
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::EmptyCollection => (
-                32,
-                SorobanString::from_str(env, "Collection is empty"),
+            
+            // Expiry Update Errors (41)
+            ContractError::InvalidExpiry => (
+                41,
+                SorobanString::from_str(env, "Expiry must be later than current expiry"),
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::KeyNotFound => (
-                33,
-                SorobanString::from_str(env, "Key not found in map"),
-                ErrorCategory::Resource,
+            
+            // Rolling Limit Errors (42)
+            ContractError::RollingLimitExceeded => (
+                42,
+                SorobanString::from_str(env, "Rolling transfer limit exceeded"),
+                ErrorCategory::State,
                 ErrorSeverity::Low,
             ),
             
-            // String/Symbol Errors (34-35)
-            ContractError::StringConversionFailed => (
-                34,
-                SorobanString::from_str(env, "String conversion failed"),
+            // Agent Capacity Errors (43)
+            ContractError::AgentAtCapacity => (
+                43,
+                SorobanString::from_str(env, "Agent is at its pending remittance capacity"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Balance Errors (44)
+            ContractError::InsufficientBalance => (
+                44,
+                SorobanString::from_str(env, "Insufficient sender balance"),
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::InvalidSymbol => (
-                35,
-                SorobanString::from_str(env, "Symbol is invalid or malformed"),
+            
+            // Payout Errors (45)
+            ContractError::PayoutTooSmall => (
+                45,
+                SorobanString::from_str(env, "Payout is below the configured minimum"),
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
+            
+            // Agent Registration Errors (46-47)
+            ContractError::RegistrationClosed => (
+                46,
+                SorobanString::from_str(env, "Agent self-registration is closed"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AgentAlreadyRegistered => (
+                47,
+                SorobanString::from_str(env, "Agent is already registered"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            
+            // Agent Freeze Errors (48)
+            ContractError::AgentFrozen => (
+                48,
+                SorobanString::from_str(env, "Agent is frozen for new remittances"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            
+            // Agent Stake Errors (49-50)
+            ContractError::InsufficientStake => (
+                49,
+                SorobanString::from_str(env, "Insufficient agent stake"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::StakeLocked => (
+                50,
+                SorobanString::from_str(env, "Agent stake is still locked"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
         }
     }
     
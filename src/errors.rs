@@ -15,7 +15,8 @@ pub enum ContractError {
     // ═══════════════════════════════════════════════════════════════════════════
     
     /// Contract has already been initialized.
-    /// Cause: Attempting to call initialize() on an already initialized contract.
+    /// Cause: Attempting to call initialize() on an already initialized contract,
+    /// or migrating storage that is already at the current schema version.
     AlreadyInitialized = 1,
     
     /// Contract has not been initialized yet.
@@ -43,19 +44,20 @@ pub enum ContractError {
     RemittanceNotFound = 6,
     
     /// Invalid remittance status for this operation.
-    /// Cause: Attempting operation on remittance in wrong status (e.g., settling completed remittance).
+    /// Cause: Attempting operation on remittance in wrong status (e.g., settling completed remittance),
+    /// including a status transition the lifecycle does not allow.
     InvalidStatus = 7,
     
-    /// Invalid state transition attempted.
-    /// Cause: Attempting to transition remittance to invalid state.
-    InvalidStateTransition = 8,
+    /// Funds cannot be sent to this address.
+    /// Cause: Naming the contract itself as a fee, payout or treasury destination.
+    InvalidRecipientAddress = 8,
     
     /// No fees available to withdraw.
     /// Cause: Attempting to withdraw fees when accumulated fees is zero or negative.
     NoFeesToWithdraw = 9,
     
     /// Invalid address format or validation failed.
-    /// Cause: Address does not meet validation requirements.
+    /// Cause: Using the contract itself as an admin, sender or agent.
     InvalidAddress = 10,
     
     // ═══════════════════════════════════════════════════════════════════════════
@@ -79,25 +81,32 @@ pub enum ContractError {
     RateLimitExceeded = 14,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Authorization Errors (15-17)
+    // Authorization Errors (15)
     // ═══════════════════════════════════════════════════════════════════════════
     
     /// Caller is not authorized to perform admin operations.
     /// Cause: Non-admin attempting to perform admin-only operations.
     Unauthorized = 15,
     
-    /// Admin address already exists in the system.
-    /// Cause: Attempting to add an admin that is already registered.
-    AdminAlreadyExists = 16,
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Remittance Cap Errors (16-17)
+    // ═══════════════════════════════════════════════════════════════════════════
     
-    /// Admin address does not exist in the system.
-    /// Cause: Attempting to remove an admin that is not registered.
-    AdminNotFound = 17,
+    /// Contract already holds as many open remittances as it accepts.
+    /// Cause: Creating a remittance while the active count has reached max_active_remittances.
+    TooManyActiveRemittances = 16,
     
-    /// Cannot remove the last admin from the system.
-    /// Cause: Attempting to remove the only remaining admin.
-
-    CannotRemoveLastAdmin = 18,
+    /// Contract is not accepting new remittances.
+    /// Cause: Creating a remittance after the admin turned off set_accepting_new; existing ones still settle.
+    NotAcceptingRemittances = 17,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Signed Payout Errors (18)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent settlement nonce has already been used.
+    /// Cause: Relaying confirm_payout_signed with a nonce the agent already signed a payout with.
+    NonceReused = 18,
     
     // ═══════════════════════════════════════════════════════════════════════════
     // Token Whitelist Errors (19-20)
@@ -112,106 +121,225 @@ pub enum ContractError {
     TokenAlreadyWhitelisted = 20,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Migration Errors (21-23)
+    // Token Recovery Errors (22)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// Migration hash verification failed.
-    /// Cause: Snapshot hash doesn't match computed hash (data tampering or corruption).
-    InvalidMigrationHash = 21,
+    /// Token backs balances held for users and cannot be recovered.
+    /// Cause: Calling recover_token with the settlement token or a whitelisted (pool) token.
+    CannotRecoverSettlementToken = 22,
     
-    /// Migration already in progress or completed.
-    /// Cause: Attempting to start migration when one is already active.
-    MigrationInProgress = 22,
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Claim Errors (23)
+    // ═══════════════════════════════════════════════════════════════════════════
     
-
-    CannotRemoveLastAdmin = 17,
-
-    /// Token is not whitelisted for use in the system.
-    /// Cause: Attempting to initialize contract with non-whitelisted token.
-    TokenNotWhitelisted = 18,
-
-    /// Token is already whitelisted in the system.
-    /// Cause: Attempting to add a token that is already whitelisted.
-    TokenAlreadyWhitelisted = 19,
-
-    /// Migration hash verification failed.
-    /// Cause: Snapshot hash doesn't match computed hash (data tampering or corruption).
-    InvalidMigrationHash = 20,
-
-    /// Migration already in progress or completed.
-    /// Cause: Attempting to start migration when one is already active.
-    MigrationInProgress = 21,
-
-
-    /// Migration batch out of order or invalid.
-    /// Cause: Importing batches in wrong order or invalid batch number.
-    InvalidMigrationBatch = 23,
+    /// Claim code does not unlock this remittance.
+    /// Cause: Claiming with a code or recipient that does not match the committed hash,
+    /// or claiming a remittance that was not created with create_claimable.
+    InvalidClaimCode = 23,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Rate Limiting Errors (24)
+    // Allowance Errors (24)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// Daily send limit exceeded for this user.
-    /// Cause: User's total transfers in the last 24 hours exceed the configured limit.
-    DailySendLimitExceeded = 24,
+    /// Amount exceeds the sender's remaining allowance for this agent.
+    /// Cause: Calling create_remittance_from_allowance for more than approve_agent_allowance left.
+    AllowanceExceeded = 24,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Sender Allowlist Errors (26)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Sender is not on the allowlist.
+    /// Cause: Creating a remittance from an address not approved with allow_sender while the allowlist is enabled.
+    SenderNotAllowed = 26,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Conditional Remittance Errors (30-31)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Conditional remittance is still waiting on its oracle.
+    /// Cause: Settling a conditional remittance before the oracle has marked its condition met.
+    ConditionNotMet = 30,
+    
+    /// No oracle has been configured.
+    /// Cause: Calling set_condition before the admin has designated an oracle with set_oracle.
+    OracleNotSet = 31,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Token Migration Errors (32)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Contract still holds balances in the settlement token.
+    /// Cause: Calling migrate_token while escrow, fees, stakes, yield or agent pool balances remain in the old token.
+    PendingRemittancesExist = 32,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Refund Request Errors (33-34)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// A refund has already been requested for this remittance.
+    /// Cause: Calling request_refund again before the agent or an admin resolves the open request.
+    RefundAlreadyRequested = 33,
+    
+    /// No refund has been requested for this remittance.
+    /// Cause: Calling approve_refund or deny_refund without an open request.
+    NoRefundRequested = 34,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Migration Errors (21)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Migration hash verification failed.
+    /// Cause: Snapshot hash doesn't match computed hash (data tampering or corruption).
+    InvalidMigrationHash = 21,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Arithmetic Errors (25-26)
+    // Arithmetic Errors (25)
     // ═══════════════════════════════════════════════════════════════════════════
     
     /// Arithmetic overflow occurred in calculation.
-    /// Cause: Result of arithmetic operation exceeds maximum value for type.
+    /// Cause: Result of arithmetic operation exceeds maximum value for type,
+    /// including the settlement counter passing u64::MAX.
     Overflow = 25,
     
-    /// Arithmetic underflow occurred in calculation.
-    /// Cause: Result of arithmetic operation is less than minimum value for type.
-    Underflow = 26,
-    
     // ═══════════════════════════════════════════════════════════════════════════
-    // Data Integrity Errors (27-30)
+    // Data Integrity Errors (27-29)
     // ═══════════════════════════════════════════════════════════════════════════
     
     /// Net settlement validation failed.
-    /// Cause: Net settlement calculations don't preserve fees or amounts correctly.
+    /// Cause: Net settlement calculations don't preserve fees or amounts correctly,
+    /// or a pair's fees and holdbacks exceed its net transfer.
     NetSettlementValidationFailed = 27,
     
-    /// Settlement counter overflow.
-    /// Cause: Settlement counter would exceed u64::MAX (extremely unlikely).
-    SettlementCounterOverflow = 28,
+    /// Contract balance cannot cover the requested transfer.
+    /// Cause: Withdrawing fees, sweeping yield or paying out more than the contract holds
+    /// beyond outstanding liabilities, agent stake and the liquidity floor.
+    InsufficientContractBalance = 28,
     
     /// Invalid batch size.
-    /// Cause: Batch size is zero or exceeds maximum allowed.
+    /// Cause: Batch size is zero or exceeds maximum allowed (e.g. more than MAX_BATCH_SIZE entries to a bulk operation,
+    /// or a migration range that is empty or longer than MAX_MIGRATION_BATCH_SIZE).
     InvalidBatchSize = 29,
     
-    /// Data corruption detected.
-    /// Cause: Storage data is corrupted or inconsistent.
-    DataCorruption = 30,
+    // ═══════════════════════════════════════════════════════════════════════════
+    // String/Symbol Errors (35)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Symbol is invalid or malformed.
+    /// Cause: Symbol contains invalid characters or exceeds length limits.
+    InvalidSymbol = 35,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Collection Errors (31-33)
+    // Liquidity Pool Errors (36-37)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// Index out of bounds.
-    /// Cause: Attempting to access collection element at invalid index.
-    IndexOutOfBounds = 31,
+    /// Agent pool cannot cover the requested transfer.
+    /// Cause: Settling in a pool token when the agent's pre-funded balance is below the converted payout amount,
+    /// or withdrawing more than the agent has pre-funded.
+    InsufficientPoolLiquidity = 36,
     
-    /// Collection is empty.
-    /// Cause: Attempting operation on empty collection that requires elements.
-    EmptyCollection = 32,
+    /// No FX rate is configured for the payout token or quote currency.
+    /// Cause: Settling from a pool in a token the admin has not set a conversion rate for,
+    /// or quoting in a currency without a rate. There is no separate currency registry,
+    /// so an unknown currency code is reported this way too.
+    FxRateNotSet = 37,
     
-    /// Key not found in map.
-    /// Cause: Attempting to access map value with non-existent key.
-    KeyNotFound = 33,
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Cancellation Timing Errors (38)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Remittance cannot be cancelled or reclaimed yet.
+    /// Cause: Cancelling before min_cancel_age_seconds has elapsed since creation, or reclaiming a
+    /// remittance that has no expiry or whose expiry plus grace window has not passed.
+    CancelTooSoon = 38,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // String/Symbol Errors (34-35)
+    // Batch Eligibility Errors (39)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// String conversion failed.
-    /// Cause: Unable to convert between string types or invalid string format.
-    StringConversionFailed = 34,
+    /// Agent is not approved for batch settlement.
+    /// Cause: Including a remittance in a batch whose agent has not been marked batch-eligible by an admin.
+    AgentNotBatchEligible = 39,
     
-    /// Symbol is invalid or malformed.
-    /// Cause: Symbol contains invalid characters or exceeds length limits.
-    InvalidSymbol = 35,
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Cap Errors (40)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Fee exceeds the configured ceiling.
+    /// Cause: Setting a platform, scheduled, per-token or tier fee above max_allowed_fee_bps,
+    /// lowering the ceiling below a fee already configured, or a resolved fee above the sender's max_fee.
+    FeeAboveCap = 40,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Expiry Update Errors (41)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// New expiry is not later than the current one.
+    /// Cause: Extending a remittance to an expiry that is earlier than or equal to its current expiry, or adding an expiry to one that never expires.
+    InvalidExpiry = 41,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Rolling Limit Errors (42)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Sender volume exceeds a rolling window limit.
+    /// Cause: Creating a remittance that would push the sender's trailing 7-day/30-day (or other configured) volume above its ceiling.
+    RollingLimitExceeded = 42,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Capacity Errors (43)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent already holds as many pending remittances as it accepts.
+    /// Cause: Creating a remittance for an agent whose pending count has reached its max_pending.
+    AgentAtCapacity = 43,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Balance Errors (44)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Sender balance is below the remittance amount.
+    /// Cause: Creating a remittance for more than the sender currently holds in the settlement token.
+    InsufficientBalance = 44,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Payout Errors (45)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Net payout is below the configured minimum.
+    /// Cause: Settling a remittance whose amount minus fee is under min_payout.
+    PayoutTooSmall = 45,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Registration Errors (46-47)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent self-registration is disabled.
+    /// Cause: Calling self_register_agent while open registration is off.
+    RegistrationClosed = 46,
+    
+    /// Agent is already registered.
+    /// Cause: Self-registering an address that is already an agent.
+    AgentAlreadyRegistered = 47,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Freeze Errors (48)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent is frozen for new inbound remittances.
+    /// Cause: Creating a remittance for an agent the admin has frozen with freeze_agent_inbound.
+    AgentFrozen = 48,
+    
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Stake Errors (49-50)
+    // ═══════════════════════════════════════════════════════════════════════════
+    
+    /// Agent stake is missing or too small for this operation.
+    /// Cause: Creating a remittance above the stake threshold for an unstaked agent,
+    /// or slashing or unstaking more than the agent has staked.
+    InsufficientStake = 49,
+    
+    /// Agent stake is still in its cooldown period.
+    /// Cause: Unstaking before the cooldown since the last deposit has elapsed.
+    StakeLocked = 50,
 }
@@ -4,7 +4,9 @@
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+use crate::PauseFlags;
 
 /// Schema version for event structure compatibility
 const SCHEMA_VERSION: u32 = 1;
@@ -47,6 +49,46 @@ pub fn emit_unpaused(env: &Env, admin: Address) {
     );
 }
 
+/// Emits an event when the volume circuit breaker pauses the contract.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Operation volume that tripped the breaker
+/// * `threshold` - Configured auto-pause threshold
+pub fn emit_auto_paused(env: &Env, amount: i128, threshold: i128) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("autopause")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            amount,
+            threshold,
+        ),
+    );
+}
+
+/// Emits an event when an admin changes the per-operation pause flags.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who updated the flags
+/// * `flags` - New pause switches
+pub fn emit_pause_flags_updated(env: &Env, admin: Address, flags: PauseFlags) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("pause_set")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            flags,
+        ),
+    );
+}
+
 // ── Remittance Events ──────────────────────────────────────────────
 
 /// Emits an event when a new remittance is created.
@@ -59,6 +101,10 @@ pub fn emit_unpaused(env: &Env, admin: Address) {
 /// * `agent` - Address of the assigned agent
 /// * `amount` - Total remittance amount
 /// * `fee` - Platform fee deducted
+/// * `fee_bps` - Fee rate the fee was resolved from, after any token or tier override
+/// * `tag` - Client-defined reporting category (0 means untagged)
+/// * `integrator_fee` - Integrator fee deducted
+#[allow(clippy::too_many_arguments)]
 pub fn emit_remittance_created(
     env: &Env,
     remittance_id: u64,
@@ -66,6 +112,8 @@ pub fn emit_remittance_created(
     agent: Address,
     amount: i128,
     fee: i128,
+    fee_bps: u32,
+    tag: u32,
     integrator_fee: i128,
 ) {
     env.events().publish(
@@ -79,11 +127,55 @@ pub fn emit_remittance_created(
             agent,
             amount,
             fee,
+            fee_bps,
+            tag,
             integrator_fee,
         ),
     );
 }
 
+/// Emits an event when an agent accepts a pending remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the accepted remittance
+/// * `agent` - Address of the agent who accepted
+pub fn emit_remittance_accepted(env: &Env, remittance_id: u64, agent: Address) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("accepted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when the oracle resolves a conditional remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the conditional remittance
+/// * `oracle` - Oracle that set the condition
+/// * `met` - Whether the condition is now met
+pub fn emit_condition_set(env: &Env, remittance_id: u64, oracle: Address, met: bool) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("condition")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            oracle,
+            met,
+        ),
+    );
+}
+
 /// Emits an event when a remittance payout is completed.
 ///
 /// # Arguments
@@ -92,11 +184,13 @@ pub fn emit_remittance_created(
 /// * `remittance_id` - ID of the completed remittance
 /// * `agent` - Address of the agent who received the payout
 /// * `amount` - Payout amount (after fee deduction)
+/// * `nonce` - The agent's settlement nonce for this payout
 pub fn emit_remittance_completed(
     env: &Env,
     remittance_id: u64,
     agent: Address,
     amount: i128,
+    nonce: u64,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("complete")),
@@ -107,6 +201,59 @@ pub fn emit_remittance_completed(
             remittance_id,
             agent,
             amount,
+            nonce,
+        ),
+    );
+}
+
+/// Emits an event when a remittance's held-back payout is released.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the completed remittance
+/// * `agent` - Agent who confirmed delivery
+/// * `amount` - Held-back amount paid out
+pub fn emit_holdback_released(env: &Env, remittance_id: u64, agent: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("holdback")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a settlement attempt is rejected.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance that failed to settle
+/// * `agent` - Agent who attempted the settlement
+/// * `error` - Code of the `ContractError` that rejected the attempt
+/// * `failed_attempts` - Rejected attempts so far, including this one
+pub fn emit_settlement_failed(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    error: u32,
+    failed_attempts: u32,
+) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("failed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            error,
+            failed_attempts,
         ),
     );
 }
@@ -119,11 +266,13 @@ pub fn emit_remittance_completed(
 /// * `remittance_id` - ID of the cancelled remittance
 /// * `sender` - Address of the sender who received the refund
 /// * `amount` - Refunded amount
+/// * `reason` - Client-defined cancellation reason code (0 for unspecified)
 pub fn emit_remittance_cancelled(
     env: &Env,
     remittance_id: u64,
     sender: Address,
     amount: i128,
+    reason: u32,
 ) {
     env.events().publish(
         (symbol_short!("remit"), symbol_short!("cancel")),
@@ -134,147 +283,935 @@ pub fn emit_remittance_cancelled(
             remittance_id,
             sender,
             amount,
+            reason,
         ),
     );
 }
 
-// ── Agent Events ───────────────────────────────────────────────────
-
-/// Emits an event when a new agent is registered.
+/// Emits an event when a sender asks for a pending remittance to be refunded.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the registered agent
-pub fn emit_agent_registered(env: &Env, agent: Address) {
+/// * `remittance_id` - ID of the remittance
+/// * `sender` - Address of the sender requesting the refund
+pub fn emit_refund_requested(env: &Env, remittance_id: u64, sender: Address) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("register")),
+        (symbol_short!("refund"), symbol_short!("requested")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            agent,
+            remittance_id,
+            sender,
         ),
     );
 }
 
-/// Emits an event when an agent is removed.
+/// Emits an event when an agent rejects a pending remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Address of the removed agent
-pub fn emit_agent_removed(env: &Env, agent: Address) {
+/// * `remittance_id` - ID of the rejected remittance
+/// * `agent` - Agent who rejected it
+/// * `sender` - Address of the sender who received the refund
+/// * `amount` - Refunded amount
+pub fn emit_remittance_rejected(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    sender: Address,
+    amount: i128,
+) {
     env.events().publish(
-        (symbol_short!("agent"), symbol_short!("removed")),
+        (symbol_short!("remit"), symbol_short!("rejected")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
+            remittance_id,
             agent,
+            sender,
+            amount,
         ),
     );
 }
 
-// ── Fee Events ─────────────────────────────────────────────────────
+/// Emits an event when a refund request is approved and the sender is repaid.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the refunded remittance
+/// * `approver` - Agent or admin who approved the refund
+/// * `amount` - Refunded amount
+pub fn emit_refund_approved(env: &Env, remittance_id: u64, approver: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("refund"), symbol_short!("approved")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            approver,
+            amount,
+        ),
+    );
+}
 
-/// Emits an event when the platform fee is updated.
+/// Emits an event when a refund request is denied.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fee_bps` - New fee rate in basis points
-pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
+/// * `remittance_id` - ID of the remittance
+/// * `denier` - Agent or admin who denied the refund
+pub fn emit_refund_denied(env: &Env, remittance_id: u64, denier: Address) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("updated")),
+        (symbol_short!("refund"), symbol_short!("denied")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            fee_bps,
+            remittance_id,
+            denier,
         ),
     );
 }
 
-/// Emits an event when accumulated fees are withdrawn.
+/// Emits an event when a remittance is cancelled with both sender and agent consent.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `to` - Address that received the withdrawn fees
-/// * `amount` - Amount of fees withdrawn
-pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+/// * `remittance_id` - ID of the cancelled remittance
+/// * `sender` - Address of the sender who received the refund
+/// * `agent` - Agent who consented to the cancellation
+/// * `amount` - Refunded amount
+pub fn emit_mutual_cancelled(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+) {
     env.events().publish(
-        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (symbol_short!("remit"), symbol_short!("mutual")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            to,
+            remittance_id,
+            sender,
+            agent,
             amount,
         ),
     );
 }
 
-// ── Settlement Events ──────────────────────────────────────────────
-
-/// Emits a structured completion event when a settlement is finalized.
-///
-/// This event is emitted exactly once per completed settlement, after all state
-/// transitions are successfully committed. It includes sufficient identifiers to
-/// uniquely reference the finalized settlement.
+/// Emits an event when a keeper refunds expired remittances.
 ///
-/// # Guarantees
+/// # Arguments
 ///
-/// - **Exactly-Once Emission**: Event is emitted once and only once per settlement
-/// - **Post-Finalization**: Only emitted after all state changes are committed
-/// - **Unique Identification**: Includes remittance_id for unambiguous reference
-/// - **Deterministic**: Same settlement always produces same event
-/// - **No Re-entry**: Protected against duplicate emission on retries
+/// * `env` - The contract execution environment
+/// * `keeper` - Address that ran the sweep
+/// * `refunded` - Number of remittances refunded
+/// * `reward` - Total reward paid to the keeper
+pub fn emit_keeper_sweep(env: &Env, keeper: Address, refunded: u32, reward: i128) {
+    env.events().publish(
+        (symbol_short!("keeper"), symbol_short!("sweep")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            keeper,
+            refunded,
+            reward,
+        ),
+    );
+}
+
+/// Emits an event when a sender reclaims an expired remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - Unique ID of the finalized settlement
-/// * `sender` - Address of the sender
-/// * `receiver` - Address of the receiver (agent)
-/// * `asset` - Address of the token contract (e.g., USDC)
-/// * `amount` - Settlement amount transferred
+/// * `remittance_id` - ID of the reclaimed remittance
+/// * `sender` - Address of the sender who received the refund
+/// * `amount` - Refunded amount (always the full remittance amount)
+pub fn emit_remittance_reclaimed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("reclaim")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a sender extends a remittance's expiry.
 ///
-/// # Event Structure
+/// # Arguments
 ///
-/// Topic: `("settle", "complete")`
-/// Data: `(schema_version, ledger_sequence, timestamp, remittance_id, sender, receiver, asset, amount)`
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `sender` - Address of the sender who extended the expiry
+/// * `old_expiry` - Previous expiry timestamp
+/// * `new_expiry` - New expiry timestamp
+pub fn emit_expiry_extended(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    old_expiry: u64,
+    new_expiry: u64,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("extended")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            old_expiry,
+            new_expiry,
+        ),
+    );
+}
+
+/// Emits an event when a sender adjusts the amount of a pending remittance.
 ///
-/// # Usage
+/// # Arguments
 ///
-/// This function should only be called from `confirm_payout` after:
-/// 1. All validations pass
-/// 2. Token transfer completes
-/// 3. Fee accumulation succeeds
-/// 4. Status updated to Settled
-/// 5. Settlement hash set
-/// 6. Event emission flag checked
-pub fn emit_settlement_completed(
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the adjusted remittance
+/// * `sender` - Address of the sender
+/// * `old_amount` - Amount before the adjustment
+/// * `new_amount` - Amount after the adjustment
+/// * `fee` - Platform fee recomputed for the new amount
+pub fn emit_amount_adjusted(
     env: &Env,
     remittance_id: u64,
     sender: Address,
-    receiver: Address,
-    asset: Address,
-    amount: i128,
+    old_amount: i128,
+    new_amount: i128,
+    fee: i128,
 ) {
     env.events().publish(
-        (symbol_short!("settle"), symbol_short!("complete")),
+        (symbol_short!("remit"), symbol_short!("adjusted")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
             sender,
-            receiver,
-            asset,
-            amount,
+            old_amount,
+            new_amount,
+            fee,
         ),
     );
 }
 
+/// Emits an event when a sender amends the amount and/or expiry of a pending remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the amended remittance
+/// * `sender` - Address of the sender
+/// * `old_amount` - Amount before the amendment
+/// * `new_amount` - Amount after the amendment
+/// * `fee` - Platform fee for the new amount
+/// * `old_expiry` - Expiry before the amendment
+/// * `new_expiry` - Expiry after the amendment
+#[allow(clippy::too_many_arguments)]
+pub fn emit_remittance_amended(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    old_amount: i128,
+    new_amount: i128,
+    fee: i128,
+    old_expiry: Option<u64>,
+    new_expiry: Option<u64>,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("amended")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            old_amount,
+            new_amount,
+            fee,
+            old_expiry,
+            new_expiry,
+        ),
+    );
+}
+
+/// Emits an event when a pending remittance is moved to a different agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the reassigned remittance
+/// * `old_agent` - Agent the remittance was assigned to
+/// * `new_agent` - Agent now responsible for the payout
+pub fn emit_agent_reassigned(env: &Env, remittance_id: u64, old_agent: Address, new_agent: Address) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("reassign")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            old_agent,
+            new_agent,
+        ),
+    );
+}
+
+// ── Agent Events ───────────────────────────────────────────────────
+
+/// Emits an event when a new agent is registered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the registered agent
+/// * `count` - Number of registered agents after the change
+pub fn emit_agent_registered(env: &Env, agent: Address, count: u32) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("register")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            count,
+        ),
+    );
+}
+
+/// Emits an event when an agent is removed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the removed agent
+/// * `count` - Number of registered agents after the change
+pub fn emit_agent_removed(env: &Env, agent: Address, count: u32) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            count,
+        ),
+    );
+}
+
+/// Emits an event when an agent's batch settlement eligibility changes.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `eligible` - Whether the agent may now be settled in batches
+pub fn emit_agent_batch_eligibility(env: &Env, agent: Address, eligible: bool) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("batch")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            eligible,
+        ),
+    );
+}
+
+/// Emits an event when an agent's inbound freeze is set or lifted.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `frozen` - Whether new remittances to the agent are now blocked
+pub fn emit_agent_inbound_frozen(env: &Env, agent: Address, frozen: bool) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("frozen")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            frozen,
+        ),
+    );
+}
+
+// ── Stake Events ───────────────────────────────────────────────────
+
+/// Emits an event when an agent deposits stake.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent that deposited
+/// * `amount` - Amount deposited
+/// * `total` - Agent's stake after the deposit
+pub fn emit_agent_staked(env: &Env, agent: Address, amount: i128, total: i128) {
+    env.events().publish(
+        (symbol_short!("stake"), symbol_short!("deposit")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            amount,
+            total,
+        ),
+    );
+}
+
+/// Emits an event when an agent's stake is slashed into platform fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent that was slashed
+/// * `amount` - Amount slashed
+/// * `remaining` - Agent's stake after the slash
+pub fn emit_agent_slashed(env: &Env, agent: Address, amount: i128, remaining: i128) {
+    env.events().publish(
+        (symbol_short!("stake"), symbol_short!("slash")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            amount,
+            remaining,
+        ),
+    );
+}
+
+/// Emits an event when an agent withdraws stake.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent that withdrew
+/// * `amount` - Amount returned to the agent
+/// * `remaining` - Agent's stake after the withdrawal
+pub fn emit_agent_unstaked(env: &Env, agent: Address, amount: i128, remaining: i128) {
+    env.events().publish(
+        (symbol_short!("stake"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            amount,
+            remaining,
+        ),
+    );
+}
+
+// ── Fee Events ─────────────────────────────────────────────────────
+
+/// Emits an event when the platform fee is updated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who changed the fee
+/// * `old_fee_bps` - Fee rate in basis points before the update
+/// * `new_fee_bps` - Fee rate in basis points after the update
+pub fn emit_fee_updated(env: &Env, admin: Address, old_fee_bps: u32, new_fee_bps: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("updated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            old_fee_bps,
+            new_fee_bps,
+        ),
+    );
+}
+
+/// Emits an event when a fee update is scheduled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - Fee rate that will apply
+/// * `effective_at` - Timestamp from which the fee applies
+pub fn emit_fee_scheduled(env: &Env, fee_bps: u32, effective_at: u64) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("schedule")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            fee_bps,
+            effective_at,
+        ),
+    );
+}
+
+/// Emits an event when fees above the fee cap are forwarded to the treasury.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `treasury` - Address that received the excess
+/// * `amount` - Amount forwarded
+pub fn emit_fee_cap_overflow(env: &Env, treasury: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("overflow")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            treasury,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a settlement's fee is collected.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Fee collected
+/// * `lifetime` - Total fees ever collected, including this one
+pub fn emit_fee_collected(env: &Env, amount: i128, lifetime: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("collected")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            amount,
+            lifetime,
+        ),
+    );
+}
+
+/// Emits an event when accumulated fees are withdrawn.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `to` - Address that received the withdrawn fees
+/// * `amount` - Amount of fees withdrawn
+pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when yield is recorded.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Yield amount recorded
+/// * `pool` - Yield pool balance after recording
+pub fn emit_yield_recorded(env: &Env, amount: i128, pool: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("recorded")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            amount,
+            pool,
+        ),
+    );
+}
+
+/// Emits an event when the yield pool is swept.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `to` - Address that received the yield
+/// * `amount` - Amount swept
+pub fn emit_yield_swept(env: &Env, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("yield"), symbol_short!("swept")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a token is added to or removed from the denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token whose status changed
+/// * `denied` - Whether the token is now denied
+pub fn emit_token_denied(env: &Env, token: Address, denied: bool) {
+    env.events().publish(
+        (symbol_short!("token"), symbol_short!("denied")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            denied,
+        ),
+    );
+}
+
+/// Emits an event when a sender is added to or removed from the allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender whose status changed
+/// * `allowed` - Whether the sender is now allowlisted
+pub fn emit_sender_allowed(env: &Env, sender: Address, allowed: bool) {
+    env.events().publish(
+        (symbol_short!("sender"), symbol_short!("allowed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            allowed,
+        ),
+    );
+}
+
+/// Emits an event when the settlement token is replaced.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `old_token` - Previous settlement token
+/// * `new_token` - New settlement token
+pub fn emit_token_migrated(env: &Env, old_token: Address, new_token: Address) {
+    env.events().publish(
+        (symbol_short!("token"), symbol_short!("migrated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            old_token,
+            new_token,
+        ),
+    );
+}
+
+/// Emits an event when stray tokens are recovered from the contract.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token that was recovered
+/// * `to` - Address that received the tokens
+/// * `amount` - Amount recovered
+pub fn emit_token_recovered(env: &Env, token: Address, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("token"), symbol_short!("recover")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when the standing fee recipient is changed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who made the change
+/// * `recipient` - New fee recipient
+pub fn emit_fee_recipient_updated(env: &Env, admin: Address, recipient: Address) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("recipient")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            recipient,
+        ),
+    );
+}
+
+// ── Settlement Events ──────────────────────────────────────────────
+
+/// Emits a structured completion event when a settlement is finalized.
+///
+/// This event is emitted exactly once per completed settlement, after all state
+/// transitions are successfully committed. It includes sufficient identifiers to
+/// uniquely reference the finalized settlement.
+///
+/// # Guarantees
+///
+/// - **Exactly-Once Emission**: Event is emitted once and only once per settlement
+/// - **Post-Finalization**: Only emitted after all state changes are committed
+/// - **Unique Identification**: Includes remittance_id for unambiguous reference
+/// - **Deterministic**: Same settlement always produces same event
+/// - **No Re-entry**: Protected against duplicate emission on retries
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Unique ID of the finalized settlement
+/// * `sender` - Address of the sender
+/// * `receiver` - Address that received the payout (the explicit recipient if set, otherwise the agent)
+/// * `asset` - Address of the token contract (e.g., USDC)
+/// * `amount` - Settlement amount transferred
+/// * `receipt` - Deterministic settlement receipt hash
+///
+/// # Event Structure
+///
+/// Topic: `("settle", "complete")`
+/// Data: `(schema_version, ledger_sequence, timestamp, remittance_id, sender, receiver, asset, amount, receipt)`
+///
+/// # Usage
+///
+/// This function should only be called from `finalize_settlement` after:
+/// 1. All validations pass
+/// 2. Token transfer completes
+/// 3. Fee accumulation succeeds
+/// 4. Status updated to Completed (or PartiallyReleased)
+/// 5. Settlement hash set
+/// 6. Event emission flag checked
+pub fn emit_settlement_completed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    receiver: Address,
+    asset: Address,
+    amount: i128,
+    receipt: BytesN<32>,
+) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("complete")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            receiver,
+            asset,
+            amount,
+            receipt,
+        ),
+    );
+}
+
+// ── Liquidity Pool Events ──────────────────────────────────────────
+
+/// Emits an event when an agent deposits into its liquidity pool.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent that funded the pool
+/// * `token` - Token deposited into the pool
+/// * `amount` - Amount deposited
+pub fn emit_agent_pool_funded(env: &Env, agent: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("pool"), symbol_short!("funded")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when an agent withdraws from its liquidity pool.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent that withdrew
+/// * `token` - Token withdrawn from the pool
+/// * `amount` - Amount withdrawn
+pub fn emit_agent_pool_withdrawn(env: &Env, agent: Address, token: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("pool"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            token,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a remittance is settled in a pool token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled remittance
+/// * `agent` - Agent whose pool funded the payout
+/// * `recipient` - Address that received the local-token payout
+/// * `payout_token` - Token paid out from the pool
+/// * `local_amount` - Amount paid out in the pool token
+/// * `rate_bps` - Conversion rate applied (10000 = 1:1)
+pub fn emit_fx_settlement(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    recipient: Address,
+    payout_token: Address,
+    local_amount: i128,
+    rate_bps: u32,
+) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("fx")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            recipient,
+            payout_token,
+            local_amount,
+            rate_bps,
+        ),
+    );
+}
+
+/// Emits an event when a remittance is settled after its `settle_by` deadline.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the late remittance
+/// * `agent` - Agent who settled late
+/// * `settle_by` - Deadline that was missed
+pub fn emit_late_settlement(env: &Env, remittance_id: u64, agent: Address, settle_by: u64) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("late")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            settle_by,
+        ),
+    );
+}
+
+/// Emits an event when a remittance is settled after expiry but inside the grace window.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled remittance
+/// * `agent` - Agent that settled it
+/// * `expiry` - Expiry timestamp the settlement passed
+pub fn emit_settled_in_grace(env: &Env, remittance_id: u64, agent: Address, expiry: u64) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("grace")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            expiry,
+        ),
+    );
+}
+
+/// Emits an event when an agent's operator confirms a payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled remittance
+/// * `agent` - Agent the payout was made to
+/// * `operator` - Operator that confirmed it
+pub fn emit_operator_confirmed(env: &Env, remittance_id: u64, agent: Address, operator: Address) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("operator")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            operator,
+        ),
+    );
+}
+
+/// Emits the contract's token balance and accumulated fees after a settlement.
+///
+/// Only emitted when diagnostic balance events are enabled, so monitors can
+/// cross-check on-chain balance against expected liability.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `balance` - Contract token balance after the settlement
+/// * `accumulated_fees` - Platform fees awaiting withdrawal
+pub fn emit_balance_check(env: &Env, balance: i128, accumulated_fees: i128) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("balance")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            balance,
+            accumulated_fees,
+        ),
+    );
+}
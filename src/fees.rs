@@ -0,0 +1,233 @@
+//! Fee calculation for the SwiftRemit contract.
+//!
+//! Centralizes the basis-point fee math so every code path that charges a
+//! fee applies the same rounding rules.
+
+use soroban_sdk::{token, Address, Env};
+
+use crate::{
+    checked_add_i128, clear_scheduled_fee, emit_fee_cap_overflow, emit_fee_collected, get_accumulated_fees, get_fee_cap,
+    get_fee_recipient, get_fee_tiers, get_lifetime_fees, get_min_fee, get_platform_fee_bps, get_rounding_mode,
+    get_scheduled_fee, get_token_fee_bps, is_auto_sweep, set_accumulated_fees, set_lifetime_fees, set_platform_fee_bps, ContractError,
+    RoundingMode,
+};
+
+/// Basis-point denominator (10000 bps = 100%).
+pub const BPS_DENOMINATOR: i128 = 10000;
+
+/// Calculates the fee on `amount` at `fee_bps`, rounded according to `mode`.
+///
+/// # Arguments
+///
+/// * `amount` - Amount the fee is charged on (must be non-negative)
+/// * `fee_bps` - Fee rate in basis points
+/// * `mode` - Rounding applied to the fractional part of the fee
+///
+/// # Returns
+///
+/// * `Ok(i128)` - The fee amount
+/// * `Err(ContractError::Overflow)` - Arithmetic overflow
+pub fn calculate_fee(amount: i128, fee_bps: u32, mode: &RoundingMode) -> Result<i128, ContractError> {
+    let scaled = amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(ContractError::Overflow)?;
+
+    let adjusted = match mode {
+        RoundingMode::Down => Some(scaled),
+        RoundingMode::Up => scaled.checked_add(BPS_DENOMINATOR - 1),
+        RoundingMode::HalfUp => scaled.checked_add(BPS_DENOMINATOR / 2),
+    }
+    .ok_or(ContractError::Overflow)?;
+
+    adjusted
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(ContractError::Overflow)
+}
+
+/// Returns the scheduled fee rate if its effective time has been reached.
+fn due_scheduled_fee(env: &Env) -> Option<u32> {
+    match get_scheduled_fee(env) {
+        Some((fee_bps, effective_at)) if env.ledger().timestamp() >= effective_at => Some(fee_bps),
+        _ => None,
+    }
+}
+
+/// Returns the fee rate to charge on a new remittance.
+///
+/// A scheduled fee update applies once its effective time has been reached.
+/// This only reads storage; `promote_scheduled_fee` persists the update.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Fee rate in basis points
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn effective_fee_bps(env: &Env) -> Result<u32, ContractError> {
+    match due_scheduled_fee(env) {
+        Some(fee_bps) => Ok(fee_bps),
+        None => get_platform_fee_bps(env),
+    }
+}
+
+/// Makes a scheduled fee update that has come due the platform fee.
+///
+/// Called only from paths that already write state, so read-only calls
+/// such as `quote_fee` never modify storage.
+pub fn promote_scheduled_fee(env: &Env) {
+    if let Some(fee_bps) = due_scheduled_fee(env) {
+        set_platform_fee_bps(env, fee_bps);
+        clear_scheduled_fee(env);
+    }
+}
+
+/// Returns the rate of the highest fee tier that `amount` reaches, if any.
+pub fn tier_fee_bps(env: &Env, amount: i128) -> Option<u32> {
+    let mut fee_bps = None;
+    for tier in get_fee_tiers(env).iter() {
+        if amount < tier.min_amount {
+            break;
+        }
+        fee_bps = Some(tier.fee_bps);
+    }
+    fee_bps
+}
+
+/// Returns the fee rate to charge on a new remittance of `amount` in `token`.
+///
+/// A per-token override takes precedence, then the amount-based fee tiers;
+/// otherwise the global platform fee applies (including any scheduled
+/// update that has come due).
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Fee rate in basis points
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn fee_bps_for_token(env: &Env, token: &Address, amount: i128) -> Result<u32, ContractError> {
+    if let Some(fee_bps) = get_token_fee_bps(env, token) {
+        return Ok(fee_bps);
+    }
+    match tier_fee_bps(env, amount) {
+        Some(fee_bps) => Ok(fee_bps),
+        None => effective_fee_bps(env),
+    }
+}
+
+/// Computes the fee a new remittance of `amount` in `token` is charged.
+///
+/// This is the single fee resolution used at creation time, so quotes and
+/// stored fees cannot drift apart. The basis-point fee is raised to the
+/// configured minimum fee when it falls below it.
+///
+/// # Returns
+///
+/// * `Ok(i128)` - The fee amount
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+/// * `Err(ContractError::PayoutTooSmall)` - `amount` does not exceed the minimum fee
+/// * `Err(ContractError::Overflow)` - Arithmetic overflow
+pub fn remittance_fee(env: &Env, token: &Address, amount: i128) -> Result<i128, ContractError> {
+    let fee_bps = fee_bps_for_token(env, token, amount)?;
+    let fee = calculate_fee(amount, fee_bps, &get_rounding_mode(env))?;
+
+    let min_fee = get_min_fee(env);
+    if min_fee > 0 {
+        // The floor must leave a positive payout
+        if amount <= min_fee {
+            return Err(ContractError::PayoutTooSmall);
+        }
+        return Ok(fee.max(min_fee));
+    }
+
+    Ok(fee)
+}
+
+/// Books a fee earned by a settlement.
+///
+/// With auto-sweep enabled and a fee recipient configured, the fee is
+/// transferred to the recipient immediately; otherwise it is added to the
+/// accumulated fees for a later `withdraw_fees`. When a fee cap is set, any
+/// accumulated amount above the cap is forwarded to the cap's treasury.
+/// Every collected fee also counts towards the lifetime fee total.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token_client` - Client for the settlement token
+/// * `fee` - Fee earned by the settlement
+///
+/// # Returns
+///
+/// * `Ok(())` - Fee routed or accumulated
+/// * `Err(ContractError::Overflow)` - Accumulated or lifetime fees would overflow
+pub fn collect_fee(env: &Env, token_client: &token::Client, fee: i128) -> Result<(), ContractError> {
+    if fee <= 0 {
+        return Ok(());
+    }
+
+    let lifetime = checked_add_i128(get_lifetime_fees(env), fee)?;
+    set_lifetime_fees(env, lifetime);
+    emit_fee_collected(env, fee, lifetime);
+
+    if is_auto_sweep(env) {
+        if let Some(recipient) = get_fee_recipient(env) {
+            token_client.transfer(&env.current_contract_address(), &recipient, &fee);
+            return Ok(());
+        }
+    }
+
+    let mut new_fees = checked_add_i128(get_accumulated_fees(env)?, fee)?;
+
+    if let Some((cap, treasury)) = get_fee_cap(env) {
+        if new_fees > cap {
+            let excess = new_fees - cap;
+            token_client.transfer(&env.current_contract_address(), &treasury, &excess);
+            emit_fee_cap_overflow(env, treasury, excess);
+            new_fees = cap;
+        }
+    }
+
+    set_accumulated_fees(env, new_fees);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_fee_rounding_modes() {
+        // 1001 * 250 = 250250 -> 25.025
+        assert_eq!(calculate_fee(1001, 250, &RoundingMode::Down), Ok(25));
+        assert_eq!(calculate_fee(1001, 250, &RoundingMode::Up), Ok(26));
+        assert_eq!(calculate_fee(1001, 250, &RoundingMode::HalfUp), Ok(25));
+
+        // 1020 * 250 = 255000 -> 25.5
+        assert_eq!(calculate_fee(1020, 250, &RoundingMode::HalfUp), Ok(26));
+    }
+
+    #[test]
+    fn test_calculate_fee_exact_division_unaffected() {
+        for mode in [RoundingMode::Down, RoundingMode::Up, RoundingMode::HalfUp] {
+            assert_eq!(calculate_fee(1000, 250, &mode), Ok(25));
+            assert_eq!(calculate_fee(1000, 0, &mode), Ok(0));
+        }
+    }
+
+    #[test]
+    fn test_calculate_fee_overflow() {
+        assert_eq!(
+            calculate_fee(i128::MAX, 10000, &RoundingMode::Down),
+            Err(ContractError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_rounding_overflow() {
+        // The multiply fits; only the rounding adjustment overflows
+        for mode in [RoundingMode::Up, RoundingMode::HalfUp] {
+            assert_eq!(calculate_fee(i128::MAX, 1, &mode), Err(ContractError::Overflow));
+        }
+        assert_eq!(
+            calculate_fee(i128::MAX, 1, &RoundingMode::Down),
+            Ok(i128::MAX / BPS_DENOMINATOR)
+        );
+    }
+}
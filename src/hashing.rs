@@ -100,6 +100,81 @@ pub fn compute_settlement_id_from_remittance(
     )
 }
 
+/// Generate a deterministic settlement receipt for a completed payout.
+///
+/// Fields are encoded in the same canonical style as settlement IDs:
+/// `remittance_id` (u64 BE), `sender` bytes, `agent` bytes,
+/// `payout_amount` (i128 BE), `timestamp` (u64 BE).
+///
+/// # Arguments
+/// * `env`            - Soroban environment
+/// * `remittance_id`  - Settled remittance ID
+/// * `sender`         - Sender address
+/// * `agent`          - Agent address that received the payout
+/// * `payout_amount`  - Amount released to the agent (amount minus fee)
+/// * `timestamp`      - Ledger timestamp of the settlement
+///
+/// # Returns
+/// SHA-256 hash as BytesN<32> — the settlement receipt
+pub fn compute_settlement_receipt(
+    env: &Env,
+    remittance_id: u64,
+    sender: &Address,
+    agent: &Address,
+    payout_amount: i128,
+    timestamp: u64,
+) -> BytesN<32> {
+    let mut buf = Bytes::new(env);
+    buf.extend_from_array(&remittance_id.to_be_bytes());
+    buf.append(&address_to_bytes(env, sender));
+    buf.append(&address_to_bytes(env, agent));
+    buf.extend_from_array(&payout_amount.to_be_bytes());
+    buf.extend_from_array(&timestamp.to_be_bytes());
+    env.crypto().sha256(&buf).into()
+}
+
+/// Compute the commitment a claimable remittance is locked to.
+///
+/// Binding the claim code to the recipient means a claim seen in the mempool
+/// cannot be front-run by resubmitting the code with another address.
+/// Encoded as `code` bytes followed by `recipient` bytes.
+///
+/// # Arguments
+/// * `env`       - Soroban environment
+/// * `code`      - Claim code shared out of band
+/// * `recipient` - Address allowed to claim the payout
+///
+/// # Returns
+/// SHA-256 hash as BytesN<32> — the claim commitment
+pub fn compute_claim_code_hash(env: &Env, code: &Bytes, recipient: &Address) -> BytesN<32> {
+    let mut buf = code.clone();
+    buf.append(&address_to_bytes(env, recipient));
+    env.crypto().sha256(&buf).into()
+}
+
+/// Compute the message an agent signs to authorize a relayed payout.
+///
+/// The contract address and network ID bind the authorization to one
+/// deployment on one network, so a signature cannot be replayed elsewhere.
+/// Encoded as contract address bytes, the 32-byte network ID (SHA-256 of
+/// the network passphrase), then `remittance_id` and `nonce` as big-endian
+/// u64.
+///
+/// # Arguments
+/// * `env`           - Soroban environment
+/// * `remittance_id` - Remittance being settled
+/// * `nonce`         - Single-use nonce chosen by the agent
+///
+/// # Returns
+/// Bytes to be signed with the agent's Ed25519 key
+pub fn compute_payout_signing_payload(env: &Env, remittance_id: u64, nonce: u64) -> Bytes {
+    let mut buf = address_to_bytes(env, &env.current_contract_address());
+    buf.append(&env.ledger().network_id().into());
+    buf.extend_from_array(&remittance_id.to_be_bytes());
+    buf.extend_from_array(&nonce.to_be_bytes());
+    buf
+}
+
 /// Serialize an Address to its canonical byte representation.
 /// Uses Soroban's built-in address serialization via to_xdr.
 fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
@@ -8,6 +8,7 @@ mod debug;
 mod error_handler;
 mod errors;
 mod events;
+mod fees;
 mod hashing;
 mod migration;
 mod netting;
@@ -18,12 +19,13 @@ mod validation;
 #[cfg(test)]
 mod test; 
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, Map, String, Vec};
 
 pub use debug::*;
 pub use error_handler::*;
 pub use errors::ContractError;
 pub use events::*;
+pub use fees::*;
 pub use hashing::*;
 pub use migration::*;
 pub use netting::*;
@@ -32,6 +34,12 @@ pub use storage::*;
 pub use types::*;
 pub use validation::*;
 
+/// Maximum number of entries accepted by a single batch operation.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Maximum length in bytes of an agent's display label.
+pub const MAX_AGENT_LABEL_LEN: u32 = 32;
+
 /// The main SwiftRemit contract for managing cross-border remittances.
 ///
 /// This contract handles the complete lifecycle of remittance transactions including:
@@ -62,6 +70,7 @@ impl SwiftRemitContract {
     /// * `Ok(())` - Contract successfully initialized
     /// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
     /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::InvalidAddress)` - `admin` and `usdc_token` are the same address
     ///
     /// # Examples
     ///
@@ -86,20 +95,99 @@ impl SwiftRemitContract {
         set_admin_count(&env, 1);
         
         set_usdc_token(&env, &usdc_token);
+        set_token_decimals(&env, token::Client::new(&env, &usdc_token).decimals());
         set_platform_fee_bps(&env, fee_bps);
         set_integrator_fee_bps(&env, 0);
         set_remittance_counter(&env, 0);
         set_accumulated_fees(&env, 0);
         set_rate_limit_cooldown(&env, rate_limit_cooldown);
 
+        // Fresh deployments start on the current storage layout
+        set_schema_version(&env, STORAGE_SCHEMA_VERSION);
+
         // Initialize rate limiting with default configuration
         init_rate_limit(&env);
 
+        extend_instance_ttl(&env);
+
         log_initialize(&env, &admin, &usdc_token, fee_bps);
 
         Ok(())
     }
 
+    /// Initializes the contract and registers its first agents in one call.
+    ///
+    /// Equivalent to `initialize` followed by `register_agents`, so a
+    /// deployment never leaves the contract initialized without agents.
+    /// The usual registration events are emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `admin` - Address that will have administrative privileges
+    /// * `usdc_token` - Address of the USDC token contract used for transactions
+    /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `rate_limit_cooldown` - Settlement cooldown in seconds, as for `initialize`
+    /// * `agents` - Agents to register (at most `MAX_BATCH_SIZE`)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Contract initialized and agents registered
+    /// * Any error returned by `initialize` or `register_agents`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `admin` to register the agents.
+    pub fn initialize_with_agents(
+        env: Env,
+        admin: Address,
+        usdc_token: Address,
+        fee_bps: u32,
+        rate_limit_cooldown: u64,
+        agents: Vec<Address>,
+    ) -> Result<(), ContractError> {
+        Self::initialize(env.clone(), admin, usdc_token, fee_bps, rate_limit_cooldown)?;
+        Self::register_agents(env, agents)
+    }
+
+    /// Checks whether the contract has been initialized.
+    ///
+    /// Lets clients and deploy scripts check before calling `initialize`
+    /// instead of relying on a failed call or on getters that error.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `true` - `initialize` has been called
+    /// * `false` - Contract is not yet initialized
+    pub fn is_initialized(env: Env) -> bool {
+        has_admin(&env)
+    }
+
+    /// Returns the number of decimals used by the settlement token.
+    ///
+    /// Amounts throughout the contract are raw integer units of the token;
+    /// clients divide by `10^decimals` to display them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Token decimals
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_token_decimals(env: Env) -> Result<u32, ContractError> {
+        match get_token_decimals(&env) {
+            Some(decimals) => Ok(decimals),
+            // Deployments initialized before decimals were recorded
+            None => Ok(token::Client::new(&env, &get_usdc_token(&env)?).decimals()),
+        }
+    }
+
     /// Registers a new agent authorized to receive remittance payouts.
     ///
     /// Only the contract admin can register agents. Registered agents can confirm
@@ -126,7 +214,7 @@ impl SwiftRemitContract {
 
         // Event: Agent registered - Fires when admin adds a new agent to the approved list
         // Used by off-chain systems to track which addresses can confirm payouts
-        emit_agent_registered(&env, agent);
+        emit_agent_registered(&env, agent, get_agent_count(&env));
 
         Ok(())
     }
@@ -155,735 +243,5305 @@ impl SwiftRemitContract {
 
         set_agent_registered(&env, &agent, false);
 
-        emit_agent_removed(&env, agent.clone(), caller.clone());
-
-        
         // Event: Agent removed - Fires when admin removes an agent from the approved list
         // Used by off-chain systems to revoke payout confirmation privileges
-        emit_agent_removed(&env, agent, caller.clone());
-
+        emit_agent_removed(&env, agent, get_agent_count(&env));
 
         Ok(())
     }
 
-    /// Updates the platform fee rate.
+    /// Registers multiple agents in a single admin call.
     ///
-    /// Only the contract admin can update the fee. The new fee applies to all
-    /// remittances created after the update.
+    /// Duplicate addresses in the input are registered once. Emits one
+    /// registration event per distinct agent.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `agents` - Addresses to register (at most `MAX_BATCH_SIZE`)
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Fee successfully updated
+    /// * `Ok(())` - All agents registered
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` addresses supplied
     ///
     /// # Authorization
     ///
     /// Requires authentication from the contract admin.
-    pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_update_fee_request(fee_bps)?;
-        
+    pub fn register_agents(env: Env, agents: Vec<Address>) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
 
-        let old_fee = get_platform_fee_bps(&env)?;
-        set_platform_fee_bps(&env, fee_bps);
-        emit_fee_updated(&env, caller.clone(), old_fee, fee_bps);
+        if agents.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
 
-        log_update_fee(&env, fee_bps);
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for agent in agents.iter() {
+            if seen.contains(&agent) {
+                continue;
+            }
+            seen.push_back(agent.clone());
+
+            set_agent_registered(&env, &agent, true);
+            emit_agent_registered(&env, agent, get_agent_count(&env));
+        }
 
         Ok(())
     }
 
-    /// Creates a new remittance transaction.
+    /// Removes multiple agents in a single admin call.
     ///
-    /// Transfers the specified amount from the sender to the contract, calculates
-    /// the platform fee, and creates a pending remittance record. The agent can later
-    /// confirm the payout to receive the amount minus fees.
+    /// Duplicate addresses in the input are removed once. Emits one removal
+    /// event per distinct agent.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `sender` - Address initiating the remittance
-    /// * `agent` - Address of the registered agent who will receive the payout
-    /// * `amount` - Amount to remit in USDC (must be positive)
-    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `agents` - Addresses to remove (at most `MAX_BATCH_SIZE`)
     ///
     /// # Returns
     ///
-    /// * `Ok(remittance_id)` - Unique ID of the created remittance
-    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
+    /// * `Ok(())` - All agents removed
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` addresses supplied
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address.
-   pub fn create_remittance(
-    env: Env,
-    sender: Address,
-    agent: Address,
-    amount: i128,
-    expiry: Option<u64>,
-) -> Result<u64, ContractError> {
-    validate_create_remittance_request(&env, &sender, &agent, amount)?;
+    /// Requires authentication from the contract admin.
+    pub fn remove_agents(env: Env, agents: Vec<Address>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
-    sender.require_auth();
+        if agents.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
 
-    let fee_bps = get_platform_fee_bps(&env)?;
-    let fee = amount
-        .checked_mul(fee_bps as i128)
-        .ok_or(ContractError::Overflow)?
-        .checked_div(10000)
-        .ok_or(ContractError::Overflow)?;
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for agent in agents.iter() {
+            if seen.contains(&agent) {
+                continue;
+            }
+            seen.push_back(agent.clone());
 
-    let usdc_token = get_usdc_token(&env)?;
-    let token_client = token::Client::new(&env, &usdc_token);
-    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+            set_agent_registered(&env, &agent, false);
+            emit_agent_removed(&env, agent, get_agent_count(&env));
+        }
 
-    let counter = get_remittance_counter(&env)?;
-    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        Ok(())
+    }
 
-    let remittance = Remittance {
-        id: remittance_id,
-        sender: sender.clone(),
-        agent: agent.clone(),
-        amount,
-        fee,
-        status: RemittanceStatus::Pending,
-        expiry,
-    };
+    /// Returns the number of currently registered agents.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Registered agent count
+    pub fn get_agent_count(env: Env) -> u32 {
+        get_agent_count(&env)
+    }
 
-    set_remittance(&env, remittance_id, &remittance);
-    set_remittance_counter(&env, remittance_id);
+    /// Returns all registered agents in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Address>` - Registered agents
+    pub fn get_agents(env: Env) -> Vec<Address> {
+        get_agent_list(&env)
+    }
 
-    Ok(remittance_id)  // ← capital O
-}
-    /// Confirms a remittance payout to the agent.
+    /// Returns one page of registered agents in registration order.
     ///
-    /// Transfers the remittance amount (minus platform fee) to the agent and marks
-    /// the remittance as completed. Includes duplicate settlement protection and
-    /// expiry validation.
+    /// Use this instead of `get_agents` once the agent set is too large to
+    /// read in a single call.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `start` - Index of the first agent to return
+    /// * `limit` - Maximum number of agents to return
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Payout successfully confirmed and transferred
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
-    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
-    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
-    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Vec<Address>` - Agents in `[start, start + limit)`, empty past the end
+    pub fn get_agents_paged(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let agents = get_agent_list(&env);
+        let end = start.saturating_add(limit).min(agents.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        agents.slice(start..end)
+    }
+
+    /// Returns when an agent last settled a remittance.
     ///
-    /// # Authorization
+    /// Lets senders and the admin avoid routing to dormant agents. Updated by
+    /// every settlement path, including batch settlement.
     ///
-    /// Requires authentication from the agent address assigned to the remittance.
-    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - Ledger timestamp of the latest settlement (0 if the agent never settled)
+    pub fn get_agent_last_active(env: Env, agent: Address) -> u64 {
+        get_agent_last_active(&env, &agent)
+    }
 
-        remittance.agent.require_auth();
+    /// Returns an agent's settlement nonce.
+    ///
+    /// The nonce increases by one on every payout settled to the agent and is
+    /// carried in the `("remit", "complete")` event, so settlement workers can
+    /// order events that arrive out of sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - Nonce of the latest payout (0 if the agent never settled)
+    pub fn get_agent_settlement_nonce(env: Env, agent: Address) -> u64 {
+        get_agent_settlement_nonce(&env, &agent)
+    }
 
-        if remittance.status != RemittanceStatus::Pending {
-            return Err(ContractError::InvalidStatus);
-        }
+    /// Returns settlement activity counters for an agent.
+    ///
+    /// Gives senders an on-chain signal for choosing agents without relying
+    /// on an off-chain indexer.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    ///
+    /// # Returns
+    ///
+    /// * `AgentStats` - Completed and expired counts plus total paid out (zeroed if no activity)
+    pub fn get_agent_stats(env: Env, agent: Address) -> AgentStats {
+        get_agent_stats(&env, &agent)
+    }
 
-        // Check for duplicate settlement execution
-        if has_settlement_hash(&env, remittance_id) {
-            return Err(ContractError::DuplicateSettlement);
-        }
+    /// Sets whether an agent may be settled through batch settlement.
+    ///
+    /// Batches are higher-throughput and harder to monitor per entry, so only
+    /// vetted agents are allowed in them. Single `confirm_payout` is unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent
+    /// * `eligible` - `true` to allow the agent in batches, `false` to exclude it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Eligibility updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_batch_eligible(
+        env: Env,
+        agent: Address,
+        eligible: bool,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
-        // Check if settlement has expired
-        if let Some(expiry_time) = remittance.expiry {
-            let current_time = env.ledger().timestamp();
-            if current_time > expiry_time {
-                return Err(ContractError::SettlementExpired);
-            }
-        }
+        set_agent_batch_eligible(&env, &agent, eligible);
 
-        // Check rate limit for sender
-        check_rate_limit(&env, &remittance.sender)?;
+        emit_agent_batch_eligibility(&env, agent, eligible);
 
-        // Validate the agent address before transfer
-        validate_address(&remittance.agent)?;
+        Ok(())
+    }
 
-        let payout_amount = remittance
-            .amount
-            .checked_sub(remittance.fee)
-            .ok_or(ContractError::Overflow)?
-            .checked_sub(remittance.integrator_fee)
-            .ok_or(ContractError::Overflow)?;
+    /// Returns whether an agent is eligible for batch settlement.
+    pub fn is_agent_batch_eligible(env: Env, agent: Address) -> bool {
+        is_agent_batch_eligible(&env, &agent)
+    }
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.agent,
-            &payout_amount,
-        );
+    /// Blocks new remittances to an agent without de-registering it.
+    ///
+    /// Meant for agents under investigation: creation and reassignment to the
+    /// agent fail with `AgentFrozen`, while remittances it already holds
+    /// can still be confirmed or cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to freeze
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent frozen
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn freeze_agent_inbound(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
-        let current_fees = get_accumulated_fees(&env)?;
-        let new_fees = current_fees
-            .checked_add(remittance.fee)
-            .ok_or(ContractError::Overflow)?;
-        set_accumulated_fees(&env, new_fees);
+        set_agent_inbound_frozen(&env, &agent, true);
 
-        let current_integrator_fees = get_accumulated_integrator_fees(&env)?;
-        let new_integrator_fees = current_integrator_fees
-            .checked_add(remittance.integrator_fee)
-            .ok_or(ContractError::Overflow)?;
-        set_accumulated_integrator_fees(&env, new_integrator_fees);
+        emit_agent_inbound_frozen(&env, agent, true);
 
-        remittance.status = RemittanceStatus::Settled;
-        set_remittance(&env, remittance_id, &remittance);
+        Ok(())
+    }
 
-        // Mark settlement as executed to prevent duplicates
-        set_settlement_hash(&env, remittance_id);
-        
-        // Capture ledger timestamp for settlement creation
-        let current_time = env.ledger().timestamp();
-        set_settlement_timestamp(&env, remittance_id, current_time);
-        
-        // Update last settlement time for rate limiting
-        set_last_settlement_time(&env, &remittance.sender, current_time);
+    /// Lifts an inbound freeze set by `freeze_agent_inbound`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn unfreeze_agent_inbound(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
+        set_agent_inbound_frozen(&env, &agent, false);
 
-        // Increment settlement counter atomically after successful finalization
-        increment_settlement_counter(&env)?;
-
-
-        // Increment settlement counter atomically after successful finalization
-        increment_settlement_counter(&env);
-
-
-
-        // Emit settlement completion event exactly once
-        // This event is emitted after all state transitions are committed
-        // and includes safeguards to prevent duplicate emission
-        if !has_settlement_event_emitted(&env, remittance_id) {
-            emit_settlement_completed(
-                &env,
-                remittance_id,
-                remittance.sender.clone(),
-                remittance.agent.clone(),
-                usdc_token.clone(),
-                payout_amount
-            );
-            set_settlement_event_emitted(&env, remittance_id);
-        }
-
-        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
-        // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(&env, remittance_id, remittance.agent.clone(), payout_amount);
+        emit_agent_inbound_frozen(&env, agent, false);
 
-        log_confirm_payout(&env, remittance_id, payout_amount);
+        Ok(())
+    }
 
-        Ok(remittance_id)
+    /// Returns whether new remittances to an agent are blocked.
+    pub fn is_agent_inbound_frozen(env: Env, agent: Address) -> bool {
+        is_agent_inbound_frozen(&env, &agent)
     }
 
-    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+    /// Sets a human-readable label for an agent, e.g. its name and location.
+    ///
+    /// The label is display metadata only. It survives inbound freezes but
+    /// is cleared when the agent is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent to label
+    /// * `label` - Label of at most `MAX_AGENT_LABEL_LEN` bytes
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Label stored
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
+    /// * `Err(ContractError::InvalidSymbol)` - Label is longer than `MAX_AGENT_LABEL_LEN` bytes
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_agent_label(env: Env, agent: Address, label: String) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
-        let mut remittance = get_remittance(&env, remittance_id)?;
 
-        if !remittance.status.can_transition_to(&RemittanceStatus::Finalized) {
-            return Err(ContractError::InvalidStateTransition);
+        validate_agent_registered(&env, &agent)?;
+
+        if label.len() > MAX_AGENT_LABEL_LEN {
+            return Err(ContractError::InvalidSymbol);
         }
 
-        remittance.status = RemittanceStatus::Finalized;
-        set_remittance(&env, remittance_id, &remittance);
+        set_agent_label(&env, &agent, &label);
 
         Ok(())
     }
 
-    /// Cancels a pending remittance and refunds the sender.
+    /// Returns an agent's label, if one is set.
+    pub fn get_agent_label(env: Env, agent: Address) -> Option<String> {
+        get_agent_label(&env, &agent)
+    }
+
+    /// Caps how many open remittances an agent will accept at once.
     ///
-    /// Returns the full remittance amount to the sender and marks the remittance
-    /// as cancelled. Can only be called by the original sender.
+    /// Open means pending or accepted. Once the agent is at the cap, new
+    /// remittances to it fail with `AgentAtCapacity` until one settles or
+    /// is cancelled.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to cancel
+    /// * `agent` - Registered agent address
+    /// * `max_pending` - Maximum open remittances (0 for unlimited)
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Remittance successfully cancelled and refunded
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Ok(())` - Cap updated
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address who created the remittance.
-    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
-
-        remittance.sender.require_auth();
+    /// Requires authentication from the agent.
+    pub fn set_agent_max_pending(
+        env: Env,
+        agent: Address,
+        max_pending: u32,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
+        validate_agent_registered(&env, &agent)?;
 
-        remittance.status = RemittanceStatus::Failed;
-        set_remittance(&env, remittance_id, &remittance);
+        set_agent_max_pending(&env, &agent, max_pending);
 
-        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
-        // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), remittance.amount);
+        Ok(())
+    }
 
-        log_cancel_remittance(&env, remittance_id);
+    /// Returns an agent's cap on open remittances (0 when unlimited).
+    pub fn get_agent_max_pending(env: Env, agent: Address) -> u32 {
+        get_agent_max_pending(&env, &agent)
+    }
 
-        Ok(())
+    /// Returns the number of open remittances assigned to an agent.
+    pub fn get_agent_pending_count(env: Env, agent: Address) -> u32 {
+        get_agent_pending_count(&env, &agent)
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    /// Caps how many open remittances the contract holds at once.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// A global throttle on storage and liability, separate from per-agent
+    /// caps and per-sender limits. Once at the cap, new remittances fail with
+    /// `TooManyActiveRemittances` until one settles or is cancelled.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
-    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// * `max_active` - Maximum open remittances (0 for unlimited)
     ///
     /// # Authorization
     ///
     /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let fees = validate_withdraw_fees_request(&env, &to)?;
-        
+    pub fn set_max_active_remittances(env: Env, max_active: u32) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
-
-        set_accumulated_fees(&env, 0);
+        set_max_active_remittances(&env, max_active);
 
-        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
-        // Used by off-chain systems to track revenue collection and maintain financial records
-        emit_fees_withdrawn(&env, to.clone(), fees);
+        Ok(())
+    }
 
-        log_withdraw_fees(&env, &to, fees);
+    /// Returns the contract-wide cap on open remittances (0 when unlimited).
+    pub fn get_max_active_remittances(env: Env) -> u32 {
+        get_max_active_remittances(&env)
+    }
 
-        Ok(())
+    /// Returns the number of open remittances across all agents.
+    pub fn get_active_remittance_count(env: Env) -> u32 {
+        get_active_remittance_count(&env)
     }
 
-    /// Retrieves a remittance record by ID.
+    /// Restricts the tokens an agent accepts remittances in.
+    ///
+    /// Creating or reassigning a remittance to the agent in a token outside
+    /// the list fails with `TokenNotWhitelisted`. An empty list lifts the
+    /// restriction.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to retrieve
+    /// * `caller` - The agent itself or an admin
+    /// * `agent` - Registered agent address
+    /// * `tokens` - Allowed tokens (at most `MAX_BATCH_SIZE`; empty for any)
     ///
     /// # Returns
     ///
-    /// * `Ok(Remittance)` - The remittance record
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
-        get_remittance(&env, remittance_id)
-    }
-
-    /// Query a remittance with a standardized response wrapper and request ID.
-    pub fn query_remittance(
+    /// * `Ok(())` - Allowed tokens updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the agent nor an admin
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` tokens
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`.
+    pub fn set_agent_allowed_tokens(
         env: Env,
-        remittance_id: u64,
-        request_id: soroban_sdk::String,
-    ) -> crate::response::Response<Remittance> {
-        match get_remittance(&env, remittance_id) {
-            Ok(remittance) => crate::response::Response::ok(remittance, request_id),
-            Err(e) => crate::response::Response::err(e as u32, request_id),
+        caller: Address,
+        agent: Address,
+        tokens: Vec<Address>,
+    ) -> Result<(), ContractError> {
+        if caller == agent {
+            caller.require_auth();
+        } else {
+            require_admin(&env, &caller)?;
         }
-    }
 
+        validate_agent_registered(&env, &agent)?;
 
-    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
-        get_accumulated_fees(&env)
+        if tokens.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        set_agent_allowed_tokens(&env, &agent, &tokens);
+
+        Ok(())
     }
 
-    /// Checks if an address is registered as an agent.
+    /// Returns the tokens an agent accepts (empty when unrestricted).
+    pub fn get_agent_allowed_tokens(env: Env, agent: Address) -> Vec<Address> {
+        get_agent_allowed_tokens(&env, &agent)
+    }
+
+    /// Sets whether agents may register themselves.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `agent` - Address to check
+    /// * `open` - `true` to allow `self_register_agent`, `false` for admin-only registration
     ///
-    /// # Returns
+    /// # Authorization
     ///
-    /// * `true` - Address is a registered agent
-    /// * `false` - Address is not registered
-    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
-        is_agent_registered(&env, &agent)
+    /// Requires authentication from the contract admin.
+    pub fn set_open_registration(env: Env, open: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_open_registration(&env, open);
+
+        Ok(())
     }
 
-    /// Retrieves the current platform fee rate.
+    /// Returns how new agents may be registered.
+    pub fn get_registration_mode(env: Env) -> RegistrationMode {
+        get_registration_mode(&env)
+    }
+
+    /// Sets the stake an agent must deposit to self-register.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `amount` - Stake in the settlement token (0 for none)
     ///
     /// # Returns
     ///
-    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
-        get_platform_fee_bps(&env)
+    /// * `Ok(())` - Registration stake updated
+    /// * `Err(ContractError::InvalidAmount)` - Amount is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_registration_stake(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_registration_stake(&env, amount);
+
+        Ok(())
     }
 
+    /// Returns the stake required to self-register (0 if none).
+    pub fn get_registration_stake(env: Env) -> i128 {
+        get_registration_stake(&env)
+    }
 
-    /// Retrieves the total number of successfully finalized settlements.
+    /// Registers the caller as an agent while open registration is enabled.
     ///
-    /// This is a read-only method that performs an O(1) constant-time read directly
-    /// from instance storage without iteration or recomputation. The counter is
-    /// incremented atomically each time a settlement is successfully finalized.
+    /// If a registration stake is configured, it is pulled from the agent in
+    /// the settlement token and locked as the agent's stake.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
+    /// * `agent` - Address registering itself as an agent
     ///
     /// # Returns
     ///
-    /// * `u64` - Total number of settlements processed (0 if none)
+    /// * `Ok(())` - Agent registered
+    /// * `Err(ContractError::RegistrationClosed)` - Registration mode is admin-only
+    /// * `Err(ContractError::AgentAlreadyRegistered)` - Address is already an agent
+    /// * `Err(ContractError::InsufficientBalance)` - Agent cannot cover the stake
     ///
-    /// # Performance
+    /// # Authorization
     ///
-    /// - O(1) constant-time operation
-    /// - Single storage read
-    /// - No iteration or computation
+    /// Requires authentication from the agent address.
+    pub fn self_register_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        if get_registration_mode(&env) != RegistrationMode::Open {
+            return Err(ContractError::RegistrationClosed);
+        }
+
+        agent.require_auth();
+
+        if is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentAlreadyRegistered);
+        }
+
+        let stake = get_registration_stake(&env);
+        if stake > 0 {
+            let usdc_token = get_usdc_token(&env)?;
+            let token_client = token::Client::new(&env, &usdc_token);
+
+            if token_client.balance(&agent) < stake {
+                return Err(ContractError::InsufficientBalance);
+            }
+
+            token_client.transfer(&agent, &env.current_contract_address(), &stake);
+
+            let new_stake = checked_add_i128(get_agent_stake(&env, &agent), stake)?;
+            set_agent_stake(&env, &agent, new_stake)?;
+            set_agent_staked_at(&env, &agent, env.ledger().timestamp());
+        }
+
+        set_agent_registered(&env, &agent, true);
+
+        emit_agent_registered(&env, agent, get_agent_count(&env));
+
+        Ok(())
+    }
+
+    /// Locks tokens from an agent as stake.
     ///
-    /// # Guarantees
+    /// Agents need a stake to receive remittances above the stake threshold.
+    /// Each deposit restarts the unstake cooldown.
     ///
-    /// - Read-only: Cannot modify storage
-    /// - Deterministic: Always returns same value for same state
-    /// - Consistent: Reflects all successfully finalized settlements
-    /// - Cannot be modified externally (no public setter)
+    /// # Arguments
     ///
-    /// # Examples
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent depositing stake
+    /// * `amount` - Amount to lock in the settlement token
     ///
-    /// ```ignore
-    /// let total = contract.get_total_settlements_count(&env);
-    /// println!("Total settlements processed: {}", total);
-    /// ```
-    pub fn get_total_settlements_count(env: Env) -> u64 {
-        get_settlement_counter(&env)
-
+    /// # Returns
+    ///
+    /// * `Ok(())` - Stake deposited
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InsufficientBalance)` - Agent cannot cover the amount
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn stake_agent(env: Env, agent: Address, amount: i128) -> Result<(), ContractError> {
+        validate_amount(amount)?;
+        validate_agent_registered(&env, &agent)?;
 
+        agent.require_auth();
 
-    pub fn get_integrator_fee_bps(env: Env) -> Result<u32, ContractError> {
-        get_integrator_fee_bps(&env)
-    }
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
 
-    pub fn get_accumulated_integrator_fees(env: Env) -> Result<i128, ContractError> {
-        get_accumulated_integrator_fees(&env)
+        if token_client.balance(&agent) < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
 
+        token_client.transfer(&agent, &env.current_contract_address(), &amount);
 
-    }
+        let total = checked_add_i128(get_agent_stake(&env, &agent), amount)?;
+        set_agent_stake(&env, &agent, total)?;
+        set_agent_staked_at(&env, &agent, env.ledger().timestamp());
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+        emit_agent_staked(&env, agent, amount, total);
 
-        set_paused(&env, true);
-        emit_paused(&env, caller);
         Ok(())
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
+    /// Moves part of an agent's stake into platform fees on proven misbehavior.
+    ///
+    /// The seized stake is collected like a settlement fee, so auto-sweep,
+    /// the fee cap and the lifetime fee total all apply to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent to slash
+    /// * `amount` - Amount of stake to seize
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Stake slashed
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientStake)` - Amount exceeds the agent's stake
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn slash_agent(env: Env, agent: Address, amount: i128) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
 
-        set_paused(&env, false);
-        emit_unpaused(&env, caller);
-        Ok(())
-    }
+        validate_amount(amount)?;
 
-    pub fn is_paused(env: Env) -> bool {
-        crate::storage::is_paused(&env)
-    }
-    
-    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+        let stake = get_agent_stake(&env, &agent);
+        if amount > stake {
+            return Err(ContractError::InsufficientStake);
+        }
 
-        let old_cooldown = get_rate_limit_cooldown(&env)?;
-        set_rate_limit_cooldown(&env, cooldown_seconds);
-        
-        emit_rate_limit_updated(&env, admin, old_cooldown, cooldown_seconds);
+        let remaining = stake - amount;
+        set_agent_stake(&env, &agent, remaining)?;
 
-        Ok(())
-    }
-    
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        collect_fee(&env, &token_client, amount)?;
+
+        emit_agent_slashed(&env, agent, amount, remaining);
+
+        Ok(())
+    }
+
+    /// Returns stake to an agent once the cooldown since the last deposit has elapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent withdrawing stake
+    /// * `amount` - Amount of stake to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Stake returned to the agent
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive
+    /// * `Err(ContractError::InsufficientStake)` - Amount exceeds the agent's stake
+    /// * `Err(ContractError::StakeLocked)` - Cooldown has not elapsed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn unstake_agent(env: Env, agent: Address, amount: i128) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.withdraw)?;
+        validate_amount(amount)?;
+
+        agent.require_auth();
+
+        let stake = get_agent_stake(&env, &agent);
+        if amount > stake {
+            return Err(ContractError::InsufficientStake);
+        }
+
+        let unlocks_at = get_agent_staked_at(&env, &agent).saturating_add(get_unstake_cooldown(&env));
+        if env.ledger().timestamp() < unlocks_at {
+            return Err(ContractError::StakeLocked);
+        }
+
+        let remaining = stake - amount;
+        set_agent_stake(&env, &agent, remaining)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_client.transfer(&env.current_contract_address(), &agent, &amount);
+
+        emit_agent_unstaked(&env, agent, amount, remaining);
+
+        Ok(())
+    }
+
+    /// Returns the tokens an agent has locked as stake.
+    pub fn get_agent_stake(env: Env, agent: Address) -> i128 {
+        get_agent_stake(&env, &agent)
+    }
+
+    /// Sets the remittance amount above which the agent must hold a stake.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `threshold` - Amount threshold (0 disables the requirement)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Threshold updated
+    /// * `Err(ContractError::InvalidAmount)` - Threshold is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_stake_threshold(env: Env, threshold: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if threshold < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_stake_threshold(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Returns the stake threshold (0 when disabled).
+    pub fn get_stake_threshold(env: Env) -> i128 {
+        get_stake_threshold(&env)
+    }
+
+    /// Sets how long stake stays locked after a deposit.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_unstake_cooldown(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_unstake_cooldown(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the unstake cooldown in seconds.
+    pub fn get_unstake_cooldown(env: Env) -> u64 {
+        get_unstake_cooldown(&env)
+    }
+
+    /// Updates the platform fee rate.
+    ///
+    /// Only the contract admin can update the fee. The new fee applies to all
+    /// remittances created after the update.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee successfully updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds 10000 bps
+    /// * `Err(ContractError::FeeAboveCap)` - Fee exceeds the configured fee cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_update_fee_request(&env, fee_bps)?;
+        
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        // Read the previous fee before overwriting it so the event carries the real old/new pair
+        let old_fee = get_platform_fee_bps(&env)?;
+        set_platform_fee_bps(&env, fee_bps);
+        emit_fee_updated(&env, caller.clone(), old_fee, fee_bps);
+
+        log_update_fee(&env, fee_bps);
+
+        Ok(())
+    }
+
+    /// Schedules a platform fee change to take effect at a future time.
+    ///
+    /// Remittances created before `effective_at` keep using the current fee;
+    /// the first creation at or after it switches the platform fee over.
+    /// Replaces any previously scheduled update.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `new_fee_bps` - Fee rate in basis points to apply
+    /// * `effective_at` - Timestamp (seconds since epoch) from which the fee applies
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee update scheduled
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds 10000 bps
+    /// * `Err(ContractError::FeeAboveCap)` - Fee exceeds the configured fee cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn schedule_fee_update(env: Env, new_fee_bps: u32, effective_at: u64) -> Result<(), ContractError> {
+        validate_fee_within_cap(&env, new_fee_bps)?;
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_scheduled_fee(&env, new_fee_bps, effective_at);
+        emit_fee_scheduled(&env, new_fee_bps, effective_at);
+
+        Ok(())
+    }
+
+    /// Returns the scheduled fee rate and its effective timestamp, if any.
+    pub fn get_scheduled_fee(env: Env) -> Option<(u32, u64)> {
+        get_scheduled_fee(&env)
+    }
+
+    /// Cancels a scheduled fee update that has not yet taken effect.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn cancel_scheduled_fee(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        clear_scheduled_fee(&env);
+
+        Ok(())
+    }
+
+    /// Overrides the platform fee for remittances in a specific token.
+    ///
+    /// Token values differ, so a single global rate may not fit every token.
+    /// Tokens without an override use the global platform fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token the override applies to
+    /// * `fee_bps` - Fee rate in basis points (max 10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Override stored
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds 10000 bps
+    /// * `Err(ContractError::FeeAboveCap)` - Fee exceeds the configured fee cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_token_fee_bps(env: Env, token: Address, fee_bps: u32) -> Result<(), ContractError> {
+        validate_fee_within_cap(&env, fee_bps)?;
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_token_fee_bps(&env, &token, fee_bps);
+
+        Ok(())
+    }
+
+    /// Sets an absolute minimum fee for new remittances.
+    ///
+    /// The creation-time fee becomes `max(bps_fee, min_fee)`, so tiny
+    /// remittances still cover operating cost. Remittances whose amount does
+    /// not exceed the floor are rejected rather than paying out nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `min_fee` - Minimum fee (0 disables the floor)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Floor updated
+    /// * `Err(ContractError::InvalidAmount)` - `min_fee` is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_min_fee(env: Env, min_fee: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if min_fee < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_min_fee(&env, min_fee);
+
+        Ok(())
+    }
+
+    /// Returns the minimum fee for new remittances (0 when disabled).
+    pub fn get_min_fee(env: Env) -> i128 {
+        get_min_fee(&env)
+    }
+
+    /// Sets the share of each payout withheld until the agent confirms delivery.
+    ///
+    /// With a non-zero holdback, `confirm_payout` releases only the rest of
+    /// the payout and marks the remittance `PartiallyReleased`; the agent
+    /// then calls `release_holdback` for the remainder. Zero keeps
+    /// settlement single-step.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `holdback_bps` - Holdback in basis points (0-10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Holdback updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Holdback above 10000 bps
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_holdback_bps(env: Env, holdback_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(holdback_bps)?;
+
+        set_holdback_bps(&env, holdback_bps);
+
+        Ok(())
+    }
+
+    /// Returns the payout holdback in basis points (0 when disabled).
+    pub fn get_holdback_bps(env: Env) -> u32 {
+        get_holdback_bps(&env)
+    }
+
+    /// Sets a ceiling that every configured fee rate must stay within.
+    ///
+    /// `update_fee`, `schedule_fee_update`, `set_token_fee_bps` and
+    /// `set_fee_tiers` reject rates above the cap, so a regulatory maximum
+    /// holds until the cap itself is changed. Defaults to 10000 bps.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `max_fee_bps` - Highest fee rate in basis points (max 10000)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Cap updated
+    /// * `Err(ContractError::InvalidFeeBps)` - Cap exceeds 10000 bps
+    /// * `Err(ContractError::FeeAboveCap)` - Current platform fee, a scheduled fee, or a
+    ///   configured fee tier would exceed the new cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_max_allowed_fee_bps(env: Env, max_fee_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_bps(max_fee_bps)?;
+        if get_platform_fee_bps(&env)? > max_fee_bps {
+            return Err(ContractError::FeeAboveCap);
+        }
+        if let Some((scheduled_bps, _)) = get_scheduled_fee(&env) {
+            if scheduled_bps > max_fee_bps {
+                return Err(ContractError::FeeAboveCap);
+            }
+        }
+        for tier in get_fee_tiers(&env).iter() {
+            if tier.fee_bps > max_fee_bps {
+                return Err(ContractError::FeeAboveCap);
+            }
+        }
+
+        set_max_allowed_fee_bps(&env, max_fee_bps);
+
+        Ok(())
+    }
+
+    /// Returns the ceiling on configured fee rates (10000 bps when unset).
+    pub fn get_max_allowed_fee_bps(env: Env) -> u32 {
+        get_max_allowed_fee_bps(&env)
+    }
+
+    /// Replaces the amount-based fee schedule in one call.
+    ///
+    /// A remittance pays the rate of the highest tier whose `min_amount` it
+    /// reaches; amounts below the first tier, or an empty schedule, fall back
+    /// to the platform fee. Per-token overrides still take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `tiers` - Tiers sorted by strictly ascending `min_amount`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Schedule replaced
+    /// * `Err(ContractError::InvalidFeeBps)` - Tiers are out of order, repeat a
+    ///   threshold, or have a negative threshold
+    /// * `Err(ContractError::FeeAboveCap)` - A tier's rate is above the fee cap
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fee_tiers(env: Env, tiers: Vec<FeeTier>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_tiers(&env, &tiers)?;
+
+        set_fee_tiers(&env, &tiers);
+
+        Ok(())
+    }
+
+    /// Returns the amount-based fee schedule (empty when unset).
+    pub fn get_fee_tiers(env: Env) -> Vec<FeeTier> {
+        get_fee_tiers(&env)
+    }
+
+    /// Returns the fee override for a token, if one is set.
+    pub fn get_token_fee_bps(env: Env, token: Address) -> Option<u32> {
+        get_token_fee_bps(&env, &token)
+    }
+
+    /// Sets the standing recipient for automatically swept fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `recipient` - Address that receives fees when auto-sweep is enabled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Recipient updated
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Recipient is the contract itself
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fee_recipient(env: Env, recipient: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_recipient_address(&env, &recipient)?;
+
+        set_fee_recipient(&env, &recipient);
+        emit_fee_recipient_updated(&env, caller, recipient);
+
+        Ok(())
+    }
+
+    /// Returns the configured fee recipient, if any.
+    pub fn get_fee_recipient(env: Env) -> Option<Address> {
+        get_fee_recipient(&env)
+    }
+
+    /// Enables or disables automatic fee sweeping on settlement.
+    ///
+    /// When enabled and a fee recipient is set, each settlement transfers the
+    /// fee it earns straight to the recipient instead of accumulating it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `enabled` - `true` to route fees to the recipient, `false` to accumulate
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Setting updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_auto_sweep(env: Env, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_auto_sweep(&env, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether fees are automatically swept on settlement.
+    pub fn is_auto_sweep(env: Env) -> bool {
+        is_auto_sweep(&env)
+    }
+
+    /// Sets the rounding applied when calculating remittance fees.
+    ///
+    /// Applies to remittances created after the update. Defaults to
+    /// `RoundingMode::Down` when never set.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `mode` - Rounding mode for the fee calculation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rounding mode updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_rounding_mode(env: Env, mode: RoundingMode) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_rounding_mode(&env, &mode);
+
+        Ok(())
+    }
+
+    /// Returns the rounding mode used for fee calculation.
+    pub fn get_rounding_mode(env: Env) -> RoundingMode {
+        get_rounding_mode(&env)
+    }
+
+    /// Sets which completion events settlements emit.
+    ///
+    /// `Full` (the default) emits both `SettlementCompleted` and
+    /// `RemittanceCompleted`; `Minimal` emits only `SettlementCompleted`,
+    /// halving event cost for high-volume operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `verbosity` - Event verbosity for settlements
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_event_verbosity(env: Env, verbosity: EventVerbosity) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_event_verbosity(&env, &verbosity);
+
+        Ok(())
+    }
+
+    /// Returns which completion events settlements emit.
+    pub fn get_event_verbosity(env: Env) -> EventVerbosity {
+        get_event_verbosity(&env)
+    }
+
+    /// Caps the fees retained by the contract, forwarding any excess to a treasury.
+    ///
+    /// When a settlement would push accumulated fees above `cap`, the excess
+    /// is transferred to `treasury` immediately. A cap of zero disables the
+    /// behavior.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `cap` - Maximum fees to retain (0 to disable)
+    /// * `treasury` - Address that receives fees above the cap
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee cap updated
+    /// * `Err(ContractError::InvalidAmount)` - Cap is negative
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Treasury is the contract itself
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fee_cap(env: Env, cap: i128, treasury: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if cap < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        validate_recipient_address(&env, &treasury)?;
+
+        if cap == 0 {
+            clear_fee_cap(&env);
+        } else {
+            set_fee_cap(&env, cap, &treasury);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fee cap and its treasury, if a cap is set.
+    pub fn get_fee_cap(env: Env) -> Option<(i128, Address)> {
+        get_fee_cap(&env)
+    }
+
+    /// Sets the minimum net payout a settlement may transfer to an agent.
+    ///
+    /// Prevents dust settlements where the fee consumes nearly the whole
+    /// amount. A value of zero disables the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `min_payout` - Minimum payout amount (0 to disable)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Minimum updated
+    /// * `Err(ContractError::InvalidAmount)` - Minimum is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_min_payout(env: Env, min_payout: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if min_payout < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_min_payout(&env, min_payout);
+
+        Ok(())
+    }
+
+    /// Returns the minimum net payout (0 when disabled).
+    pub fn get_min_payout(env: Env) -> i128 {
+        get_min_payout(&env)
+    }
+
+    /// Overrides the minimum net payout for settlements in a specific token.
+    ///
+    /// A dust threshold depends on the token's value and decimals, so each
+    /// token can carry its own minimum in its own units. Tokens without an
+    /// override use the global minimum payout.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token the override applies to
+    /// * `min_payout` - Minimum payout in the token's units (0 disables the check for it)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Override stored
+    /// * `Err(ContractError::InvalidAmount)` - Minimum is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_token_min_payout(env: Env, token: Address, min_payout: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if min_payout < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_token_min_payout(&env, &token, min_payout);
+
+        Ok(())
+    }
+
+    /// Returns the minimum payout override for a token, if one is set.
+    pub fn get_token_min_payout(env: Env, token: Address) -> Option<i128> {
+        get_token_min_payout(&env, &token)
+    }
+
+    /// Sets the default lifetime for remittances created without an expiry.
+    ///
+    /// When `create_remittance` receives `None`, the stored expiry becomes
+    /// the creation time plus this many seconds. Zero keeps such remittances
+    /// open-ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `seconds` - Default validity window (0 to disable)
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_default_validity_seconds(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_default_validity_seconds(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the default validity window in seconds (0 when disabled).
+    pub fn get_default_validity_seconds(env: Env) -> u64 {
+        get_default_validity_seconds(&env)
+    }
+
+    /// Sets the default validity window for remittances of at least `min_amount`.
+    ///
+    /// When `create_remittance` receives `None`, the highest band whose
+    /// `min_amount` the amount reaches supplies the validity window; amounts
+    /// below every band fall back to `set_default_validity_seconds`. Setting
+    /// an existing threshold replaces its window, and zero seconds removes
+    /// the band.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `min_amount` - Smallest remittance amount the band applies to
+    /// * `seconds` - Validity window for the band (0 to remove it)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Band stored or removed
+    /// * `Err(ContractError::InvalidAmount)` - `min_amount` is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_validity_band(env: Env, min_amount: i128, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if min_amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut bands = get_validity_bands(&env);
+        let mut index = bands.len();
+        for (i, band) in bands.iter().enumerate() {
+            if band.min_amount >= min_amount {
+                index = i as u32;
+                break;
+            }
+        }
+
+        let replaces = bands
+            .get(index)
+            .map(|band| band.min_amount == min_amount)
+            .unwrap_or(false);
+        if replaces {
+            bands.remove(index);
+        }
+        if seconds > 0 {
+            bands.insert(index, ValidityBand { min_amount, seconds });
+        }
+
+        set_validity_bands(&env, &bands);
+
+        Ok(())
+    }
+
+    /// Returns the amount-based default validity bands (empty when unset).
+    pub fn get_validity_bands(env: Env) -> Vec<ValidityBand> {
+        get_validity_bands(&env)
+    }
+
+    /// Sets how long after expiry an agent may still settle a remittance.
+    ///
+    /// Within `[expiry, expiry + grace]` settlement still succeeds (emitting a
+    /// `("settle", "grace")` event) and `reclaim_expired` is refused, so a
+    /// slightly late agent keeps priority over the sender's refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `seconds` - Grace window (0 makes expiry a hard cutoff)
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_expiry_grace_seconds(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_expiry_grace_seconds(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the expiry grace window in seconds (0 when disabled).
+    pub fn get_expiry_grace_seconds(env: Env) -> u64 {
+        get_expiry_grace_seconds(&env)
+    }
+
+    /// Sets how long a remittance must exist before its sender may cancel it.
+    ///
+    /// Applies to `cancel_remittance` and `batch_cancel`; `mutual_cancel`
+    /// needs the agent's consent and is not restricted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `seconds` - Minimum age in seconds (0 allows immediate cancellation)
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_min_cancel_age_seconds(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_min_cancel_age_seconds(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the minimum cancellation age in seconds (0 when disabled).
+    pub fn get_min_cancel_age_seconds(env: Env) -> u64 {
+        get_min_cancel_age_seconds(&env)
+    }
+
+    /// Enables or disables the diagnostic balance-check event on settlement.
+    ///
+    /// When enabled, `confirm_payout` and `batch_settle_with_netting` emit the
+    /// contract's post-settlement token balance and accumulated fees. Off by
+    /// default to avoid event noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `enabled` - Whether to emit balance-check events
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn emit_balance_events(env: Env, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_balance_events_enabled(&env, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether balance-check events are emitted on settlement.
+    pub fn is_balance_events_enabled(env: Env) -> bool {
+        is_balance_events_enabled(&env)
+    }
+
+    /// Creates a new remittance transaction.
+    ///
+    /// Transfers the specified amount from the sender to the contract, calculates
+    /// the platform fee, and creates a pending remittance record. The agent can later
+    /// confirm the payout to receive the amount minus fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::RollingLimitExceeded)` - Sender would exceed a rolling window limit
+    /// * `Err(ContractError::InsufficientBalance)` - Sender holds less than `amount`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        Self::create_remittance_with_tag(env, sender, agent, amount, expiry, 0)
+    }
+
+    /// Creates a new remittance labelled with a reporting category.
+    ///
+    /// Behaves exactly like `create_remittance`; `tag` is stored on the
+    /// remittance and published in the created event so clients can group
+    /// transfers (e.g. payroll vs. one-off) without an external indexer.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `tag` - Client-defined category (0 means untagged)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+   pub fn create_remittance_with_tag(
+    env: Env,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+    expiry: Option<u64>,
+    tag: u32,
+) -> Result<u64, ContractError> {
+    validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+    sender.require_auth();
+
+    validate_rolling_limits(&env, &sender, amount)?;
+
+    let usdc_token = get_usdc_token(&env)?;
+    let token_client = token::Client::new(&env, &usdc_token);
+
+    promote_scheduled_fee(&env);
+    let fee = remittance_fee(&env, &usdc_token, amount)?;
+    let fee_bps = fee_bps_for_token(&env, &usdc_token, amount)?;
+
+    // Fail with a contract error instead of an opaque token panic
+    if token_client.balance(&sender) < amount {
+        return Err(ContractError::InsufficientBalance);
+    }
+
+    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+
+    let counter = get_remittance_counter(&env)?;
+    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+    let expiry = resolve_expiry(&env, expiry, amount)?;
+
+    let remittance = Remittance {
+        id: remittance_id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        amount,
+        fee,
+        status: RemittanceStatus::Pending,
+        expiry,
+        settle_by: None,
+        late: false,
+        quote_amount: None,
+        quote_currency: None,
+        created_at: env.ledger().timestamp(),
+        recipient: None,
+        tag,
+        cancel_reason: 0,
+        conditional: false,
+        failed_attempts: 0,
+    };
+
+    set_remittance(&env, remittance_id, &remittance);
+    set_remittance_counter(&env, remittance_id);
+    add_outstanding_liability(&env, amount)?;
+    increment_agent_pending_count(&env, &agent)?;
+    record_user_transfer(&env, &sender, amount);
+
+    emit_remittance_created(&env, remittance_id, sender, agent, amount, fee, fee_bps, tag, 0);
+
+    Self::trip_auto_pause(&env, amount);
+
+    Ok(remittance_id)  // ← capital O
+}
+
+    /// Creates a remittance only if its fee does not exceed the sender's cap.
+    ///
+    /// Protects a sender against a fee change landing in the same ledger: the
+    /// fee is resolved exactly as `create_remittance` would, and the call
+    /// fails without moving funds if it is above `max_fee`. `None` behaves
+    /// like `create_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `max_fee` - Highest fee the sender accepts, if any
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::FeeAboveCap)` - The resolved fee exceeds `max_fee`
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance_with_max_fee(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+        max_fee: Option<i128>,
+    ) -> Result<u64, ContractError> {
+        if let Some(max_fee) = max_fee {
+            let usdc_token = get_usdc_token(&env)?;
+            if remittance_fee(&env, &usdc_token, amount)? > max_fee {
+                return Err(ContractError::FeeAboveCap);
+            }
+        }
+
+        Self::create_remittance(env, sender, agent, amount, expiry)
+    }
+
+    /// Creates a remittance at most once per client-supplied idempotency key.
+    ///
+    /// If the sender already created a remittance with `idempotency_key`, the
+    /// existing ID is returned and nothing is transferred, so clients can
+    /// safely retry after an RPC timeout. Keys are scoped to the sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `idempotency_key` - Client-generated key identifying this logical request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the new or previously created remittance
+    /// * Any error returned by `create_remittance` when a new remittance is created
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address when a new remittance is created.
+    pub fn create_remittance_idempotent(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+        idempotency_key: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        if let Some(existing) = get_idempotent_remittance(&env, &sender, &idempotency_key) {
+            return Ok(existing);
+        }
+
+        let remittance_id =
+            Self::create_remittance(env.clone(), sender.clone(), agent, amount, expiry)?;
+        set_idempotent_remittance(&env, &sender, &idempotency_key, remittance_id);
+
+        Ok(remittance_id)
+    }
+
+    /// Runs the checks shared by every path that settles a single remittance.
+    fn validate_settlement(env: &Env, remittance_id: u64, remittance: &Remittance) -> Result<(), ContractError> {
+        validate_remittance_settleable(remittance)?;
+        validate_condition_met(env, remittance)?;
+
+        // Check for duplicate settlement execution
+        if has_settlement_hash(env, remittance_id) {
+            return Err(ContractError::DuplicateSettlement);
+        }
+
+        // Check if settlement has expired (allowing the grace window)
+        validate_settlement_not_expired(env, remittance.expiry)?;
+
+        // Check rate limit for sender
+        storage::check_rate_limit(env, &remittance.sender)?;
+
+        Ok(())
+    }
+
+    /// Pays a validated remittance out in USDC and books the settlement.
+    ///
+    /// Every single-remittance payout path ends here and differs only in who
+    /// receives the USDC. The configured holdback stays in the contract until
+    /// `release_holdback` pays it to `payee`.
+    fn settle_remittance(
+        env: &Env,
+        remittance: &mut Remittance,
+        payout_amount: i128,
+        payee: &Address,
+    ) -> Result<(), ContractError> {
+        let held = Self::holdback_amount(env, payout_amount)?;
+        let released = payout_amount - held;
+
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+
+        // Fail with a contract error instead of an opaque token panic
+        if token_client.balance(&env.current_contract_address()) < released {
+            return Err(ContractError::InsufficientContractBalance);
+        }
+
+        token_client.transfer(&env.current_contract_address(), payee, &released);
+
+        collect_fee(env, &token_client, remittance.fee)?;
+
+        Self::finalize_settlement(env, remittance, payout_amount, held, payee)?;
+
+        if is_balance_events_enabled(env) {
+            let balance = token_client.balance(&env.current_contract_address());
+            emit_balance_check(env, balance, get_accumulated_fees(env)?);
+        }
+
+        Ok(())
+    }
+
+    /// Part of a payout withheld until the agent confirms delivery.
+    fn holdback_amount(env: &Env, payout_amount: i128) -> Result<i128, ContractError> {
+        calculate_fee(payout_amount, get_holdback_bps(env), &RoundingMode::Down)
+    }
+
+    /// Books a settlement whose released payout has already been transferred.
+    ///
+    /// Moves the remittance to `Completed`, or to `PartiallyReleased` while
+    /// `held` is outstanding, and records liability, agent stats, receipt and
+    /// events. `payee` received the payout and is owed any holdback.
+    fn finalize_settlement(
+        env: &Env,
+        remittance: &mut Remittance,
+        payout_amount: i128,
+        held: i128,
+        payee: &Address,
+    ) -> Result<(), ContractError> {
+        let remittance_id = remittance.id;
+
+        // Flag SLA breaches without blocking the payout
+        remittance.late = is_past_settle_by(env, remittance);
+        if is_in_expiry_grace(env, remittance.expiry) {
+            emit_settled_in_grace(env, remittance_id, remittance.agent.clone(), remittance.expiry.unwrap_or(0));
+        }
+
+        if held > 0 {
+            remittance.status = RemittanceStatus::PartiallyReleased;
+            set_holdback(env, remittance_id, held);
+            set_holdback_payee(env, remittance_id, Some(payee));
+        } else {
+            remittance.status = RemittanceStatus::Completed;
+        }
+        set_remittance(env, remittance_id, remittance);
+        sub_outstanding_liability(env, remittance.amount - held)?;
+        decrement_agent_pending_count(env, &remittance.agent);
+
+        // Mark settlement as executed to prevent duplicates
+        set_settlement_hash(env, remittance_id);
+
+        // Update last settlement time for rate limiting
+        set_last_settlement_time(env, &remittance.sender, env.ledger().timestamp());
+
+        // Increment settlement counter atomically after successful finalization
+        increment_settlement_counter(env)?;
+
+        record_agent_completion(env, &remittance.agent, payout_amount)?;
+        let nonce = increment_agent_settlement_nonce(env, &remittance.agent)?;
+
+        let receipt = compute_settlement_receipt(
+            env,
+            remittance_id,
+            &remittance.sender,
+            &remittance.agent,
+            payout_amount,
+            env.ledger().timestamp(),
+        );
+        set_settlement_receipt(env, remittance_id, &receipt);
+
+        // Emit settlement completion event exactly once
+        // This event is emitted after all state transitions are committed
+        // and includes safeguards to prevent duplicate emission
+        if !has_settlement_event_emitted(env, remittance_id) {
+            emit_settlement_completed(
+                env,
+                remittance_id,
+                remittance.sender.clone(),
+                payee.clone(),
+                get_usdc_token(env)?,
+                payout_amount,
+                receipt,
+            );
+            set_settlement_event_emitted(env, remittance_id);
+        }
+
+        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
+        // Used by off-chain systems to track successful settlements and update transaction status
+        if get_event_verbosity(env) == EventVerbosity::Full {
+            emit_remittance_completed(env, remittance_id, remittance.agent.clone(), payout_amount, nonce);
+        }
+
+        if remittance.late {
+            emit_late_settlement(env, remittance_id, remittance.agent.clone(), remittance.settle_by.unwrap_or(0));
+        }
+
+        Ok(())
+    }
+
+    /// Creates a remittance priced in a quote currency.
+    ///
+    /// `quote_amount` is converted into the settlement token at the admin-set
+    /// rate for `quote_currency`; the remittance then escrows the converted
+    /// amount exactly like `create_remittance`. Both the quote and the
+    /// settled amount are stored on the record.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address sending the remittance
+    /// * `agent` - Registered agent who will pay out
+    /// * `quote_amount` - Amount in the quote currency
+    /// * `quote_currency` - Quote currency code (e.g. "MXN")
+    /// * `expiry` - Optional expiry timestamp
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the new remittance
+    /// * `Err(ContractError::FxRateNotSet)` - No rate is configured for `quote_currency`;
+    ///   currencies are only known through their rates, so this also covers an unknown code
+    /// * `Err(ContractError::InvalidAmount)` - Quote amount is not positive or converts to zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `sender`.
+    pub fn create_remittance_quoted(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        quote_amount: i128,
+        quote_currency: String,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        if quote_amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let rate_bps = get_quote_rate(&env, &quote_currency).ok_or(ContractError::FxRateNotSet)?;
+        let amount = quote_amount
+            .checked_mul(10000)
+            .ok_or(ContractError::Overflow)?
+            .checked_div(rate_bps as i128)
+            .ok_or(ContractError::Overflow)?;
+
+        let remittance_id = Self::create_remittance(env.clone(), sender, agent, amount, expiry)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.quote_amount = Some(quote_amount);
+        remittance.quote_currency = Some(quote_currency);
+        set_remittance(&env, remittance_id, &remittance);
+
+        Ok(remittance_id)
+    }
+
+    /// Lists remittances carrying a given tag, in ID order.
+    ///
+    /// Scans the IDs in `[start_id, start_id + limit)` (`limit` capped at
+    /// `MAX_BATCH_SIZE`) and returns the matches among them, so a page may be
+    /// empty even when later IDs match. Call again with `start_id + limit`
+    /// for the next page.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `tag` - Tag to filter by
+    /// * `start_id` - First remittance ID to scan
+    /// * `limit` - Number of IDs to scan
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Remittance>)` - Matching remittances in the scanned range
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn list_remittances_by_tag(
+        env: Env,
+        tag: u32,
+        start_id: u64,
+        limit: u32,
+    ) -> Result<Vec<Remittance>, ContractError> {
+        let mut matches = Vec::new(&env);
+
+        for id in Self::id_window(&env, start_id, limit)? {
+            if let Ok(remittance) = get_remittance(&env, id) {
+                if remittance.tag == tag {
+                    matches.push_back(remittance);
+                }
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Returns the remittance IDs a paged scan starting at `start_id` covers.
+    ///
+    /// The window is `[start_id, start_id + limit)`, with `start_id` raised to
+    /// 1, `limit` capped at `MAX_BATCH_SIZE`, and the end clamped to the last
+    /// issued ID.
+    fn id_window(env: &Env, start_id: u64, limit: u32) -> Result<core::ops::Range<u64>, ContractError> {
+        let counter = get_remittance_counter(env)?;
+        let start_id = start_id.max(1);
+        let end_id = start_id
+            .saturating_add(limit.min(MAX_BATCH_SIZE) as u64)
+            .min(counter.saturating_add(1));
+
+        Ok(start_id..end_id)
+    }
+
+    /// Creates a remittance paid out to a third-party recipient.
+    ///
+    /// For corridors where the agent only processes the payout: the agent
+    /// still authorizes settlement, but the funds are released to
+    /// `recipient` instead of the agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Registered agent who authorizes the payout
+    /// * `recipient` - Address that receives the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_remittance_with_recipient(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        recipient: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_recipient_address(&env, &recipient)?;
+
+        let remittance_id = Self::create_remittance(env.clone(), sender, agent, amount, expiry)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.recipient = Some(recipient);
+        set_remittance(&env, remittance_id, &remittance);
+
+        Ok(remittance_id)
+    }
+
+    /// Creates a remittance and accepts it on the agent's behalf in one call.
+    ///
+    /// For pre-arranged transfers where the agent can co-sign (e.g. an agent
+    /// contract), this saves the separate `accept_remittance` round-trip.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Registered agent committing to the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the new remittance, in `Accepted` status
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both the sender and the agent.
+    pub fn create_and_accept(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        agent.require_auth();
+
+        let remittance_id = Self::create_remittance(env.clone(), sender, agent, amount, expiry)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.status = RemittanceStatus::Accepted;
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_remittance_accepted(&env, remittance_id, remittance.agent);
+
+        Ok(remittance_id)
+    }
+
+    /// Creates a remittance whose settlement waits on an off-chain condition.
+    ///
+    /// Behaves like `create_remittance`, but no settlement path will pay it
+    /// out until the oracle marks the condition met with `set_condition`
+    /// (e.g. once KYC clears); until then settlement fails with
+    /// `ConditionNotMet`. The sender can still cancel while it waits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the new conditional remittance
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_conditional_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        let remittance_id = Self::create_remittance(env.clone(), sender, agent, amount, expiry)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.conditional = true;
+        set_remittance(&env, remittance_id, &remittance);
+
+        Ok(remittance_id)
+    }
+
+    /// Sets the oracle allowed to resolve conditional remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `oracle` - Address that will call `set_condition`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_oracle(env: Env, oracle: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_oracle(&env, &oracle);
+
+        Ok(())
+    }
+
+    /// Returns the oracle address, if one is set.
+    pub fn get_oracle(env: Env) -> Option<Address> {
+        get_oracle(&env)
+    }
+
+    /// Marks a conditional remittance's off-chain condition as met or unmet.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the conditional remittance
+    /// * `met` - True to release the remittance for settlement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Condition updated
+    /// * `Err(ContractError::OracleNotSet)` - No oracle has been set
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not conditional
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the oracle.
+    pub fn set_condition(env: Env, remittance_id: u64, met: bool) -> Result<(), ContractError> {
+        let oracle = get_oracle(&env).ok_or(ContractError::OracleNotSet)?;
+        oracle.require_auth();
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        if !remittance.conditional {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        set_condition_met(&env, remittance_id, met);
+
+        emit_condition_set(&env, remittance_id, oracle, met);
+
+        Ok(())
+    }
+
+    /// Returns whether a conditional remittance's condition has been met.
+    pub fn is_condition_met(env: Env, remittance_id: u64) -> bool {
+        is_condition_met(&env, remittance_id)
+    }
+
+    /// Creates a remittance that a recipient claims with a secret code.
+    ///
+    /// Instead of an agent, the remittance is locked to `code_hash`, a
+    /// commitment to the claim code and the recipient's address (see
+    /// `compute_claim_code_hash`). The sender shares the code out of band and
+    /// only that recipient can claim with it via `claim_remittance`, so a
+    /// code observed in a pending transaction cannot be redirected. An
+    /// unclaimed remittance can be reclaimed by the sender with
+    /// `reclaim_expired` once it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the remittance
+    /// * `amount` - Amount to remit in USDC (must be positive)
+    /// * `code_hash` - SHA-256 of the claim code followed by the recipient's address
+    /// * `expiry` - Optional expiry timestamp after which the code stops working
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - ID of the new remittance
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::InsufficientBalance)` - Sender holds less than `amount`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_claimable(
+        env: Env,
+        sender: Address,
+        amount: i128,
+        code_hash: BytesN<32>,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.create)?;
+        validate_accepting_new(&env)?;
+        validate_active_remittance_cap(&env, 1)?;
+        validate_address(&env, &sender)?;
+        validate_sender_allowed(&env, &sender)?;
+        validate_amount(amount)?;
+
+        sender.require_auth();
+
+        validate_rolling_limits(&env, &sender, amount)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        validate_token_not_denied(&env, &usdc_token)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        promote_scheduled_fee(&env);
+        let fee = remittance_fee(&env, &usdc_token, amount)?;
+        let fee_bps = fee_bps_for_token(&env, &usdc_token, amount)?;
+
+        if token_client.balance(&sender) < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        let expiry = resolve_expiry(&env, expiry, amount)?;
+
+        // The contract stands in for the agent, so only a claim can settle it
+        let remittance = Remittance {
+            id: remittance_id,
+            sender: sender.clone(),
+            agent: env.current_contract_address(),
+            amount,
+            fee,
+            status: RemittanceStatus::Pending,
+            expiry,
+            settle_by: None,
+            late: false,
+            quote_amount: None,
+            quote_currency: None,
+            created_at: env.ledger().timestamp(),
+            recipient: None,
+            tag: 0,
+            cancel_reason: 0,
+            conditional: false,
+            failed_attempts: 0,
+        };
+
+        set_remittance(&env, remittance_id, &remittance);
+        set_remittance_counter(&env, remittance_id);
+        set_claim_code_hash(&env, remittance_id, &code_hash);
+        add_outstanding_liability(&env, amount)?;
+        increment_agent_pending_count(&env, &remittance.agent)?;
+        record_user_transfer(&env, &sender, amount);
+
+        emit_remittance_created(&env, remittance_id, sender, remittance.agent, amount, fee, fee_bps, 0, 0);
+
+        Self::trip_auto_pause(&env, amount);
+
+        Ok(remittance_id)
+    }
+
+    /// Pays out a claimable remittance to the recipient its claim code was issued for.
+    ///
+    /// The claim settles like any other payout: the holdback, agent stats,
+    /// receipt and late flag all apply, and `recipient` is recorded on the
+    /// remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the claimable remittance
+    /// * `code` - Claim code shared by the sender
+    /// * `recipient` - Address to receive the payout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout (amount minus fee) sent to `recipient`
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is already settled or cancelled
+    /// * `Err(ContractError::SettlementExpired)` - Expiry has passed
+    /// * `Err(ContractError::InvalidClaimCode)` - Remittance is not claimable, or code and recipient do not match the commitment
+    /// * `Err(ContractError::InvalidRecipientAddress)` - `recipient` is the contract itself
+    ///
+    /// # Authorization
+    ///
+    /// Possession of the claim code authorizes the payout to the committed
+    /// recipient; no signature is required.
+    pub fn claim_remittance(
+        env: Env,
+        remittance_id: u64,
+        code: Bytes,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        let code_hash = get_claim_code_hash(&env, remittance_id).ok_or(ContractError::InvalidClaimCode)?;
+        if compute_claim_code_hash(&env, &code, &recipient) != code_hash {
+            return Err(ContractError::InvalidClaimCode);
+        }
+
+        validate_recipient_address(&env, &recipient)?;
+        Self::validate_settlement(&env, remittance_id, &remittance)?;
+
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+
+        validate_min_payout(&env, payout_amount)?;
+
+        remittance.recipient = Some(recipient.clone());
+        Self::settle_remittance(&env, &mut remittance, payout_amount, &recipient)?;
+
+        Ok(())
+    }
+
+    /// Creates one remittance per leg from a single funding transfer.
+    ///
+    /// Every leg is validated before any funds move or state is written, and
+    /// agent capacity and the active remittance cap are checked against the
+    /// split as a whole; the sender authorizes once and the total is pulled
+    /// in a single transfer. Each resulting remittance is independent and can
+    /// be settled or cancelled on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding all legs
+    /// * `legs` - Agent and amount for each remittance (at most `MAX_BATCH_SIZE`)
+    /// * `expiry` - Optional expiry timestamp applied to every leg
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - Remittance IDs in leg order
+    /// * `Err(ContractError::InvalidAmount)` - No legs, or a leg amount is not positive
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` legs
+    /// * `Err(ContractError::AgentNotRegistered)` - A leg's agent is not registered
+    /// * `Err(ContractError::AgentAtCapacity)` - An agent cannot take all of its legs
+    /// * `Err(ContractError::TooManyActiveRemittances)` - The legs would exceed the active remittance cap
+    /// * `Err(ContractError::InsufficientBalance)` - Sender cannot fund the total
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_split_remittance(
+        env: Env,
+        sender: Address,
+        legs: Vec<SplitLeg>,
+        expiry: Option<u64>,
+    ) -> Result<Vec<u64>, ContractError> {
+        if legs.is_empty() {
+            return Err(ContractError::InvalidAmount);
+        }
+        if legs.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut total: i128 = 0;
+        let mut legs_per_agent: Map<Address, u32> = Map::new(&env);
+        for leg in legs.iter() {
+            validate_create_remittance_request(&env, &sender, &leg.agent, leg.amount)?;
+            total = checked_add_i128(total, leg.amount)?;
+            let count = legs_per_agent.get(leg.agent.clone()).unwrap_or(0);
+            legs_per_agent.set(leg.agent.clone(), count + 1);
+        }
+
+        // Capacity applies to the split as a whole, not leg by leg
+        for (agent, count) in legs_per_agent.iter() {
+            validate_agent_capacity(&env, &agent, count)?;
+        }
+        validate_active_remittance_cap(&env, legs.len())?;
+
+        sender.require_auth();
+
+        validate_rolling_limits(&env, &sender, total)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        if token_client.balance(&sender) < total {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        token_client.transfer(&sender, &env.current_contract_address(), &total);
+
+        promote_scheduled_fee(&env);
+
+        let mut remittance_id = get_remittance_counter(&env)?;
+        let mut ids = Vec::new(&env);
+
+        for leg in legs.iter() {
+            remittance_id = remittance_id.checked_add(1).ok_or(ContractError::Overflow)?;
+            let expiry = resolve_expiry(&env, expiry, leg.amount)?;
+
+            let fee = remittance_fee(&env, &usdc_token, leg.amount)?;
+            let fee_bps = fee_bps_for_token(&env, &usdc_token, leg.amount)?;
+
+            let remittance = Remittance {
+                id: remittance_id,
+                sender: sender.clone(),
+                agent: leg.agent.clone(),
+                amount: leg.amount,
+                fee,
+                status: RemittanceStatus::Pending,
+                expiry,
+                settle_by: None,
+                late: false,
+                quote_amount: None,
+                quote_currency: None,
+                created_at: env.ledger().timestamp(),
+                recipient: None,
+                tag: 0,
+                cancel_reason: 0,
+                conditional: false,
+                failed_attempts: 0,
+            };
+
+            set_remittance(&env, remittance_id, &remittance);
+            add_outstanding_liability(&env, leg.amount)?;
+            increment_agent_pending_count(&env, &leg.agent)?;
+            record_user_transfer(&env, &sender, leg.amount);
+            ids.push_back(remittance_id);
+
+            emit_remittance_created(&env, remittance_id, sender.clone(), leg.agent, leg.amount, fee, fee_bps, 0, 0);
+        }
+
+        set_remittance_counter(&env, remittance_id);
+        Self::trip_auto_pause(&env, total);
+
+        Ok(ids)
+    }
+
+    /// Pre-authorizes an agent to draw remittances from the sender up to a total.
+    ///
+    /// Replaces any previous allowance for the pair. The contract is approved
+    /// on the token for the sender's total allowance across all agents, so
+    /// later draws need no fresh sender signature. The token approval expires
+    /// after `TTL_EXTEND_TO` ledgers; approving again renews it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address funding the remittances
+    /// * `agent` - Registered agent allowed to draw on the allowance
+    /// * `total` - Total amount the agent may draw (0 revokes)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Allowance stored and token approval updated
+    /// * `Err(ContractError::InvalidAmount)` - Total is negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn approve_agent_allowance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        total: i128,
+    ) -> Result<(), ContractError> {
+        if total < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        validate_agent_registered(&env, &agent)?;
+
+        sender.require_auth();
+
+        let sender_total = set_allowance(&env, &sender, &agent, total)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_client.approve(
+            &sender,
+            &env.current_contract_address(),
+            &sender_total,
+            &(env.ledger().sequence() + TTL_EXTEND_TO),
+        );
+
+        Ok(())
+    }
+
+    /// Returns the remaining allowance a sender has approved for an agent.
+    pub fn get_allowance(env: Env, sender: Address, agent: Address) -> i128 {
+        get_allowance(&env, &sender, &agent)
+    }
+
+    /// Creates a remittance funded from a sender's pre-approved agent allowance.
+    ///
+    /// Pulls `amount` from the sender under the token approval granted by
+    /// `approve_agent_allowance` and reduces the remaining allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address that approved the allowance
+    /// * `agent` - Agent drawing on the allowance
+    /// * `amount` - Amount to remit (must be positive)
+    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - ID of the created remittance
+    /// * `Err(ContractError::AllowanceExceeded)` - Amount exceeds the remaining allowance
+    /// * `Err(ContractError::InsufficientBalance)` - Sender cannot cover the amount
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address; the sender authorized
+    /// up front when approving the allowance.
+    pub fn create_remittance_from_allowance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
+
+        agent.require_auth();
+
+        let remaining = get_allowance(&env, &sender, &agent);
+        if amount > remaining {
+            return Err(ContractError::AllowanceExceeded);
+        }
+
+        validate_rolling_limits(&env, &sender, amount)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        promote_scheduled_fee(&env);
+        let fee = remittance_fee(&env, &usdc_token, amount)?;
+        let fee_bps = fee_bps_for_token(&env, &usdc_token, amount)?;
+
+        if token_client.balance(&sender) < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let contract_address = env.current_contract_address();
+        token_client.transfer_from(&contract_address, &sender, &contract_address, &amount);
+        set_allowance(&env, &sender, &agent, remaining - amount)?;
+
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        let expiry = resolve_expiry(&env, expiry, amount)?;
+
+        let remittance = Remittance {
+            id: remittance_id,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            fee,
+            status: RemittanceStatus::Pending,
+            expiry,
+            settle_by: None,
+            late: false,
+            quote_amount: None,
+            quote_currency: None,
+            created_at: env.ledger().timestamp(),
+            recipient: None,
+            tag: 0,
+            cancel_reason: 0,
+            conditional: false,
+            failed_attempts: 0,
+        };
+
+        set_remittance(&env, remittance_id, &remittance);
+        set_remittance_counter(&env, remittance_id);
+        add_outstanding_liability(&env, amount)?;
+        increment_agent_pending_count(&env, &agent)?;
+        record_user_transfer(&env, &sender, amount);
+
+        emit_remittance_created(&env, remittance_id, sender, agent, amount, fee, fee_bps, 0, 0);
+
+        Self::trip_auto_pause(&env, amount);
+
+        Ok(remittance_id)
+    }
+
+    /// Accepts a pending remittance on behalf of its assigned agent.
+    ///
+    /// Signals that the agent will service the payout. Once accepted, the sender
+    /// can no longer cancel the remittance; only `confirm_payout` may proceed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to accept
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance moved to Accepted status
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn accept_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        remittance.status = RemittanceStatus::Accepted;
+        set_remittance(&env, remittance_id, &remittance);
+
+        // Event: Remittance accepted - Fires when the agent commits to servicing a remittance
+        // Used by off-chain systems to tell senders their payout is being handled
+        emit_remittance_accepted(&env, remittance_id, remittance.agent.clone());
+
+        Ok(())
+    }
+
+    /// Confirms a remittance payout to the agent.
+    ///
+    /// Transfers the remittance amount (minus platform fee) to the agent and marks
+    /// the remittance as completed. Includes duplicate settlement protection and
+    /// expiry validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout successfully confirmed and transferred
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Payout destination is the contract itself
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Err(ContractError::InsufficientContractBalance)` - Contract balance cannot cover the payout
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        remittance.agent.require_auth();
+
+        Self::execute_payout(env, remittance_id, remittance)
+    }
+
+    /// Confirms a payout, recording the attempt on the remittance if it is rejected.
+    ///
+    /// A contract error rolls back every storage write of the call, so
+    /// `confirm_payout` cannot leave a trace when it fails. This variant
+    /// returns `Ok(false)` instead when settlement is refused (paused,
+    /// expired, duplicate or not settleable), after incrementing the
+    /// remittance's `failed_attempts` and emitting a `("settle", "failed")`
+    /// event carrying the error code.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - Payout settled
+    /// * `Ok(false)` - Settlement was refused and the failure recorded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * Any error `confirm_payout` returns once settlement has started
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance.
+    pub fn attempt_payout(env: Env, remittance_id: u64) -> Result<bool, ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.agent.require_auth();
+
+        match validate_confirm_payout_request(&env, remittance_id) {
+            Ok(remittance) => {
+                Self::execute_payout(env, remittance_id, remittance)?;
+                Ok(true)
+            }
+            Err(error) => {
+                remittance.failed_attempts = remittance.failed_attempts.saturating_add(1);
+                set_remittance(&env, remittance_id, &remittance);
+
+                emit_settlement_failed(
+                    &env,
+                    remittance_id,
+                    remittance.agent,
+                    error as u32,
+                    remittance.failed_attempts,
+                );
+
+                Ok(false)
+            }
+        }
+    }
+
+    /// Confirms a payout using an agent signature submitted by a relayer.
+    ///
+    /// The agent signs the payload from `compute_payout_signing_payload`
+    /// (contract address, network ID, then `remittance_id` and `nonce` as
+    /// big-endian u64) off-chain with the Ed25519 key registered via
+    /// `set_agent_public_key`. Each nonce can be used once per agent, and the
+    /// contract address and network ID keep a signature from being replayed
+    /// on another deployment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `agent` - Agent assigned to the remittance
+    /// * `signature` - Ed25519 signature over the message
+    /// * `nonce` - Single-use nonce chosen by the agent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout settled
+    /// * `Err(ContractError::Unauthorized)` - `agent` is not the remittance's agent or has no public key
+    /// * `Err(ContractError::NonceReused)` - Nonce has already been used
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// An Ed25519 signature that does not verify is not a `ContractError`:
+    /// the host's `ed25519_verify` traps, so the invocation aborts and the
+    /// caller sees a host error instead.
+    ///
+    /// # Authorization
+    ///
+    /// No transaction signature is required; the agent's Ed25519 signature
+    /// stands in for it.
+    pub fn confirm_payout_signed(
+        env: Env,
+        remittance_id: u64,
+        agent: Address,
+        signature: BytesN<64>,
+        nonce: u64,
+    ) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        if remittance.agent != agent {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if is_agent_nonce_used(&env, &agent, nonce) {
+            return Err(ContractError::NonceReused);
+        }
+
+        let public_key = get_agent_public_key(&env, &agent).ok_or(ContractError::Unauthorized)?;
+
+        let message = compute_payout_signing_payload(&env, remittance_id, nonce);
+        env.crypto().ed25519_verify(&public_key, &message, &signature);
+
+        set_agent_nonce_used(&env, &agent, nonce);
+
+        Self::execute_payout(env, remittance_id, remittance)
+    }
+
+    /// Confirms a payout on behalf of the remittance's agent.
+    ///
+    /// Lets an agent running several tellers have each confirm under the
+    /// agent's account. The payout still goes to the agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `operator` - Operator registered by the remittance's agent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout settled
+    /// * `Err(ContractError::Unauthorized)` - `operator` is not an operator of the remittance's agent
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the operator.
+    pub fn confirm_payout_by_operator(
+        env: Env,
+        remittance_id: u64,
+        operator: Address,
+    ) -> Result<(), ContractError> {
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        if !is_agent_operator(&env, &remittance.agent, &operator) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        operator.require_auth();
+
+        let agent = remittance.agent.clone();
+        Self::execute_payout(env.clone(), remittance_id, remittance)?;
+
+        emit_operator_confirmed(&env, remittance_id, agent, operator);
+
+        Ok(())
+    }
+
+    /// Authorizes an address to confirm payouts on the agent's behalf.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent address
+    /// * `operator` - Address to authorize
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Operator added
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn add_agent_operator(env: Env, agent: Address, operator: Address) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        set_agent_operator(&env, &agent, &operator, true);
+
+        Ok(())
+    }
+
+    /// Revokes an operator's ability to confirm payouts for the agent.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn remove_agent_operator(env: Env, agent: Address, operator: Address) {
+        agent.require_auth();
+
+        set_agent_operator(&env, &agent, &operator, false);
+    }
+
+    /// Returns whether `operator` may confirm payouts for `agent`.
+    pub fn is_agent_operator(env: Env, agent: Address, operator: Address) -> bool {
+        is_agent_operator(&env, &agent, &operator)
+    }
+
+    /// Registers the Ed25519 key an agent uses to sign off-chain payout authorizations.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent address
+    /// * `public_key` - Ed25519 public key
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Key stored
+    /// * `Err(ContractError::AgentNotRegistered)` - Address is not a registered agent
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn set_agent_public_key(
+        env: Env,
+        agent: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        set_agent_public_key(&env, &agent, &public_key);
+
+        Ok(())
+    }
+
+    /// Returns the Ed25519 key registered for an agent, if any.
+    pub fn get_agent_public_key(env: Env, agent: Address) -> Option<BytesN<32>> {
+        get_agent_public_key(&env, &agent)
+    }
+
+    /// Settles a remittance once the caller has established the agent's authorization.
+    fn execute_payout(
+        env: Env,
+        remittance_id: u64,
+        mut remittance: Remittance,
+    ) -> Result<(), ContractError> {
+        Self::validate_settlement(&env, remittance_id, &remittance)?;
+
+        // Validate the payee address before transfer
+        let payee = remittance.payee();
+        validate_recipient_address(&env, &payee)?;
+
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?
+            .checked_sub(remittance.integrator_fee)
+            .ok_or(ContractError::Overflow)?;
+
+        validate_min_payout(&env, payout_amount)?;
+
+        Self::settle_remittance(&env, &mut remittance, payout_amount, &payee)?;
+
+        let current_integrator_fees = get_accumulated_integrator_fees(&env)?;
+        let new_integrator_fees = current_integrator_fees
+            .checked_add(remittance.integrator_fee)
+            .ok_or(ContractError::Overflow)?;
+        set_accumulated_integrator_fees(&env, new_integrator_fees);
+
+        // Capture ledger timestamp for settlement creation
+        let current_time = env.ledger().timestamp();
+        set_settlement_timestamp(&env, remittance_id, current_time);
+
+        log_confirm_payout(&env, remittance_id, payout_amount);
+
+        Ok(remittance_id)
+    }
+
+    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        if !remittance.status.can_transition_to(&RemittanceStatus::Finalized) {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        remittance.status = RemittanceStatus::Finalized;
+        set_remittance(&env, remittance_id, &remittance);
+
+        Ok(())
+    }
+
+    /// Pays out the held-back part of a partially released remittance.
+    ///
+    /// Called by the agent once cash has been delivered; the remittance then
+    /// moves to `Completed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the partially released remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Holdback paid and remittance completed
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not partially released
+    /// * `Err(ContractError::InsufficientContractBalance)` - Contract balance cannot cover the holdback
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance's agent, or from the sender
+    /// for a claimable remittance, which has no agent.
+    pub fn release_holdback(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.confirm)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if remittance.agent == env.current_contract_address() {
+            remittance.sender.require_auth();
+        } else {
+            remittance.agent.require_auth();
+        }
+
+        if remittance.status != RemittanceStatus::PartiallyReleased {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let held = get_holdback(&env, remittance_id);
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        if token_client.balance(&env.current_contract_address()) < held {
+            return Err(ContractError::InsufficientContractBalance);
+        }
+
+        let payee = get_holdback_payee(&env, remittance_id).unwrap_or_else(|| remittance.payee());
+        token_client.transfer(&env.current_contract_address(), &payee, &held);
+
+        remittance.status = RemittanceStatus::Completed;
+        set_remittance(&env, remittance_id, &remittance);
+        set_holdback(&env, remittance_id, 0);
+        set_holdback_payee(&env, remittance_id, None);
+        sub_outstanding_liability(&env, held)?;
+
+        emit_holdback_released(&env, remittance_id, remittance.agent, held);
+
+        Ok(())
+    }
+
+    /// Returns the payout amount still held back for a remittance (0 if none).
+    pub fn get_holdback(env: Env, remittance_id: u64) -> i128 {
+        get_holdback(&env, remittance_id)
+    }
+
+    /// Cancels a pending remittance and refunds the sender.
+    ///
+    /// Returns the full remittance amount to the sender and marks the remittance
+    /// as cancelled. Can only be called by the original sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully cancelled and refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::CancelTooSoon)` - Remittance is younger than the minimum cancellation age
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        Self::cancel_remittance_with_reason(env, remittance_id, 0)
+    }
+
+    /// Cancels a pending remittance, recording why the sender cancelled.
+    ///
+    /// Behaves exactly like `cancel_remittance`. `reason` is a client-defined
+    /// code (e.g. 1 = changed mind, 2 = wrong details) stored on the
+    /// remittance and published in the cancellation event for analytics.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    /// * `reason` - Cancellation reason code (0 for unspecified)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully cancelled and refunded
+    /// * Any error returned by `cancel_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn cancel_remittance_with_reason(
+        env: Env,
+        remittance_id: u64,
+        reason: u32,
+    ) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        Self::cancel_pending(&env, &mut remittance, reason)
+    }
+
+    /// Refunds the sender in full and moves a validated open remittance to
+    /// `Cancelled`.
+    ///
+    /// Every path that refunds a remittance goes through here, so each one
+    /// releases the liability and agent capacity and closes any open refund
+    /// request the same way. Callers that need their own event (refund
+    /// approval, rejection, expiry) emit it after this one.
+    fn cancel_pending(env: &Env, remittance: &mut Remittance, reason: u32) -> Result<(), ContractError> {
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &remittance.amount,
+        );
+
+        remittance.status = RemittanceStatus::Cancelled;
+        remittance.cancel_reason = reason;
+        set_remittance(env, remittance.id, remittance);
+        set_refund_requested(env, remittance.id, false);
+        sub_outstanding_liability(env, remittance.amount)?;
+        decrement_agent_pending_count(env, &remittance.agent);
+
+        // Event: Remittance cancelled - Fires whenever a remittance is cancelled and the sender receives a full refund
+        // Used by off-chain systems to track cancellations and update transaction status
+        emit_remittance_cancelled(env, remittance.id, remittance.sender.clone(), remittance.amount, reason);
+
+        log_cancel_remittance(env, remittance.id);
+
+        Ok(())
+    }
+
+    /// Asks for a pending remittance to be refunded.
+    ///
+    /// For disputes raised before the agent accepts: the request is only a
+    /// flag until the agent or an admin resolves it with `approve_refund` or
+    /// `deny_refund`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to refund
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Refund requested
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending
+    /// * `Err(ContractError::RefundAlreadyRequested)` - A refund request is already open
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn request_refund(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+        if is_refund_requested(&env, remittance_id) {
+            return Err(ContractError::RefundAlreadyRequested);
+        }
+
+        set_refund_requested(&env, remittance_id, true);
+
+        emit_refund_requested(&env, remittance_id, remittance.sender);
+
+        Ok(())
+    }
+
+    /// Approves a refund request, returning the full amount to the sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - The remittance's agent or an admin
+    /// * `remittance_id` - ID of the remittance to refund
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Sender refunded and remittance cancelled
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the agent nor an admin
+    /// * `Err(ContractError::NoRefundRequested)` - No refund has been requested
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is no longer pending
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`.
+    pub fn approve_refund(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        Self::require_agent_or_admin(&env, &caller, &remittance.agent)?;
+
+        if !is_refund_requested(&env, remittance_id) {
+            return Err(ContractError::NoRefundRequested);
+        }
+        validate_remittance_pending(&remittance)?;
+
+        Self::cancel_pending(&env, &mut remittance, 0)?;
+
+        emit_refund_approved(&env, remittance_id, caller, remittance.amount);
+
+        Ok(())
+    }
+
+    /// Denies a refund request, leaving the remittance pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - The remittance's agent or an admin
+    /// * `remittance_id` - ID of the remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Request cleared
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the agent nor an admin
+    /// * `Err(ContractError::NoRefundRequested)` - No refund has been requested
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`.
+    pub fn deny_refund(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        Self::require_agent_or_admin(&env, &caller, &remittance.agent)?;
+
+        if !is_refund_requested(&env, remittance_id) {
+            return Err(ContractError::NoRefundRequested);
+        }
+
+        set_refund_requested(&env, remittance_id, false);
+
+        emit_refund_denied(&env, remittance_id, caller);
+
+        Ok(())
+    }
+
+    /// Lets the assigned agent decline a pending remittance it cannot service.
+    ///
+    /// The full amount is refunded to the sender and the remittance is
+    /// cancelled, clearing any open refund request.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to reject
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Sender refunded and remittance cancelled
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is no longer pending
+    /// * `Err(ContractError::ContractPaused)` - Cancellations are paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance's agent.
+    pub fn reject_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.agent.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        Self::cancel_pending(&env, &mut remittance, 0)?;
+
+        emit_remittance_rejected(
+            &env,
+            remittance_id,
+            remittance.agent,
+            remittance.sender,
+            remittance.amount,
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether a refund request is open for a remittance.
+    pub fn is_refund_requested(env: Env, remittance_id: u64) -> bool {
+        is_refund_requested(&env, remittance_id)
+    }
+
+    /// Requires `caller` to be `agent` or an admin, authenticating it either way.
+    fn require_agent_or_admin(env: &Env, caller: &Address, agent: &Address) -> Result<(), ContractError> {
+        if caller == agent {
+            caller.require_auth();
+            Ok(())
+        } else {
+            require_admin(env, caller)
+        }
+    }
+
+    /// Cancels many of a sender's pending remittances in one call.
+    ///
+    /// IDs that do not exist, belong to another sender, are no longer
+    /// pending, or are younger than the minimum cancellation age are skipped
+    /// rather than failing the batch. Each remaining remittance is cancelled
+    /// exactly as `cancel_remittance_with_reason` would, with the same
+    /// terminal status, reason and event.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender whose remittances should be cancelled
+    /// * `ids` - Remittance IDs to cancel (at most `MAX_BATCH_SIZE`)
+    /// * `reason` - Cancellation reason code recorded on every entry (0 for unspecified)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Number of remittances cancelled
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` IDs
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender once for the whole batch.
+    pub fn batch_cancel(
+        env: Env,
+        sender: Address,
+        ids: Vec<u64>,
+        reason: u32,
+    ) -> Result<u32, ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        if ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        sender.require_auth();
+
+        let mut cancelled: u32 = 0;
+
+        for id in ids.iter() {
+            let mut remittance = match validate_cancel_remittance_request(&env, id) {
+                Ok(remittance) if remittance.sender == sender => remittance,
+                _ => continue,
+            };
+
+            Self::cancel_pending(&env, &mut remittance, reason)?;
+            cancelled += 1;
+        }
+
+        Ok(cancelled)
+    }
+
+    /// Cancels a pending or accepted remittance with consent from both parties.
+    ///
+    /// Once accepted, a remittance can no longer be cancelled by the sender
+    /// alone; this lets sender and agent agree to abort and refund the sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance cancelled and sender refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is already completed or cancelled
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both the sender and the agent of the remittance.
+    pub fn mutual_cancel(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+        remittance.agent.require_auth();
+
+        // Pending and accepted are the only non-terminal states
+        validate_remittance_settleable(&remittance)?;
+
+        Self::cancel_pending(&env, &mut remittance, 0)?;
+
+        emit_mutual_cancelled(
+            &env,
+            remittance_id,
+            remittance.sender.clone(),
+            remittance.agent.clone(),
+            remittance.amount,
+        );
+
+        Ok(())
+    }
+
+    /// Tops up or reduces the amount of a pending remittance.
+    ///
+    /// Pulls the difference from the sender when increasing, refunds it when
+    /// decreasing, and recomputes the fee at the current fee rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to adjust
+    /// * `new_amount` - New total amount (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Amount and fee updated
+    /// * `Err(ContractError::InvalidAmount)` - `new_amount` is zero or negative
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::InsufficientBalance)` - Sender cannot cover the increase
+    /// * `Err(ContractError::RollingLimitExceeded)` - Increase would exceed a rolling window limit
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn adjust_amount(env: Env, remittance_id: u64, new_amount: i128) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.create)?;
+        validate_amount(new_amount)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        let old_amount = remittance.amount;
+        Self::apply_amount_change(&env, &mut remittance, new_amount)?;
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_amount_adjusted(
+            &env,
+            remittance_id,
+            remittance.sender.clone(),
+            old_amount,
+            new_amount,
+            remittance.fee,
+        );
+
+        Ok(())
+    }
+
+    /// Moves a pending remittance to `new_amount`, settling the difference
+    /// with the sender and recomputing the fee. The caller persists the record.
+    fn apply_amount_change(
+        env: &Env,
+        remittance: &mut Remittance,
+        new_amount: i128,
+    ) -> Result<(), ContractError> {
+        let delta = new_amount
+            .checked_sub(remittance.amount)
+            .ok_or(ContractError::Overflow)?;
+
+        let usdc_token = get_usdc_token(env)?;
+        let token_client = token::Client::new(env, &usdc_token);
+
+        if delta > 0 {
+            validate_agent_stake(env, &remittance.agent, new_amount)?;
+            validate_rolling_limits(env, &remittance.sender, delta)?;
+            if token_client.balance(&remittance.sender) < delta {
+                return Err(ContractError::InsufficientBalance);
+            }
+            token_client.transfer(&remittance.sender, &env.current_contract_address(), &delta);
+            add_outstanding_liability(env, delta)?;
+            record_user_transfer(env, &remittance.sender, delta);
+        } else if delta < 0 {
+            let refund = -delta;
+            token_client.transfer(&env.current_contract_address(), &remittance.sender, &refund);
+            sub_outstanding_liability(env, refund)?;
+        }
+
+        remittance.amount = new_amount;
+        remittance.fee = remittance_fee(env, &usdc_token, new_amount)?;
+
+        Ok(())
+    }
+
+    /// Moves a pending remittance to a different registered agent.
+    ///
+    /// Lets the sender route around an unavailable agent without cancelling
+    /// and recreating the remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to reassign
+    /// * `new_agent` - Registered agent that will service the payout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent updated
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending
+    /// * `Err(ContractError::AgentNotRegistered)` - New agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn reassign_agent(env: Env, remittance_id: u64, new_agent: Address) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        if !is_agent_registered(&env, &new_agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        validate_agent_not_frozen(&env, &new_agent)?;
+        validate_agent_stake(&env, &new_agent, remittance.amount)?;
+        validate_agent_capacity(&env, &new_agent, 1)?;
+        validate_agent_accepts_token(&env, &new_agent, &get_usdc_token(&env)?)?;
+
+        let old_agent = remittance.agent.clone();
+        remittance.agent = new_agent.clone();
+        set_remittance(&env, remittance_id, &remittance);
+        decrement_agent_pending_count(&env, &old_agent);
+        increment_agent_pending_count(&env, &new_agent)?;
+
+        emit_agent_reassigned(&env, remittance_id, old_agent, new_agent);
+
+        Ok(())
+    }
+
+    /// Pushes out the expiry of a pending remittance.
+    ///
+    /// Lets the sender give the agent more time without cancelling and
+    /// recreating the remittance, which would change its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to extend
+    /// * `new_expiry` - New expiry timestamp (seconds since epoch)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Expiry extended
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::InvalidExpiry)` - `new_expiry` is not later than the current expiry,
+    ///   or the remittance has no expiry to extend
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn extend_expiry(env: Env, remittance_id: u64, new_expiry: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        let old_expiry = match remittance.expiry {
+            Some(expiry) if new_expiry > expiry => expiry,
+            _ => return Err(ContractError::InvalidExpiry),
+        };
+
+        remittance.expiry = Some(new_expiry);
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_expiry_extended(&env, remittance_id, remittance.sender.clone(), old_expiry, new_expiry);
+
+        Ok(())
+    }
+
+    /// Changes the amount and/or expiry of a pending remittance in one call.
+    ///
+    /// Fields passed as `None` are left unchanged. An amount change moves the
+    /// difference to or from the sender and recomputes the fee, exactly as
+    /// `adjust_amount` does. Unlike `extend_expiry`, the new expiry may be
+    /// earlier than the current one as long as it is not in the past.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to amend
+    /// * `new_amount` - New total amount, if changing
+    /// * `new_expiry` - New expiry timestamp (seconds since epoch), if changing
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance amended
+    /// * `Err(ContractError::InvalidAmount)` - `new_amount` is zero or negative
+    /// * `Err(ContractError::InvalidExpiry)` - `new_expiry` is earlier than the current time
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::InsufficientBalance)` - Sender cannot cover the increase
+    /// * `Err(ContractError::RollingLimitExceeded)` - Increase would exceed a rolling window limit
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn amend_remittance(
+        env: Env,
+        remittance_id: u64,
+        new_amount: Option<i128>,
+        new_expiry: Option<u64>,
+    ) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.create)?;
+        if let Some(amount) = new_amount {
+            validate_amount(amount)?;
+        }
+        if let Some(expiry) = new_expiry {
+            if expiry < env.ledger().timestamp() {
+                return Err(ContractError::InvalidExpiry);
+            }
+        }
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        let old_amount = remittance.amount;
+        let old_expiry = remittance.expiry;
+
+        if let Some(amount) = new_amount {
+            Self::apply_amount_change(&env, &mut remittance, amount)?;
+        }
+        if new_expiry.is_some() {
+            remittance.expiry = new_expiry;
+        }
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_remittance_amended(
+            &env,
+            remittance_id,
+            remittance.sender.clone(),
+            old_amount,
+            remittance.amount,
+            remittance.fee,
+            old_expiry,
+            remittance.expiry,
+        );
+
+        Ok(())
+    }
+
+    /// Sets a soft settlement deadline on a pending remittance.
+    ///
+    /// Unlike `expiry`, passing `settle_by` never blocks settlement; the
+    /// remittance is marked `late` and a late-settlement event is emitted so
+    /// SLA breaches can be penalized off-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance
+    /// * `settle_by` - Deadline timestamp (seconds since epoch)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Deadline set
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn set_settle_by(env: Env, remittance_id: u64, settle_by: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_pending(&remittance)?;
+
+        remittance.settle_by = Some(settle_by);
+        set_remittance(&env, remittance_id, &remittance);
+
+        Ok(())
+    }
+
+    /// Reclaims an expired, unsettled remittance and refunds the sender.
+    ///
+    /// The full remittance amount is always returned: fees are only earned on a
+    /// completed settlement, never on expiry or cancellation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the expired remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance cancelled and `amount` refunded in full
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending or Accepted
+    /// * `Err(ContractError::CancelTooSoon)` - No expiry set, or expiry plus the grace window has not passed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn reclaim_expired(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        validate_remittance_settleable(&remittance)?;
+
+        // The agent keeps priority until the grace window after expiry closes
+        match remittance.expiry {
+            Some(expiry_time)
+                if env.ledger().timestamp() > expiry_time.saturating_add(get_expiry_grace_seconds(&env)) => {}
+            _ => return Err(ContractError::CancelTooSoon),
+        }
+
+        Self::cancel_pending(&env, &mut remittance, 0)?;
+
+        record_agent_expiry(&env, &remittance.agent)?;
+
+        emit_remittance_reclaimed(&env, remittance_id, remittance.sender.clone(), remittance.amount);
+
+        Ok(())
+    }
+
+    /// Refunds expired remittances on their senders' behalf and rewards the caller.
+    ///
+    /// Anyone may run the sweep. It scans the IDs in `[start_id, start_id + limit)`
+    /// (`limit` capped at `MAX_BATCH_SIZE`) and refunds every remittance there
+    /// that `reclaim_expired` would accept. The keeper earns `keeper_reward`
+    /// per refund, paid from accumulated fees; once those run out the sweep
+    /// continues unpaid. Pass the returned `next_id` as the next `start_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `keeper` - Address running the sweep and receiving the reward
+    /// * `start_id` - First remittance ID to scan
+    /// * `limit` - Number of IDs to scan
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(KeeperSweepResult)` - Refund count and the cursor for the next page
+    /// * `Err(ContractError::ContractPaused)` - Cancellations are paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the keeper.
+    pub fn keeper_sweep_expired(
+        env: Env,
+        keeper: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> Result<KeeperSweepResult, ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.cancel)?;
+
+        keeper.require_auth();
+
+        let window = Self::id_window(&env, start_id, limit)?;
+        let end_id = window.end;
+        let cutoff = env.ledger().timestamp();
+        let grace = get_expiry_grace_seconds(&env);
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        let keeper_reward = get_keeper_reward(&env);
+        let mut fees = get_accumulated_fees(&env)?;
+        let mut reward: i128 = 0;
+        let mut refunded: u32 = 0;
+
+        for id in window {
+            let mut remittance = match get_remittance(&env, id) {
+                Ok(remittance) => remittance,
+                Err(_) => continue,
+            };
+            if validate_remittance_settleable(&remittance).is_err() {
+                continue;
+            }
+            match remittance.expiry {
+                Some(expiry_time) if cutoff > expiry_time.saturating_add(grace) => {}
+                _ => continue,
+            }
+
+            Self::cancel_pending(&env, &mut remittance, 0)?;
+            record_agent_expiry(&env, &remittance.agent)?;
+
+            emit_remittance_reclaimed(&env, id, remittance.sender.clone(), remittance.amount);
+
+            // Never pay out more than the fees actually accumulated
+            let payment = keeper_reward.min(fees).max(0);
+            fees -= payment;
+            reward = checked_add_i128(reward, payment)?;
+            refunded += 1;
+        }
+
+        if reward > 0 {
+            set_accumulated_fees(&env, fees);
+            token_client.transfer(&env.current_contract_address(), &keeper, &reward);
+        }
+
+        if refunded > 0 {
+            emit_keeper_sweep(&env, keeper, refunded, reward);
+        }
+
+        let next_id = if end_id > get_remittance_counter(&env)? { 0 } else { end_id };
+
+        Ok(KeeperSweepResult { refunded, next_id })
+    }
+
+    /// Sets the reward paid to keepers per expired remittance they refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `reward` - Reward per refund, paid from accumulated fees (0 to disable)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Reward updated
+    /// * `Err(ContractError::InvalidAmount)` - Reward is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_keeper_reward(env: Env, reward: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if reward < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_keeper_reward(&env, reward);
+
+        Ok(())
+    }
+
+    /// Returns the keeper reward per refund (0 when disabled).
+    pub fn get_keeper_reward(env: Env) -> i128 {
+        get_keeper_reward(&env)
+    }
+
+    /// Withdraws accumulated platform fees to a specified address.
+    ///
+    /// Transfers all accumulated fees to the recipient address and resets the
+    /// fee counter to zero. Only the contract admin can withdraw fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the withdrawn fees
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Recipient is the contract itself
+    /// * `Err(ContractError::InsufficientContractBalance)` - Withdrawal would leave less than
+    ///   outstanding liability, agent stake and the liquidity floor
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let fees = validate_withdraw_fees_request(&env, &to)?;
+        
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        validate_liquidity_floor(&env, token_client.balance(&env.current_contract_address()), fees)?;
+        token_client.transfer(&env.current_contract_address(), &to, &fees);
+
+        set_accumulated_fees(&env, 0);
+
+        // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
+        // Used by off-chain systems to track revenue collection and maintain financial records
+        emit_fees_withdrawn(&env, to.clone(), fees);
+
+        log_withdraw_fees(&env, &to, fees);
+
+        Ok(())
+    }
+
+    /// Withdraws part of the accumulated platform fees to a specified address.
+    ///
+    /// Like `withdraw_fees`, but transfers only `amount` and leaves the rest
+    /// accumulated.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the withdrawn fees
+    /// * `amount` - Amount of fees to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidAmount)` - `amount` is zero or negative
+    /// * `Err(ContractError::NoFeesToWithdraw)` - `amount` exceeds the accumulated fees
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Recipient is the contract itself
+    /// * `Err(ContractError::InsufficientContractBalance)` - Withdrawal would leave less than
+    ///   outstanding liability, agent stake and the liquidity floor
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_fees_amount(env: Env, to: Address, amount: i128) -> Result<(), ContractError> {
+        validate_amount(amount)?;
+        let fees = validate_withdraw_fees_request(&env, &to)?;
+        if amount > fees {
+            return Err(ContractError::NoFeesToWithdraw);
+        }
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        validate_liquidity_floor(&env, token_client.balance(&env.current_contract_address()), amount)?;
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        set_accumulated_fees(&env, fees - amount);
+
+        emit_fees_withdrawn(&env, to.clone(), amount);
+
+        log_withdraw_fees(&env, &to, amount);
+
+        Ok(())
+    }
+
+    /// Withdraws accumulated fees in every token the contract earns fees in.
+    ///
+    /// Fees currently accrue only in the settlement token, so this delegates to
+    /// `withdraw_fees`; unlike it, a zero balance is a no-op rather than an
+    /// error, making it safe to call on a schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the withdrawn fees
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All nonzero fee balances withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidRecipientAddress)` - Recipient is the contract itself
+    /// * `Err(ContractError::InsufficientContractBalance)` - Withdrawal would leave less than
+    ///   outstanding liability, agent stake and the liquidity floor
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_all_fees(env: Env, to: Address) -> Result<(), ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.withdraw)?;
+        validate_recipient_address(&env, &to)?;
+
+        // Fees accrue only in the settlement token, so this is `withdraw_fees`
+        // minus the error on an empty balance
+        if get_accumulated_fees(&env)? <= 0 {
+            let caller = get_admin(&env)?;
+            require_admin(&env, &caller)?;
+            return Ok(());
+        }
+
+        Self::withdraw_fees(env, to)
+    }
+
+    /// Sets the balance admin withdrawals must leave on top of outstanding liability.
+    ///
+    /// `withdraw_fees`, `withdraw_all_fees` and `sweep_yield` refuse to take
+    /// the contract's balance below escrowed principal plus this floor, so
+    /// refunds stay covered even if an admin withdraws carelessly.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `floor` - Liquidity floor (0 to require only liability coverage)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Floor updated
+    /// * `Err(ContractError::InvalidAmount)` - `floor` is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_liquidity_floor(env: Env, floor: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if floor < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_liquidity_floor(&env, floor);
+
+        Ok(())
+    }
+
+    /// Returns the liquidity floor (0 when unset).
+    pub fn get_liquidity_floor(env: Env) -> i128 {
+        get_liquidity_floor(&env)
+    }
+
+    /// Retrieves a remittance record by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Retrieves several remittance records in one call.
+    ///
+    /// Remittances are returned in the order their IDs were given; IDs that
+    /// do not exist are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `ids` - Remittance IDs to look up
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Remittance>)` - The remittances that were found
+    /// * `Err(ContractError::InvalidBatchSize)` - More than `MAX_BATCH_SIZE` IDs
+    pub fn get_remittances(env: Env, ids: Vec<u64>) -> Result<Vec<Remittance>, ContractError> {
+        if ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut remittances = Vec::new(&env);
+        for id in ids.iter() {
+            if let Ok(remittance) = get_remittance(&env, id) {
+                remittances.push_back(remittance);
+            }
+        }
+
+        Ok(remittances)
+    }
+
+    /// Checks whether a remittance exists without loading it.
+    ///
+    /// Useful for clients polling for an ID assigned in an earlier
+    /// transaction. Same lookup as `settlement_exists`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `id` - Remittance ID to look up
+    ///
+    /// # Returns
+    ///
+    /// * `true` - A remittance exists for this ID
+    /// * `false` - The ID was never created
+    pub fn remittance_exists(env: Env, id: u64) -> bool {
+        has_remittance(&env, id)
+    }
+
+    /// Retrieves a settlement record by remittance ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `id` - ID of the remittance backing the settlement
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The settlement record
+    /// * `Err(ContractError::RemittanceNotFound)` - No record exists for this ID
+    pub fn get_settlement(env: Env, id: u64) -> Result<Remittance, ContractError> {
+        get_remittance(&env, id)
+    }
+
+    /// Retrieves the settlement receipt for a completed remittance.
+    ///
+    /// The receipt is SHA-256 over `(remittance_id, sender, agent,
+    /// payout_amount, timestamp)` — see `compute_settlement_receipt` for the
+    /// canonical encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the settled remittance
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BytesN<32>)` - Receipt recorded at settlement
+    /// * `None` - Remittance has not been settled
+    pub fn get_settlement_receipt(env: Env, remittance_id: u64) -> Option<BytesN<32>> {
+        get_settlement_receipt(&env, remittance_id)
+    }
+
+    /// Checks whether a settlement record exists for an ID.
+    ///
+    /// Lets front ends probe IDs without handling a `RemittanceNotFound` error.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `id` - ID to look up
+    ///
+    /// # Returns
+    ///
+    /// * `true` - A record exists for this ID
+    /// * `false` - The ID was never created
+    pub fn settlement_exists(env: Env, id: u64) -> bool {
+        has_remittance(&env, id)
+    }
+
+    /// Query a remittance with a standardized response wrapper and request ID.
+    pub fn query_remittance(
+        env: Env,
+        remittance_id: u64,
+        request_id: soroban_sdk::String,
+    ) -> crate::response::Response<Remittance> {
+        match get_remittance(&env, remittance_id) {
+            Ok(remittance) => crate::response::Response::ok(remittance, request_id),
+            Err(e) => crate::response::Response::err(e as u32, request_id),
+        }
+    }
+
+    /// Checks whether a remittance's settlement has been executed.
+    ///
+    /// Reads the duplicate-protection settlement flag rather than the status
+    /// field, so it is the authoritative signal that funds have moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to check
+    ///
+    /// # Returns
+    ///
+    /// * `true` - Settlement has been executed
+    /// * `false` - No settlement recorded for this ID
+    pub fn is_settled(env: Env, remittance_id: u64) -> bool {
+        has_settlement_hash(&env, remittance_id)
+    }
+
+
+    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
+        get_accumulated_fees(&env)
+    }
+
+    /// Returns the total fees ever collected.
+    ///
+    /// Unlike `get_accumulated_fees`, this total is never reduced by
+    /// withdrawals and includes fees routed straight to a recipient.
+    pub fn get_lifetime_fees(env: Env) -> i128 {
+        get_lifetime_fees(&env)
+    }
+
+    /// Returns the total amount owed on remittances that are not yet settled or refunded.
+    ///
+    /// Maintained as a running counter on create, adjust, settle, cancel and
+    /// reclaim. An off-chain monitor can assert solvency by checking that the
+    /// contract's token balance covers this plus `get_accumulated_fees`.
+    pub fn get_outstanding_liability(env: Env) -> i128 {
+        get_outstanding_liability(&env)
+    }
+
+    /// Checks if an address is registered as an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address to check
+    ///
+    /// # Returns
+    ///
+    /// * `true` - Address is a registered agent
+    /// * `false` - Address is not registered
+    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
+        is_agent_registered(&env, &agent)
+    }
+
+    /// Retrieves the current platform fee rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
+        get_platform_fee_bps(&env)
+    }
+
+    /// Quotes the fee `create_remittance` would charge for `amount` to `agent`.
+    ///
+    /// Runs the same fee resolution as creation (token override, scheduled
+    /// fee, rounding mode), so front ends can show the exact fee without
+    /// re-implementing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent the remittance would be sent to
+    /// * `amount` - Hypothetical remittance amount
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Fee that would be stored on the remittance
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    pub fn quote_fee(env: Env, agent: Address, amount: i128) -> Result<i128, ContractError> {
+        validate_amount(amount)?;
+        validate_agent_registered(&env, &agent)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        remittance_fee(&env, &usdc_token, amount)
+    }
+
+    /// Retrieves the ID of the most recently created remittance.
+    ///
+    /// IDs are assigned sequentially from 1, so clients can use this to
+    /// predict the next ID or bound a pagination scan.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - Number of remittances created so far (0 if none)
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_remittance_counter(env: Env) -> Result<u64, ContractError> {
+        get_remittance_counter(&env)
+    }
+
+
+    /// Retrieves the total number of successfully finalized settlements.
+    ///
+    /// This is a read-only method that performs an O(1) constant-time read directly
+    /// from instance storage without iteration or recomputation. The counter is
+    /// incremented atomically each time a settlement is successfully finalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - Total number of settlements processed (0 if none)
+    ///
+    /// # Performance
+    ///
+    /// - O(1) constant-time operation
+    /// - Single storage read
+    /// - No iteration or computation
+    ///
+    /// # Guarantees
+    ///
+    /// - Read-only: Cannot modify storage
+    /// - Deterministic: Always returns same value for same state
+    /// - Consistent: Reflects all successfully finalized settlements
+    /// - Cannot be modified externally (no public setter)
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let total = contract.get_total_settlements_count(&env);
+    /// println!("Total settlements processed: {}", total);
+    /// ```
+    pub fn get_total_settlements_count(env: Env) -> u64 {
+        get_settlement_counter(&env)
+
+
+
+    pub fn get_integrator_fee_bps(env: Env) -> Result<u32, ContractError> {
+        get_integrator_fee_bps(&env)
+    }
+
+    pub fn get_accumulated_integrator_fees(env: Env) -> Result<i128, ContractError> {
+        get_accumulated_integrator_fees(&env)
+
+
+    }
+
+    pub fn pause(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_paused(&env, true);
+        emit_paused(&env, caller);
+        Ok(())
+    }
+
+    pub fn unpause(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_paused(&env, false);
+        emit_unpaused(&env, caller);
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        crate::storage::is_paused(&env)
+    }
+
+    /// Stops or resumes accepting new remittances.
+    ///
+    /// A softer wind-down than a pause: while off, creation fails with
+    /// `NotAcceptingRemittances`, but confirmations and cancellations keep
+    /// working so existing remittances can settle. Unaffected by
+    /// `pause`/`unpause`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `accepting` - False to reject new remittances
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_accepting_new(env: Env, accepting: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_accepting_new(&env, accepting);
+
+        Ok(())
+    }
+
+    /// Returns whether new remittances are accepted.
+    pub fn is_accepting_new(env: Env) -> bool {
+        is_accepting_new(&env)
+    }
+
+    /// Restricts or reopens remittance creation to allowlisted senders.
+    ///
+    /// While enabled, creating a remittance from a sender that has not been
+    /// approved with `allow_sender` fails with `SenderNotAllowed`. Disabled
+    /// by default; the allowlist itself is kept when toggling.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `enabled` - True to enforce the sender allowlist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_sender_allowlist_enabled(env: Env, enabled: bool) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowlist_enabled(&env, enabled);
+
+        Ok(())
+    }
+
+    /// Returns whether the sender allowlist is enforced.
+    pub fn is_sender_allowlist_enabled(env: Env) -> bool {
+        is_sender_allowlist_enabled(&env)
+    }
+
+    /// Approves a sender to create remittances while the allowlist is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender to allow
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn allow_sender(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowlisted(&env, &sender, true);
+
+        emit_sender_allowed(&env, sender, true);
+
+        Ok(())
+    }
+
+    /// Removes a sender from the allowlist.
+    ///
+    /// Remittances the sender already created are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Sender to disallow
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn disallow_sender(env: Env, sender: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_sender_allowlisted(&env, &sender, false);
+
+        emit_sender_allowed(&env, sender, false);
+
+        Ok(())
+    }
+
+    /// Returns whether a sender may currently create remittances.
+    ///
+    /// Always true while the allowlist is disabled.
+    pub fn is_sender_allowed(env: Env, sender: Address) -> bool {
+        validate_sender_allowed(&env, &sender).is_ok()
+    }
+
+    /// Sets the single-operation volume that trips the circuit breaker.
+    ///
+    /// When one creation (or the total of one batch settlement) exceeds the
+    /// threshold, that operation completes and the contract then pauses
+    /// every operation until an admin calls `unpause`. Zero disables it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `threshold` - Volume above which the contract auto-pauses (0 to disable)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Threshold updated
+    /// * `Err(ContractError::InvalidAmount)` - Threshold is negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_auto_pause_threshold(env: Env, threshold: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if threshold < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_auto_pause_threshold(&env, threshold);
+
+        Ok(())
+    }
+
+    /// Returns the auto-pause threshold (0 when disabled).
+    pub fn get_auto_pause_threshold(env: Env) -> i128 {
+        get_auto_pause_threshold(&env)
+    }
+
+    /// Pauses all operations if `amount` exceeds the auto-pause threshold.
+    ///
+    /// Runs after the triggering operation has succeeded; returning an error
+    /// instead would roll the pause back along with it.
+    fn trip_auto_pause(env: &Env, amount: i128) {
+        let threshold = get_auto_pause_threshold(env);
+        if threshold > 0 && amount > threshold {
+            set_paused(env, true);
+            emit_auto_paused(env, amount, threshold);
+        }
+    }
+
+    /// Sets per-operation pause switches.
+    ///
+    /// Allows pausing a single class of operation (e.g. confirmations) while
+    /// others keep working. `pause`/`unpause` set or clear all flags at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `flags` - Pause switches to apply
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Flags updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_pause_flags(env: Env, flags: PauseFlags) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_pause_flags(&env, &flags);
+        emit_pause_flags_updated(&env, caller, flags);
+        Ok(())
+    }
+
+    /// Returns the current per-operation pause switches.
+    pub fn get_pause_flags(env: Env) -> PauseFlags {
+        get_pause_flags(&env)
+    }
+    
+    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        let old_cooldown = get_rate_limit_cooldown(&env)?;
+        set_rate_limit_cooldown(&env, cooldown_seconds);
+        
+        emit_rate_limit_updated(&env, admin, old_cooldown, cooldown_seconds);
+
+        Ok(())
+    }
+    
     pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
         get_rate_limit_cooldown(&env)
     }
-    
-    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
-        get_last_settlement_time(&env, &sender)
+    
+    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
+        get_last_settlement_time(&env, &sender)
+    }
+
+    pub fn get_version(env: Env) -> soroban_sdk::String {
+        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Dry-runs `batch_settle_with_netting` without moving funds or writing state.
+    ///
+    /// Applies the same per-entry checks as the real batch's validation phase
+    /// but, instead of stopping at the first failure, reports every entry.
+    /// A batch settles only if `failed` comes back empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `entries` - Remittance IDs that would be passed to the real batch
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BatchSimulationResult)` - IDs that would settle and IDs that would fail with their error codes
+    /// * `Err(ContractError::ContractPaused)` - Confirmations are paused, so the whole batch would fail
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    pub fn simulate_batch_settle(
+        env: Env,
+        entries: Vec<BatchSettlementEntry>,
+    ) -> Result<BatchSimulationResult, ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.confirm)?;
+
+        let batch_size = entries.len();
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut succeeded = Vec::new(&env);
+        let mut failed = Vec::new(&env);
+        let mut seen_ids: Vec<u64> = Vec::new(&env);
+
+        for entry in entries.iter() {
+            let remittance_id = entry.remittance_id;
+
+            let outcome = if seen_ids.contains(remittance_id) {
+                Err(ContractError::DuplicateSettlement)
+            } else {
+                Self::validate_batch_entry(&env, remittance_id)
+            };
+            seen_ids.push_back(remittance_id);
+
+            match outcome {
+                Ok(_) => succeeded.push_back(remittance_id),
+                Err(error) => failed.push_back(BatchSimulationFailure {
+                    remittance_id,
+                    error_code: error as u32,
+                }),
+            }
+        }
+
+        Ok(BatchSimulationResult { succeeded, failed })
+    }
+
+    /// Loads a remittance and runs the per-entry checks of batch settlement.
+    fn validate_batch_entry(env: &Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        let remittance = get_remittance(env, remittance_id)?;
+
+        // Verify remittance is pending
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        // Check for duplicate settlement execution
+        if has_settlement_hash(env, remittance_id) {
+            return Err(ContractError::DuplicateSettlement);
+        }
+
+        // Check expiry (allowing the grace window)
+        validate_settlement_not_expired(env, remittance.expiry)?;
+
+        // Conditional remittances wait for the oracle
+        validate_condition_met(env, &remittance)?;
+
+        // Validate addresses
+        validate_address(env, &remittance.agent)?;
+
+        // Reject dust payouts before any funds move
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+        validate_min_payout(env, payout_amount)?;
+
+        // Only admin-vetted agents may be settled in batches
+        if !is_agent_batch_eligible(env, &remittance.agent) {
+            return Err(ContractError::AgentNotBatchEligible);
+        }
+
+        Ok(remittance)
+    }
+
+    /// Batch settle multiple remittances with net settlement optimization.
+    /// 
+    /// This function processes multiple remittances in a single transaction and applies
+    /// net settlement logic to offset opposing transfers between the same parties.
+    /// Only the net difference is executed on-chain, reducing total token transfers.
+    /// 
+    /// # Benefits
+    /// - Reduces on-chain transfer count by offsetting opposing flows
+    /// - Preserves all fees and accounting integrity
+    /// - Deterministic and order-independent results
+    /// - Gas-efficient batch processing
+    /// 
+    /// # Example
+    /// If batch contains:
+    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
+    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
+    /// 
+    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
+    /// 
+    /// # Parameters
+    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    /// 
+    /// # Returns
+    /// BatchSettlementResult with list of successfully settled remittance IDs
+    /// 
+    /// # Errors
+    /// - ContractPaused: Contract is in paused state
+    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
+    /// - AgentNotBatchEligible: An entry's agent is not approved for batch settlement
+    /// - RemittanceNotFound: One or more remittance IDs don't exist
+    /// - InvalidStatus: One or more remittances are not in Pending status
+    /// - DuplicateSettlement: Duplicate remittance IDs in batch
+    /// - NetSettlementValidationFailed: A pair's fees and holdbacks exceed its net transfer
+    /// - Overflow: Arithmetic overflow in calculations
+    ///
+    /// # Atomicity
+    /// The generic token interface offers no way to ask whether a recipient can
+    /// receive funds, so a rejected transfer (e.g. a deauthorized account) is
+    /// only detected in phase 2. The token call then aborts the whole
+    /// invocation and the host rolls back every transfer and state change made
+    /// by the batch, leaving all remittances pending.
+    pub fn batch_settle_with_netting(
+        env: Env,
+        entries: Vec<BatchSettlementEntry>,
+    ) -> Result<BatchSettlementResult, ContractError> {
+        validate_operation_not_paused(&env, |flags| flags.confirm)?;
+
+        // Validate batch size
+        let batch_size = entries.len();
+        if batch_size == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if batch_size > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Load all remittances and validate
+        let mut remittances = Vec::new(&env);
+        let mut seen_ids = Vec::new(&env);
+        let mut batch_total: i128 = 0;
+
+        for i in 0..batch_size {
+            let entry = entries.get_unchecked(i);
+            let remittance_id = entry.remittance_id;
+
+            // Check for duplicate IDs in batch
+            for j in 0..seen_ids.len() {
+                if seen_ids.get_unchecked(j) == remittance_id {
+                    return Err(ContractError::DuplicateSettlement);
+                }
+            }
+            seen_ids.push_back(remittance_id);
+
+            let remittance = Self::validate_batch_entry(&env, remittance_id)?;
+
+            batch_total = checked_add_i128(batch_total, remittance.amount)?;
+            remittances.push_back(remittance);
+        }
+
+        // Holdbacks stay in the contract until each remittance is released
+        let mut holdbacks = Vec::new(&env);
+        for i in 0..remittances.len() {
+            let remittance = remittances.get_unchecked(i);
+            let payout_amount = remittance
+                .amount
+                .checked_sub(remittance.fee)
+                .ok_or(ContractError::Overflow)?;
+            holdbacks.push_back(Self::holdback_amount(&env, payout_amount)?);
+        }
+
+        // Compute net settlements
+        let net_transfers = compute_net_settlements(&env, &remittances);
+
+        // Validate net settlement calculations
+        validate_net_settlement(&remittances, &net_transfers)?;
+
+        // Execute net transfers
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+
+        for i in 0..net_transfers.len() {
+            let transfer = net_transfers.get_unchecked(i);
+
+            // Determine actual sender and recipient based on net_amount sign
+            let (from, to, amount) = if transfer.net_amount > 0 {
+                // Positive: party_a -> party_b
+                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
+            } else if transfer.net_amount < 0 {
+                // Negative: party_b -> party_a
+                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
+            } else {
+                // Zero: complete offset, no transfer needed
+                continue;
+            };
+
+            // Each leg's holdback is withheld from its own payee, so only the
+            // legs paying `to` reduce this transfer
+            let mut held: i128 = 0;
+            for j in 0..remittances.len() {
+                let remittance = remittances.get_unchecked(j);
+                if remittance.sender == from && remittance.payee() == to {
+                    held = checked_add_i128(held, holdbacks.get_unchecked(j))?;
+                }
+            }
+
+            // Calculate payout amount (net amount minus fees and holdbacks)
+            let payout_amount = amount
+                .checked_sub(transfer.total_fees)
+                .and_then(|v| v.checked_sub(held))
+                .ok_or(ContractError::Overflow)?;
+            if payout_amount < 0 {
+                return Err(ContractError::NetSettlementValidationFailed);
+            }
+
+            // Execute the net transfer from contract to recipient
+            // Note: The sender's funds are already in the contract from create_remittance
+            if payout_amount > 0 {
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &to,
+                    &payout_amount,
+                );
+            }
+
+            // Accumulate or route fees
+            collect_fee(&env, &token_client, transfer.total_fees)?;
+        }
+
+        // Mark all remittances as completed and set settlement hashes
+        let mut settled_ids = Vec::new(&env);
+        let mut receipts = Vec::new(&env);
+
+        for i in 0..remittances.len() {
+            let mut remittance = remittances.get_unchecked(i);
+
+            // Calculate payout amount for this remittance
+            let payout_amount = remittance
+                .amount
+                .checked_sub(remittance.fee)
+                .ok_or(ContractError::Overflow)?;
+
+            let payee = remittance.payee();
+            Self::finalize_settlement(&env, &mut remittance, payout_amount, holdbacks.get_unchecked(i), &payee)?;
+            settled_ids.push_back(remittance.id);
+
+            receipts.push_back(SettlementReceipt {
+                remittance_id: remittance.id,
+                payout_amount,
+                fee: remittance.fee,
+            });
+        }
+
+        if is_balance_events_enabled(&env) {
+            let balance = token_client.balance(&env.current_contract_address());
+            emit_balance_check(&env, balance, get_accumulated_fees(&env)?);
+        }
+
+        Self::trip_auto_pause(&env, batch_total);
+
+        Ok(BatchSettlementResult { settled_ids, receipts })
+    }
+
+    /// Settles an agent's settleable remittances within a range of IDs in one batch.
+    ///
+    /// Scans the IDs in `[start_id, start_id + limit)` (`limit` capped at
+    /// `MAX_BATCH_SIZE`) and settles the agent's remittances there through
+    /// `batch_settle_with_netting`, so operators do not need to list IDs.
+    /// Entries the batch would reject (expired, awaiting a condition, below
+    /// the minimum payout) are skipped and left pending, as are other
+    /// agents' remittances. Call again with `start_id + limit` for the next page.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent whose remittances should be settled
+    /// * `start_id` - First remittance ID to scan
+    /// * `limit` - Number of IDs to scan
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BatchSettlementResult)` - Settled IDs and receipts (empty if none were eligible)
+    /// * `Err(ContractError::AgentNotBatchEligible)` - Agent is not approved for batch settlement
+    /// * Any other error returned by `batch_settle_with_netting`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn batch_settle_for_agent(
+        env: Env,
+        agent: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> Result<BatchSettlementResult, ContractError> {
+        agent.require_auth();
+
+        if !is_agent_batch_eligible(&env, &agent) {
+            return Err(ContractError::AgentNotBatchEligible);
+        }
+
+        let mut entries = Vec::new(&env);
+
+        for id in Self::id_window(&env, start_id, limit)? {
+            if let Ok(remittance) = Self::validate_batch_entry(&env, id) {
+                if remittance.agent == agent {
+                    entries.push_back(BatchSettlementEntry { remittance_id: id });
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            return Ok(BatchSettlementResult {
+                settled_ids: Vec::new(&env),
+                receipts: Vec::new(&env),
+            });
+        }
+
+        Self::batch_settle_with_netting(env, entries)
+    }
+
+    /// Add a token to the whitelist. Only admins can call this.
+    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_admin_operation(&env, &caller, &token)?;
+        validate_token_not_denied(&env, &token)?;
+
+        if is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenAlreadyWhitelisted);
+        }
+
+        set_token_whitelisted(&env, &token, true);
+        
+        // Event: Token whitelisted - Fires when admin adds a token to the approved list
+        // Used by off-chain systems to track which tokens can be used for remittances
+        emit_token_whitelisted(&env, caller.clone(), token.clone());
+        log_whitelist_token(&env, &token);
+
+        Ok(())
+    }
+
+    /// Remove a token from the whitelist. Only admins can call this.
+    pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_admin_operation(&env, &caller, &token)?;
+
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+
+        set_token_whitelisted(&env, &token, false);
+        
+        // Event: Token removed - Fires when admin removes a token from the approved list
+        // Used by off-chain systems to track which tokens are no longer accepted for remittances
+        emit_token_removed(&env, caller.clone(), token.clone());
+        log_remove_whitelisted_token(&env, &token);
+
+        Ok(())
     }
 
-    pub fn get_version(env: Env) -> soroban_sdk::String {
-        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    /// Check if a token is whitelisted.
+    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
+        is_token_whitelisted(&env, &token)
     }
 
-    /// Batch settle multiple remittances with net settlement optimization.
-    /// 
-    /// This function processes multiple remittances in a single transaction and applies
-    /// net settlement logic to offset opposing transfers between the same parties.
-    /// Only the net difference is executed on-chain, reducing total token transfers.
-    /// 
-    /// # Benefits
-    /// - Reduces on-chain transfer count by offsetting opposing flows
-    /// - Preserves all fees and accounting integrity
-    /// - Deterministic and order-independent results
-    /// - Gas-efficient batch processing
-    /// 
-    /// # Example
-    /// If batch contains:
-    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
-    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
-    /// 
-    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
-    /// 
-    /// # Parameters
-    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
-    /// 
+    /// Returns every whitelisted token, in the order they were added.
+    pub fn get_supported_tokens(env: Env) -> Vec<Address> {
+        get_supported_tokens(&env)
+    }
+
+    /// Blocks a token for all uses, even if it is whitelisted.
+    ///
+    /// Denied tokens cannot be whitelisted, deposited into agent pools, or
+    /// used to create remittances.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token to deny
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn deny_token(env: Env, token: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_token_denied(&env, &token, true);
+
+        emit_token_denied(&env, token, true);
+
+        Ok(())
+    }
+
+    /// Removes a token from the denylist.
+    ///
+    /// This does not whitelist the token; it only lifts the block.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token to allow
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn allow_token(env: Env, token: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_token_denied(&env, &token, false);
+
+        emit_token_denied(&env, token, false);
+
+        Ok(())
+    }
+
+    /// Check if a token is on the denylist.
+    pub fn is_token_denied(env: Env, token: Address) -> bool {
+        is_token_denied(&env, &token)
+    }
+
+    /// Returns the settlement token address.
+    pub fn get_usdc_token(env: Env) -> Result<Address, ContractError> {
+        get_usdc_token(&env)
+    }
+
+    /// Replaces the settlement token, e.g. after the issuer rotates the asset.
+    ///
+    /// The contract must be paused and hold nothing in the old token: no
+    /// escrow, accumulated fees, agent stakes, unswept yield or agent pool
+    /// balances. Withdraw or sweep these first so none is stranded.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `new_token` - New settlement token
+    ///
     /// # Returns
-    /// BatchSettlementResult with list of successfully settled remittance IDs
-    /// 
-    /// # Errors
-    /// - ContractPaused: Contract is in paused state
-    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
-    /// - RemittanceNotFound: One or more remittance IDs don't exist
-    /// - InvalidStatus: One or more remittances are not in Pending status
-    /// - DuplicateSettlement: Duplicate remittance IDs in batch
-    /// - Overflow: Arithmetic overflow in calculations
-    pub fn batch_settle_with_netting(
+    ///
+    /// * `Ok(())` - Settlement token replaced
+    /// * `Err(ContractError::InvalidStatus)` - Contract is not paused
+    /// * `Err(ContractError::PendingRemittancesExist)` - Escrow, fees, stakes, yield or pool balances remain in the old token
+    /// * `Err(ContractError::TokenNotWhitelisted)` - `new_token` is on the denylist
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn migrate_token(env: Env, new_token: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if !crate::storage::is_paused(&env) {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let old_token = get_usdc_token(&env)?;
+
+        // Every balance below is held in the old token
+        if get_outstanding_liability(&env) > 0
+            || get_accumulated_fees(&env)? > 0
+            || get_total_staked(&env) > 0
+            || get_yield_pool(&env) > 0
+            || get_agent_pool_total(&env, &old_token) > 0
+        {
+            return Err(ContractError::PendingRemittancesExist);
+        }
+
+        validate_token_not_denied(&env, &new_token)?;
+
+        set_usdc_token(&env, &new_token);
+        set_token_decimals(&env, token::Client::new(&env, &new_token).decimals());
+
+        emit_token_migrated(&env, old_token, new_token);
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Liquidity Pools
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Deposits tokens into an agent's liquidity pool.
+    ///
+    /// Pool balances let an agent settle a USDC remittance by paying the
+    /// recipient in another whitelisted token (e.g. a local stablecoin). The
+    /// agent is reimbursed in USDC when a pool settlement completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Registered agent funding its pool
+    /// * `token` - Whitelisted token being deposited
+    /// * `amount` - Amount to deposit (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Pool funded
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn fund_agent_pool(
         env: Env,
-        entries: Vec<BatchSettlementEntry>,
-    ) -> Result<BatchSettlementResult, ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::ContractPaused);
+        agent: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        validate_amount(amount)?;
+
+        if !is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
         }
 
-        // Validate batch size
-        let batch_size = entries.len();
-        if batch_size == 0 {
-            return Err(ContractError::InvalidAmount);
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
-        if batch_size > MAX_BATCH_SIZE {
+        validate_token_not_denied(&env, &token)?;
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&agent, &env.current_contract_address(), &amount);
+
+        let balance = checked_add_i128(get_agent_pool(&env, &agent, &token), amount)?;
+        set_agent_pool(&env, &agent, &token, balance);
+
+        emit_agent_pool_funded(&env, agent, token, amount);
+
+        Ok(())
+    }
+
+    /// Withdraws unused tokens from an agent's liquidity pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Agent owning the pool
+    /// * `token` - Pool token to withdraw
+    /// * `amount` - Amount to withdraw (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Tokens returned to the agent
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::InsufficientPoolLiquidity)` - Pool balance is below `amount`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn withdraw_agent_pool(
+        env: Env,
+        agent: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        validate_operation_not_paused(&env, |flags| flags.withdraw)?;
+        validate_amount(amount)?;
+
+        let balance = get_agent_pool(&env, &agent, &token);
+        if balance < amount {
+            return Err(ContractError::InsufficientPoolLiquidity);
+        }
+
+        set_agent_pool(&env, &agent, &token, balance - amount);
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &agent, &amount);
+
+        emit_agent_pool_withdrawn(&env, agent, token, amount);
+
+        Ok(())
+    }
+
+    /// Returns an agent's pool balance for a token.
+    pub fn get_agent_pool(env: Env, agent: Address, token: Address) -> i128 {
+        get_agent_pool(&env, &agent, &token)
+    }
+
+    /// Sets the USDC-to-token conversion rate used for pool settlements.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin address
+    /// * `token` - Whitelisted pool token
+    /// * `rate_bps` - Units of `token` paid per USDC unit, in basis points (10000 = 1:1)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate stored
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::InvalidAmount)` - Rate is zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_fx_rate(
+        env: Env,
+        caller: Address,
+        token: Address,
+        rate_bps: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+
+        if rate_bps == 0 {
             return Err(ContractError::InvalidAmount);
         }
 
-        // Load all remittances and validate
-        let mut remittances = Vec::new(&env);
-        let mut seen_ids = Vec::new(&env);
+        set_fx_rate(&env, &token, rate_bps);
 
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
-            let remittance_id = entry.remittance_id;
+        Ok(())
+    }
 
-            // Check for duplicate IDs in batch
-            for j in 0..seen_ids.len() {
-                if seen_ids.get_unchecked(j) == remittance_id {
-                    return Err(ContractError::DuplicateSettlement);
-                }
-            }
-            seen_ids.push_back(remittance_id);
+    /// Returns the configured conversion rate for a pool token, if any.
+    pub fn get_fx_rate(env: Env, token: Address) -> Option<u32> {
+        get_fx_rate(&env, &token)
+    }
 
-            // Load and validate remittance
-            let remittance = get_remittance(&env, remittance_id)?;
+    /// Sets the conversion rate for a quote currency used by `create_remittance_quoted`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `currency` - Quote currency code (e.g. "MXN")
+    /// * `rate_bps` - Quote currency units per USDC unit, in basis points (10000 = 1:1)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate stored
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAmount)` - Rate is zero
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_rate(env: Env, currency: String, rate_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
-            // Verify remittance is pending
-            if remittance.status != RemittanceStatus::Pending {
-                return Err(ContractError::InvalidStatus);
-            }
+        if rate_bps == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_quote_rate(&env, &currency, rate_bps);
+
+        Ok(())
+    }
+
+    /// Returns the configured conversion rate for a quote currency, if any.
+    pub fn get_rate(env: Env, currency: String) -> Option<u32> {
+        get_quote_rate(&env, &currency)
+    }
+
+    /// Settles a remittance by paying the recipient from the agent's pool.
+    ///
+    /// The USDC payout (amount minus fees) is converted at the configured rate
+    /// and paid to `recipient` in `payout_token` out of the agent's pool. The
+    /// agent is reimbursed with the USDC payout, and fees accrue as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `payout_token` - Pool token to pay the recipient in
+    /// * `recipient` - Address receiving the local-token payout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance settled from the pool
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending or Accepted
+    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::FxRateNotSet)` - No rate configured for `payout_token`
+    /// * `Err(ContractError::InsufficientPoolLiquidity)` - Pool cannot cover the converted payout
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent assigned to the remittance.
+    pub fn confirm_payout_from_pool(
+        env: Env,
+        remittance_id: u64,
+        payout_token: Address,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
 
-            // Check for duplicate settlement execution
-            if has_settlement_hash(&env, remittance_id) {
-                return Err(ContractError::DuplicateSettlement);
-            }
+        remittance.agent.require_auth();
 
-            // Check expiry
-            if let Some(expiry_time) = remittance.expiry {
-                let current_time = env.ledger().timestamp();
-                if current_time > expiry_time {
-                    return Err(ContractError::SettlementExpired);
-                }
-            }
+        Self::validate_settlement(&env, remittance_id, &remittance)?;
 
-            // Validate addresses
-            validate_address(&remittance.agent)?;
+        validate_recipient_address(&env, &recipient)?;
 
-            remittances.push_back(remittance);
+        // A sender-designated recipient cannot be redirected by the agent
+        if remittance.recipient.is_some() && remittance.recipient != Some(recipient.clone()) {
+            return Err(ContractError::Unauthorized);
         }
 
-        // Compute net settlements
-        let net_transfers = compute_net_settlements(&env, &remittances);
-
-        // Validate net settlement calculations
-        validate_net_settlement(&remittances, &net_transfers)?;
-
-        // Execute net transfers
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-
-        for i in 0..net_transfers.len() {
-            let transfer = net_transfers.get_unchecked(i);
-
-            // Determine actual sender and recipient based on net_amount sign
-            let (from, to, amount) = if transfer.net_amount > 0 {
-                // Positive: party_a -> party_b
-                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
-            } else if transfer.net_amount < 0 {
-                // Negative: party_b -> party_a
-                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
-            } else {
-                // Zero: complete offset, no transfer needed
-                continue;
-            };
+        let rate_bps = get_fx_rate(&env, &payout_token).ok_or(ContractError::FxRateNotSet)?;
 
-            // Calculate payout amount (net amount minus fees)
-            let payout_amount = amount
-                .checked_sub(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+        let payout_amount = remittance
+            .amount
+            .checked_sub(remittance.fee)
+            .ok_or(ContractError::Overflow)?;
+        validate_min_payout(&env, payout_amount)?;
 
-            // Execute the net transfer from contract to recipient
-            // Note: The sender's funds are already in the contract from create_remittance
-            token_client.transfer(
-                &env.current_contract_address(),
-                &to,
-                &payout_amount,
-            );
+        let local_amount = payout_amount
+            .checked_mul(rate_bps as i128)
+            .and_then(|v| v.checked_div(10000))
+            .ok_or(ContractError::Overflow)?;
 
-            // Accumulate fees
-            let current_fees = get_accumulated_fees(&env)?;
-            let new_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
-            set_accumulated_fees(&env, new_fees);
+        let pool_balance = get_agent_pool(&env, &remittance.agent, &payout_token);
+        if pool_balance < local_amount {
+            return Err(ContractError::InsufficientPoolLiquidity);
         }
+        set_agent_pool(&env, &remittance.agent, &payout_token, pool_balance - local_amount);
+
+        let pool_client = token::Client::new(&env, &payout_token);
+        pool_client.transfer(&env.current_contract_address(), &recipient, &local_amount);
+
+        // The agent fronted the local payout, so the USDC payout reimburses them
+        let agent = remittance.agent.clone();
+        Self::settle_remittance(&env, &mut remittance, payout_amount, &agent)?;
+
+        // Event: FX settlement - Fires when a remittance is paid out in a pool token
+        // Used by off-chain systems to reconcile agent pool balances and applied rates
+        emit_fx_settlement(
+            &env,
+            remittance_id,
+            remittance.agent.clone(),
+            recipient,
+            payout_token,
+            local_amount,
+            rate_bps,
+        );
 
-        // Mark all remittances as completed and set settlement hashes
-        let mut settled_ids = Vec::new(&env);
-
-        for i in 0..remittances.len() {
-            let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Settled;
-            set_remittance(&env, remittance.id, &remittance);
-            set_settlement_hash(&env, remittance.id);
-            settled_ids.push_back(remittance.id);
-
+        Ok(())
+    }
 
-            // Increment settlement counter atomically for each successful settlement
-            increment_settlement_counter(&env)?;
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Yield Accounting
+    // ═══════════════════════════════════════════════════════════════════════════
 
+    /// Records yield earned on escrowed funds.
+    ///
+    /// Yield is tracked in its own pool, separate from accumulated fees, so
+    /// principal owed to senders and agents is never mixed with it. The yield
+    /// tokens must already have been transferred to the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Yield amount to add to the pool (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Yield recorded
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn record_yield(env: Env, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
+        validate_amount(amount)?;
 
-            // Increment settlement counter atomically for each successful settlement
-            increment_settlement_counter(&env);
+        let pool = checked_add_i128(get_yield_pool(&env), amount)?;
+        set_yield_pool(&env, pool);
 
-          
+        emit_yield_recorded(&env, amount, pool);
 
-            // Calculate payout amount for this remittance
-            let payout_amount = remittance
-                .amount
-                .checked_sub(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
+        Ok(())
+    }
 
-            // Emit settlement completion event exactly once per remittance
-            // This ensures each finalized settlement has exactly one completion event
-            if !has_settlement_event_emitted(&env, remittance.id) {
-                emit_settlement_completed(
-                    &env,
-                    remittance.id,
-                    remittance.sender.clone(),
-                    remittance.agent.clone(),
-                    usdc_token.clone(),
-                    payout_amount,
-                );
-                set_settlement_event_emitted(&env, remittance.id);
-            }
+    /// Transfers the entire yield pool out of the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the yield
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Yield transferred and pool reset to zero
+    /// * `Err(ContractError::InvalidRecipientAddress)` - `to` is the contract itself
+    /// * `Err(ContractError::NoFeesToWithdraw)` - Yield pool is empty
+    /// * `Err(ContractError::InsufficientContractBalance)` - Sweeping would dip into escrowed
+    ///   principal, accumulated fees, agent stake, or the liquidity floor
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn sweep_yield(env: Env, to: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
 
-            // Emit individual remittance completion event
-            emit_remittance_completed(
-                &env,
-                remittance.id,
-                remittance.sender.clone(),
-                remittance.agent.clone(),
-                usdc_token.clone(),
-                payout_amount,
-            );
-        }
+        validate_operation_not_paused(&env, |flags| flags.withdraw)?;
+        validate_recipient_address(&env, &to)?;
 
-        Ok(BatchSettlementResult { settled_ids })
-    }
+        let pool = get_yield_pool(&env);
+        validate_fees_available(pool)?;
 
-    /// Add a token to the whitelist. Only admins can call this.
-    pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
 
-        if is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenAlreadyWhitelisted);
+        // Never touch funds owed to senders/agents, earned as fees or locked as stake
+        let liabilities = checked_add_i128(get_outstanding_liability(&env), get_accumulated_fees(&env)?)?;
+        let liabilities = checked_add_i128(liabilities, get_total_staked(&env))?;
+        let available = token_client
+            .balance(&env.current_contract_address())
+            .checked_sub(liabilities)
+            .ok_or(ContractError::Overflow)?;
+        if pool > available {
+            return Err(ContractError::InsufficientContractBalance);
         }
+        validate_liquidity_floor(&env, token_client.balance(&env.current_contract_address()), pool)?;
 
-        set_token_whitelisted(&env, &token, true);
-        
-        // Event: Token whitelisted - Fires when admin adds a token to the approved list
-        // Used by off-chain systems to track which tokens can be used for remittances
-        emit_token_whitelisted(&env, caller.clone(), token.clone());
-        log_whitelist_token(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &to, &pool);
+        set_yield_pool(&env, 0);
+
+        emit_yield_swept(&env, to, pool);
 
         Ok(())
     }
 
-    /// Remove a token from the whitelist. Only admins can call this.
-    pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+    /// Returns the yield recorded but not yet swept.
+    pub fn get_yield_pool(env: Env) -> i128 {
+        get_yield_pool(&env)
+    }
 
-        if !is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenNotWhitelisted);
+    /// Recovers the full balance of a token sent to the contract by mistake.
+    ///
+    /// Only tokens the contract never holds on anyone's behalf can be
+    /// recovered: the settlement token backs escrow, fees and stake, and
+    /// whitelisted tokens back agent liquidity pools, so both are refused.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token to recover
+    /// * `to` - Address to receive the tokens
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Amount recovered
+    /// * `Err(ContractError::CannotRecoverSettlementToken)` - `token` is the settlement token or whitelisted
+    /// * `Err(ContractError::InvalidRecipientAddress)` - `to` is the contract itself
+    /// * `Err(ContractError::NoFeesToWithdraw)` - Contract holds none of `token`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn recover_token(env: Env, token: Address, to: Address) -> Result<i128, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_recipient_address(&env, &to)?;
+
+        if token == get_usdc_token(&env)? || is_token_whitelisted(&env, &token) {
+            return Err(ContractError::CannotRecoverSettlementToken);
         }
 
-        set_token_whitelisted(&env, &token, false);
-        
-        // Event: Token removed - Fires when admin removes a token from the approved list
-        // Used by off-chain systems to track which tokens are no longer accepted for remittances
-        emit_token_removed(&env, caller.clone(), token.clone());
-        log_remove_whitelisted_token(&env, &token);
+        let token_client = token::Client::new(&env, &token);
+        let balance = token_client.balance(&env.current_contract_address());
+        validate_fees_available(balance)?;
 
-        Ok(())
-    }
+        token_client.transfer(&env.current_contract_address(), &to, &balance);
 
-    /// Check if a token is whitelisted.
-    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
-        is_token_whitelisted(&env, &token)
+        emit_token_recovered(&env, token, to, balance);
+
+        Ok(balance)
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1052,6 +5710,95 @@ impl SwiftRemitContract {
         migration::import_batch(&env, batch)
     }
 
+    /// Export contract configuration for migration
+    ///
+    /// Returns the non-remittance configuration (admin, token, fee, pause
+    /// state, counters and accumulated fees) so a new deployment can be
+    /// seeded from this one with `import_config`.
+    ///
+    /// # Returns
+    /// ConfigSnapshot of the current configuration
+    ///
+    /// # Errors
+    /// - NotInitialized: Contract has not been initialized
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = old_contract.export_config();
+    /// new_contract.import_config(&config);
+    /// ```
+    pub fn export_config(env: Env) -> Result<ConfigSnapshot, ContractError> {
+        migration::export_config(&env)
+    }
+
+    /// Import contract configuration from another deployment
+    ///
+    /// Seeds a fresh, uninitialized contract with a configuration exported
+    /// from an old instance. Replaces `initialize` for that deployment.
+    ///
+    /// # Parameters
+    /// - `snapshot`: Configuration exported with `export_config`
+    ///
+    /// # Errors
+    /// - AlreadyInitialized: Contract already has configuration
+    /// - InvalidFeeBps: Snapshot fee exceeds 10000 bps
+    /// - InvalidAddress: Snapshot admin is the token contract
+    /// - Any other error `initialize` returns for the same admin and token
+    ///
+    /// # Authorization
+    /// Requires authentication from the snapshot's admin address
+    pub fn import_config(env: Env, snapshot: ConfigSnapshot) -> Result<(), ContractError> {
+        snapshot.admin.require_auth();
+        migration::import_config(&env, &snapshot)
+    }
+
+    /// Bump the stored schema version after an upgrade
+    ///
+    /// Call once after upgrading to a build that adds fields to stored
+    /// records, then backfill existing remittances with `migrate_range` in
+    /// chunks that fit the CPU budget.
+    ///
+    /// # Returns
+    /// The schema version storage was migrated to
+    ///
+    /// # Errors
+    /// - AlreadyInitialized: Storage is already at the current schema version
+    ///
+    /// # Authorization
+    /// Requires admin authentication
+    pub fn migrate(env: Env) -> Result<u32, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        migration::migrate(&env)
+    }
+
+    /// Backfill defaults for newly added fields on a range of remittances
+    ///
+    /// # Parameters
+    /// - `start_id`: First remittance ID to migrate (inclusive, at least 1)
+    /// - `end_id`: Last remittance ID to migrate (inclusive)
+    ///
+    /// # Returns
+    /// Number of remittances that were updated
+    ///
+    /// # Errors
+    /// - InvalidBatchSize: Empty range or more than MAX_MIGRATION_BATCH_SIZE IDs
+    ///
+    /// # Authorization
+    /// Requires admin authentication
+    pub fn migrate_range(env: Env, start_id: u64, end_id: u64) -> Result<u32, ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        migration::migrate_range(&env, start_id, end_id)
+    }
+
+    /// Get the storage schema version existing records have been migrated to
+    pub fn get_schema_version(env: Env) -> u32 {
+        get_schema_version(&env)
+    }
+
     /// Sets the daily send limit for a specific currency-country pair.
     /// 
     /// # Parameters
@@ -1103,4 +5850,118 @@ impl SwiftRemitContract {
 
         Ok(get_daily_limit(&env, &currency, &country))
     }
+
+    /// Sets a sender volume ceiling over a trailing window of days.
+    ///
+    /// Multiple windows (e.g. 7 and 30 days) can be configured; each is checked
+    /// independently when a remittance is created. Setting a window again
+    /// replaces its limit, and a limit of 0 removes the window.
+    ///
+    /// # Parameters
+    /// - `window_days`: Length of the trailing window in days
+    /// - `limit`: Maximum cumulative amount per sender within the window
+    ///
+    /// # Authorization
+    /// Requires admin authentication
+    ///
+    /// # Errors
+    /// - InvalidAmount: If `window_days` is zero or `limit` is negative
+    /// - NotInitialized: If the contract has not been initialized
+    pub fn set_rolling_limit(env: Env, window_days: u32, limit: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        if window_days == 0 || limit < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut limits = Vec::new(&env);
+        for existing in get_rolling_limits(&env).iter() {
+            if existing.window_days != window_days {
+                limits.push_back(existing);
+            }
+        }
+        if limit > 0 {
+            limits.push_back(RollingLimit { window_days, limit });
+        }
+
+        set_rolling_limits(&env, &limits);
+
+        Ok(())
+    }
+
+    /// Gets all configured rolling window limits.
+    pub fn get_rolling_limits(env: Env) -> Vec<RollingLimit> {
+        get_rolling_limits(&env)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Storage TTL
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Reports the remaining TTL of the contract's critical storage entries.
+    ///
+    /// Covers instance storage (admin and configuration) and up to
+    /// `TTL_SAMPLE_SIZE` of the most recent unsettled remittances. Monitoring
+    /// should call `bump_ttl` before `min_ttl` reaches zero.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TtlStatus)` - Remaining TTLs in ledgers
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_ttl_status(env: Env) -> Result<TtlStatus, ContractError> {
+        let counter = get_remittance_counter(&env)?;
+        let instance_ttl = get_instance_ttl(&env);
+
+        let mut min_remittance_ttl = u32::MAX;
+        let mut remittances_sampled = 0u32;
+        let oldest = counter.saturating_sub(TTL_SAMPLE_SIZE);
+        for id in (oldest + 1..=counter).rev() {
+            if let Ok(remittance) = get_remittance(&env, id) {
+                if matches!(
+                    remittance.status,
+                    RemittanceStatus::Pending | RemittanceStatus::Accepted
+                ) {
+                    min_remittance_ttl = min_remittance_ttl.min(get_remittance_ttl(&env, id));
+                    remittances_sampled += 1;
+                }
+            }
+        }
+
+        Ok(TtlStatus {
+            ledger: env.ledger().sequence(),
+            instance_ttl,
+            min_remittance_ttl,
+            remittances_sampled,
+            min_ttl: instance_ttl.min(min_remittance_ttl),
+        })
+    }
+
+    /// Extends the TTL of instance storage and recent unsettled remittances.
+    ///
+    /// Permissionless so keepers can keep the contract alive; extending TTL
+    /// never changes contract state beyond the tracked live-until ledgers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Entries extended to `TTL_EXTEND_TO` ledgers
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn bump_ttl(env: Env) -> Result<(), ContractError> {
+        let counter = get_remittance_counter(&env)?;
+        extend_instance_ttl(&env);
+
+        let oldest = counter.saturating_sub(TTL_SAMPLE_SIZE);
+        for id in oldest + 1..=counter {
+            if let Ok(remittance) = get_remittance(&env, id) {
+                if matches!(
+                    remittance.status,
+                    RemittanceStatus::Pending | RemittanceStatus::Accepted
+                ) {
+                    extend_remittance_ttl(&env, id);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,11 +1,20 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::{
+    get_remittance_fields, get_schema_version, set_remittance_fields, set_schema_version,
+    ContractError, Remittance, RemittanceStatus,
+};
 
 /// Maximum number of items that can be exported/imported in a single batch
 /// to prevent excessive resource consumption
 pub const MAX_MIGRATION_BATCH_SIZE: u32 = 100;
 
+/// Storage layout version written by this build of the contract.
+///
+/// Bump when a field is added to a stored record, and add its default to
+/// `remittance_field_defaults` so `migrate_range` can backfill old records.
+pub const STORAGE_SCHEMA_VERSION: u32 = 9;
+
 /// Migration state snapshot containing all contract data
 /// This structure ensures complete and verifiable state transfer
 #[contracttype]
@@ -110,6 +119,36 @@ pub struct MigrationVerification {
     pub timestamp: u64,
 }
 
+/// Contract configuration snapshot (everything except remittance records)
+/// Used to seed a fresh deployment from an old one during a wasm upgrade
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigSnapshot {
+    /// Legacy admin address
+    pub admin: Address,
+
+    /// USDC token contract address
+    pub usdc_token: Address,
+
+    /// Platform fee in basis points
+    pub platform_fee_bps: u32,
+
+    /// Contract pause status
+    pub paused: bool,
+
+    /// Global remittance counter
+    pub remittance_counter: u64,
+
+    /// Total number of finalized settlements
+    pub settlement_counter: u64,
+
+    /// Accumulated platform fees
+    pub accumulated_fees: i128,
+
+    /// Per-sender settlement cooldown in seconds
+    pub rate_limit_cooldown: u64,
+}
+
 /// Export complete contract state for migration
 ///
 /// This function creates a complete snapshot of all contract data including:
@@ -341,6 +380,8 @@ fn compute_snapshot_hash(
             RemittanceStatus::Pending => 0u8,
             RemittanceStatus::Completed => 1u8,
             RemittanceStatus::Cancelled => 2u8,
+            RemittanceStatus::Accepted => 3u8,
+            RemittanceStatus::PartiallyReleased => 4u8,
         };
         data.append(&Bytes::from_array(env, &[status_byte]));
 
@@ -505,6 +546,8 @@ fn compute_batch_hash(env: &Env, remittances: &Vec<Remittance>, batch_number: u3
             RemittanceStatus::Pending => 0u8,
             RemittanceStatus::Completed => 1u8,
             RemittanceStatus::Cancelled => 2u8,
+            RemittanceStatus::Accepted => 3u8,
+            RemittanceStatus::PartiallyReleased => 4u8,
         };
         data.append(&Bytes::from_array(env, &[status_byte]));
 
@@ -516,6 +559,132 @@ fn compute_batch_hash(env: &Env, remittances: &Vec<Remittance>, batch_number: u3
     env.crypto().sha256(&data)
 }
 
+/// Export contract configuration
+///
+/// Captures the non-remittance configuration (admin, token, fee, pause state,
+/// counters and accumulated fees) so it can be replayed into a new deployment.
+///
+/// # Returns
+/// ConfigSnapshot of the current configuration
+pub fn export_config(env: &Env) -> Result<ConfigSnapshot, ContractError> {
+    Ok(ConfigSnapshot {
+        admin: crate::storage::get_admin(env)?,
+        usdc_token: crate::storage::get_usdc_token(env)?,
+        platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
+        paused: crate::storage::is_paused(env),
+        remittance_counter: crate::storage::get_remittance_counter(env)?,
+        settlement_counter: crate::storage::get_settlement_counter(env),
+        accumulated_fees: crate::storage::get_accumulated_fees(env)?,
+        rate_limit_cooldown: crate::storage::get_rate_limit_cooldown(env)?,
+    })
+}
+
+/// Import contract configuration
+///
+/// Seeds an uninitialized contract with a configuration exported from
+/// another instance. The snapshot admin becomes the sole admin.
+///
+/// # Security
+/// - Applies the same checks as `initialize`, including refusing an already
+///   initialized contract
+/// - Stamps the current storage schema version, as a fresh deployment would
+///
+/// # Parameters
+/// - `snapshot`: Configuration snapshot to import
+///
+/// # Returns
+/// Ok(()) if import successful, Err otherwise
+pub fn import_config(env: &Env, snapshot: &ConfigSnapshot) -> Result<(), ContractError> {
+    crate::validation::validate_initialize_request(
+        env,
+        &snapshot.admin,
+        &snapshot.usdc_token,
+        snapshot.platform_fee_bps,
+    )?;
+
+    crate::storage::set_admin(env, &snapshot.admin);
+    crate::storage::set_admin_role(env, &snapshot.admin, true);
+    crate::storage::set_admin_count(env, 1);
+    crate::storage::set_usdc_token(env, &snapshot.usdc_token);
+    crate::storage::set_token_decimals(
+        env,
+        soroban_sdk::token::Client::new(env, &snapshot.usdc_token).decimals(),
+    );
+    crate::storage::set_platform_fee_bps(env, snapshot.platform_fee_bps);
+    crate::storage::set_paused(env, snapshot.paused);
+    crate::storage::set_remittance_counter(env, snapshot.remittance_counter);
+    crate::storage::set_settlement_counter(env, snapshot.settlement_counter);
+    crate::storage::set_accumulated_fees(env, snapshot.accumulated_fees);
+    crate::storage::set_rate_limit_cooldown(env, snapshot.rate_limit_cooldown);
+    set_schema_version(env, STORAGE_SCHEMA_VERSION);
+    crate::rate_limit::init_rate_limit(env);
+
+    Ok(())
+}
+
+/// Defaults for `Remittance` fields added after the original layout.
+fn remittance_field_defaults(env: &Env) -> Map<Symbol, Val> {
+    let mut defaults = Map::new(env);
+    defaults.set(Symbol::new(env, "settle_by"), Option::<u64>::None.into_val(env));
+    defaults.set(Symbol::new(env, "late"), false.into_val(env));
+    defaults.set(Symbol::new(env, "quote_amount"), Option::<i128>::None.into_val(env));
+    defaults.set(Symbol::new(env, "quote_currency"), Option::<String>::None.into_val(env));
+    defaults.set(Symbol::new(env, "created_at"), 0u64.into_val(env));
+    defaults.set(Symbol::new(env, "recipient"), Option::<Address>::None.into_val(env));
+    defaults.set(Symbol::new(env, "tag"), 0u32.into_val(env));
+    defaults.set(Symbol::new(env, "cancel_reason"), 0u32.into_val(env));
+    defaults.set(Symbol::new(env, "conditional"), false.into_val(env));
+    defaults.set(Symbol::new(env, "failed_attempts"), 0u32.into_val(env));
+    defaults
+}
+
+/// Marks storage as migrated to `STORAGE_SCHEMA_VERSION`.
+///
+/// Refuses to run again once the stored schema version is current.
+pub fn migrate(env: &Env) -> Result<u32, ContractError> {
+    if get_schema_version(env) >= STORAGE_SCHEMA_VERSION {
+        return Err(ContractError::AlreadyInitialized);
+    }
+
+    set_schema_version(env, STORAGE_SCHEMA_VERSION);
+    Ok(STORAGE_SCHEMA_VERSION)
+}
+
+/// Backfills missing fields on remittances `start_id..=end_id`.
+///
+/// Records already in the current layout and IDs with no record are skipped,
+/// so ranges can be re-run safely. Returns the number of records updated.
+pub fn migrate_range(env: &Env, start_id: u64, end_id: u64) -> Result<u32, ContractError> {
+    if start_id == 0 || end_id < start_id || end_id - start_id >= MAX_MIGRATION_BATCH_SIZE as u64 {
+        return Err(ContractError::InvalidBatchSize);
+    }
+
+    let defaults = remittance_field_defaults(env);
+    let mut updated = 0u32;
+
+    for id in start_id..=end_id {
+        let mut fields = match get_remittance_fields(env, id) {
+            Some(fields) => fields,
+            None => continue,
+        };
+
+        let mut changed = false;
+        for (name, default) in defaults.iter() {
+            if !fields.contains_key(name.clone()) {
+                fields.set(name, default);
+                changed = true;
+            }
+        }
+
+        if changed {
+            set_remittance_fields(env, id, &fields);
+            updated += 1;
+        }
+    }
+
+    Ok(updated)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,9 +5,18 @@
 //! Uses both instance storage (contract-level config) and persistent storage
 //! (per-entity data).
 
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Val, Vec};
 
-use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
+use crate::{AgentStats, ContractError, DailyLimit, EventVerbosity, FeeTier, PauseFlags, RegistrationMode, Remittance, RollingLimit, RoundingMode, TransferRecord, ValidityBand};
+
+/// Number of ledgers critical entries are kept alive for on each extension (~30 days).
+pub const TTL_EXTEND_TO: u32 = 518_400;
+
+/// Maximum number of recent remittances inspected when reporting TTL status.
+pub const TTL_SAMPLE_SIZE: u64 = 20;
+
+/// Seconds an agent's stake stays locked after a deposit when no cooldown is configured (7 days).
+pub const DEFAULT_UNSTAKE_COOLDOWN: u64 = 604_800;
 
 /// Storage keys for the SwiftRemit contract.
 ///
@@ -33,9 +42,15 @@ enum DataKey {
     /// USDC token contract address used for all remittance transactions
     UsdcToken,
 
+    /// Decimals of the settlement token, captured at initialization
+    TokenDecimals,
+
     /// Platform fee in basis points (1 bps = 0.01%)
     PlatformFeeBps,
 
+    /// Rounding applied to the fractional part of the platform fee
+    FeeRoundingMode,
+
     // === Remittance Management ===
     // Keys for tracking and storing remittance transactions
     /// Global counter for generating unique remittance IDs
@@ -44,29 +59,203 @@ enum DataKey {
     /// Individual remittance record indexed by ID (persistent storage)
     Remittance(u64),
 
+    /// Remittance ID created for a sender's idempotency key (persistent storage)
+    IdempotencyKey(Address, BytesN<32>),
+
+    /// Remaining amount a sender has pre-authorized for an agent (persistent storage)
+    AgentAllowance(Address, Address),
+
+    /// Sum of a sender's remaining allowances across all agents (persistent storage)
+    SenderAllowanceTotal(Address),
+
     // === Agent Management ===
     // Keys for tracking registered agents
     /// Agent registration status indexed by agent address (persistent storage)
     AgentRegistered(Address),
 
+    /// Number of currently registered agents (instance storage)
+    AgentCount,
+
+    /// Registered agent addresses in registration order (persistent storage)
+    AgentList,
+
+    /// Whether an agent may be settled through batch settlement (persistent storage)
+    AgentBatchEligible(Address),
+
+    /// Whether new remittances to an agent are blocked (persistent storage)
+    AgentInboundFrozen(Address),
+
+    /// Number of open (pending or accepted) remittances assigned to an agent (persistent storage)
+    AgentPendingCount(Address),
+
+    /// Number of open remittances across all agents (instance storage)
+    ActiveRemittanceCount,
+
+    /// Contract-wide cap on open remittances, 0 for unlimited (instance storage)
+    MaxActiveRemittances,
+
+    /// Agent-chosen cap on open remittances, 0 for unlimited (persistent storage)
+    AgentMaxPending(Address),
+
+    /// Tokens an agent will settle in, empty for any supported token (persistent storage)
+    AgentAllowedTokens(Address),
+
+    /// Settlement activity counters indexed by agent address (persistent storage)
+    AgentStats(Address),
+
+    /// Ledger timestamp of an agent's most recent settlement (persistent storage)
+    AgentLastActive(Address),
+
+    /// Count of payouts ever settled to an agent, used as a settlement nonce (persistent storage)
+    AgentSettlementNonce(Address),
+
+    /// Whether agents may self-register (instance storage)
+    OpenRegistration,
+
+    /// Stake an agent must deposit to self-register (instance storage)
+    RegistrationStake,
+
+    /// Tokens an agent has locked as stake (persistent storage)
+    AgentStake(Address),
+
+    /// Sum of all agent stakes held by the contract (instance storage)
+    TotalStaked,
+
+    /// Timestamp of an agent's most recent stake deposit (persistent storage)
+    AgentStakedAt(Address),
+
+    /// Remittance amount above which the agent must hold a stake (instance storage)
+    StakeThreshold,
+
+    /// Seconds a stake stays locked after a deposit (instance storage)
+    UnstakeCooldown,
+
     // === Fee Tracking ===
     // Keys for managing platform fees
     /// Total accumulated platform fees awaiting withdrawal
     AccumulatedFees,
 
+    /// Total fees ever collected, never reduced by withdrawals (instance storage)
+    LifetimeFees,
+
+    /// Sum of amounts held in escrow for unsettled remittances (instance storage)
+    OutstandingLiability,
+
+    /// Balance kept in the contract on top of outstanding liability (instance storage)
+    LiquidityFloor,
+
+    /// Yield earned on escrowed funds awaiting sweep (instance storage)
+    YieldPool,
+
+    /// Standing recipient for automatically swept fees (instance storage)
+    FeeRecipient,
+
+    /// Whether settlement fees are sent to the fee recipient immediately (instance storage)
+    AutoSweep,
+
+    /// Ceiling on retained fees and the treasury that receives the excess (instance storage)
+    FeeCap,
+
+    /// Smallest net payout a settlement may transfer (instance storage)
+    MinPayout,
+
+    /// Default lifetime in seconds for remittances created without an expiry (instance storage)
+    DefaultValiditySeconds,
+
+    /// Seconds after expiry during which the agent may still settle (instance storage)
+    ExpiryGraceSeconds,
+
+    /// Minimum age in seconds before a sender may cancel a remittance (instance storage)
+    MinCancelAgeSeconds,
+
+    /// Which completion events a settlement emits (instance storage)
+    EventVerbosity,
+
+    /// Whether settlements emit a diagnostic balance-check event (instance storage)
+    BalanceEvents,
+
+    /// Pending fee rate and the timestamp it takes effect (instance storage)
+    ScheduledFee,
+
+    /// Fee rate override for remittances in a specific token (persistent storage)
+    TokenFeeBps(Address),
+
+    /// Minimum net payout override for settlements in a specific token (persistent storage)
+    TokenMinPayout(Address),
+
+    /// Absolute minimum fee charged on a new remittance (instance storage)
+    MinFee,
+
+    /// Ceiling that every configured fee rate must stay within (instance storage)
+    MaxAllowedFeeBps,
+
+    /// Share of each payout, in basis points, withheld until `release_holdback` (instance storage)
+    HoldbackBps,
+
+    /// Amount paid from accumulated fees to a keeper for each expired remittance it refunds (instance storage)
+    KeeperReward,
+
+    /// Payout amount still held back for a partially released remittance (persistent storage)
+    Holdback(u64),
+
+    /// Address owed the held-back payout of a remittance (persistent storage)
+    HoldbackPayee(u64),
+
+    /// Amount-based fee schedule, sorted by ascending `min_amount` (instance storage)
+    FeeTiers,
+
+    /// Amount-based default validity windows, sorted by ascending `min_amount` (instance storage)
+    ValidityBands,
+
+    /// Storage layout version that existing records have been migrated to (instance storage)
+    SchemaVersion,
+
     /// Integrator fee in basis points
     IntegratorFeeBps,
 
     /// Total accumulated integrator fees awaiting withdrawal
     AccumulatedIntegratorFees,
 
-    /// Contract pause status for emergency halts
+    /// Contract pause status for emergency halts (legacy, superseded by PauseFlags)
     Paused,
 
+    /// Per-operation pause switches (instance storage)
+    PauseFlags,
+
+    /// Whether new remittances may be created, cleared for wind-down (instance storage)
+    AcceptingNew,
+
+    /// Whether only allowlisted senders may create remittances (instance storage)
+    SenderAllowlistEnabled,
+
+    /// Sender approved to create remittances while the allowlist is enabled (persistent storage)
+    SenderAllowed(Address),
+
+    /// Single-operation volume above which the contract pauses itself (instance storage)
+    AutoPauseThreshold,
+
     // === Settlement Deduplication ===
     // Keys for preventing duplicate settlement execution
     /// Settlement hash for duplicate detection (persistent storage)
     SettlementHash(u64),
+
+    /// Settlement receipt hash for a completed remittance (persistent storage)
+    SettlementReceipt(u64),
+
+    /// SHA-256 of the claim code locking a claimable remittance (persistent storage)
+    ClaimCodeHash(u64),
+
+    /// Ed25519 public key an agent signs payout authorizations with (persistent storage)
+    AgentPublicKey(Address),
+
+    /// Whether an address may confirm payouts on an agent's behalf (persistent storage)
+    AgentOperator(Address, Address),
+
+    /// Admin-assigned display label for an agent, e.g. name and location (persistent storage)
+    AgentLabel(Address),
+
+    /// Marks an agent's payout authorization nonce as consumed (persistent storage)
+    AgentNonceUsed(Address, u64),
     
     // === Rate Limiting ===
     // Keys for preventing abuse through rate limiting
@@ -83,12 +272,21 @@ enum DataKey {
     
     /// User transfer records indexed by user address (persistent storage)
     UserTransfers(Address),
+
+    /// Configured trailing-window volume limits (instance storage)
+    RollingLimits,
     
     // === Token Whitelist ===
     // Keys for managing whitelisted tokens
     /// Token whitelist status indexed by token address (persistent storage)
     TokenWhitelisted(Address),
+
+    /// Whitelisted token addresses in whitelisting order (persistent storage)
+    SupportedTokens,
     
+    /// Token blocked by the admin regardless of whitelist status (persistent storage)
+    TokenDenied(Address),
+
     /// Settlement completion event emission tracking (persistent storage)
     /// Tracks whether the completion event has been emitted for a settlement
     SettlementEventEmitted(u64),
@@ -98,6 +296,54 @@ enum DataKey {
     /// Incremented atomically each time a settlement is successfully completed
     SettlementCounter,
 
+    // === Agent Liquidity Pools ===
+    // Keys for cross-token settlement from pre-funded agent pools
+    /// Pool balance indexed by agent and token address (persistent storage)
+    AgentPool(Address, Address),
+
+    /// Sum of all agent pool balances held in a token (persistent storage)
+    AgentPoolTotal(Address),
+
+    /// Conversion rate from USDC into a pool token, in basis points (persistent storage)
+    FxRate(Address),
+
+    /// Conversion rate from a quote currency into USDC, in basis points (persistent storage)
+    QuoteRate(String),
+
+    // === Storage TTL Tracking ===
+    // Keys recording when critical entries will be archived
+    /// Ledger sequence until which instance storage is live (instance storage)
+    InstanceLiveUntil,
+
+    /// Whether the sender has asked for a remittance to be refunded (persistent storage)
+    RefundRequested(u64),
+
+    /// Address allowed to resolve conditional remittances (instance storage)
+    Oracle,
+
+    /// Whether the oracle has marked a conditional remittance's condition met (persistent storage)
+    ConditionMet(u64),
+
+    /// Ledger sequence until which a remittance record is live (persistent storage)
+    RemittanceLiveUntil(u64),
+}
+
+/// Adds to a running i128 total, reporting overflow as a contract error.
+///
+/// Every accumulated amount (liability, fees, stake, agent totals) goes
+/// through this helper so overflow is handled the same way everywhere.
+///
+/// # Arguments
+///
+/// * `total` - Current running total
+/// * `amount` - Amount to add
+///
+/// # Returns
+///
+/// * `Ok(i128)` - The new total
+/// * `Err(ContractError::Overflow)` - The sum does not fit in an i128
+pub fn checked_add_i128(total: i128, amount: i128) -> Result<i128, ContractError> {
+    total.checked_add(amount).ok_or(ContractError::Overflow)
 }
 
 /// Checks if the contract has an admin configured.
@@ -168,6 +414,30 @@ pub fn get_usdc_token(env: &Env) -> Result<Address, ContractError> {
         .ok_or(ContractError::NotInitialized)
 }
 
+/// Stores the settlement token's decimals.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `decimals` - Number of decimal places reported by the token contract
+pub fn set_token_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&DataKey::TokenDecimals, &decimals);
+}
+
+/// Retrieves the settlement token's decimals.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Some(u32)` - Decimals captured at initialization
+/// * `None` - Contract was initialized before decimals were recorded
+pub fn get_token_decimals(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::TokenDecimals)
+}
+
 /// Sets the platform fee rate.
 ///
 /// # Arguments
@@ -197,6 +467,34 @@ pub fn get_platform_fee_bps(env: &Env) -> Result<u32, ContractError> {
         .ok_or(ContractError::NotInitialized)
 }
 
+/// Sets the rounding mode used for fee calculation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `mode` - Rounding mode to store
+pub fn set_rounding_mode(env: &Env, mode: &RoundingMode) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeRoundingMode, mode);
+}
+
+/// Retrieves the rounding mode used for fee calculation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `RoundingMode` - Configured mode (defaults to `RoundingMode::Down`)
+pub fn get_rounding_mode(env: &Env) -> RoundingMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeRoundingMode)
+        .unwrap_or(RoundingMode::Down)
+}
+
 /// Sets the remittance counter for ID generation.
 ///
 /// # Arguments
@@ -237,6 +535,7 @@ pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
     env.storage()
         .persistent()
         .set(&DataKey::Remittance(id), remittance);
+    extend_remittance_ttl(env, id);
 }
 
 /// Retrieves a remittance record by ID.
@@ -257,126 +556,2059 @@ pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
         .ok_or(ContractError::RemittanceNotFound)
 }
 
-/// Sets an agent's registration status.
+/// Retrieves a remittance record as its raw field map.
+///
+/// Used by storage migrations to read records written under an older
+/// layout that no longer decode as `Remittance`.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Agent address
-/// * `registered` - Registration status (true = registered, false = removed)
-pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
+/// * `id` - Remittance ID to retrieve
+///
+/// # Returns
+///
+/// * `Some(Map<Symbol, Val>)` - Stored fields keyed by field name
+/// * `None` - No remittance stored under this ID
+pub fn get_remittance_fields(env: &Env, id: u64) -> Option<Map<Symbol, Val>> {
+    env.storage().persistent().get(&DataKey::Remittance(id))
+}
+
+/// Stores a remittance record from its raw field map.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID
+/// * `fields` - Field map in the current `Remittance` layout
+pub fn set_remittance_fields(env: &Env, id: u64, fields: &Map<Symbol, Val>) {
     env.storage()
         .persistent()
-        .set(&DataKey::AgentRegistered(agent.clone()), &registered);
+        .set(&DataKey::Remittance(id), fields);
+    extend_remittance_ttl(env, id);
 }
 
-/// Checks if an address is registered as an agent.
+/// Retrieves the storage layout version existing records have been migrated to.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Agent address to check
 ///
 /// # Returns
 ///
-/// * `true` - Address is registered
-/// * `false` - Address is not registered
-pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+/// * `u32` - Schema version (1 for deployments that have never migrated)
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+/// Records the storage layout version existing records have been migrated to.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `version` - Schema version
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::SchemaVersion, &version);
+}
+
+/// Retrieves the remittance ID previously created for an idempotency key.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender the key belongs to
+/// * `key` - Client-supplied idempotency key
+///
+/// # Returns
+///
+/// * `Some(u64)` - ID of the remittance created with this key
+/// * `None` - Key has not been used by this sender
+pub fn get_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>) -> Option<u64> {
     env.storage()
         .persistent()
-        .get(&DataKey::AgentRegistered(agent.clone()))
-        .unwrap_or(false)
+        .get(&DataKey::IdempotencyKey(sender.clone(), key.clone()))
 }
 
-/// Sets the accumulated platform fees.
+/// Records the remittance ID created for an idempotency key.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fees` - Total accumulated fees
-pub fn set_accumulated_fees(env: &Env, fees: i128) {
+/// * `sender` - Sender the key belongs to
+/// * `key` - Client-supplied idempotency key
+/// * `remittance_id` - ID of the created remittance
+pub fn set_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>, remittance_id: u64) {
     env.storage()
-        .instance()
-        .set(&DataKey::AccumulatedFees, &fees);
+        .persistent()
+        .set(&DataKey::IdempotencyKey(sender.clone(), key.clone()), &remittance_id);
 }
 
-/// Retrieves the accumulated platform fees.
+/// Retrieves the remaining allowance a sender has approved for an agent.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `sender` - Sender who approved the allowance
+/// * `agent` - Agent the allowance is for
 ///
 /// # Returns
 ///
-/// * `Ok(i128)` - Total accumulated fees
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+/// * `i128` - Remaining allowance (0 if none)
+pub fn get_allowance(env: &Env, sender: &Address, agent: &Address) -> i128 {
     env.storage()
-        .instance()
-        .get(&DataKey::AccumulatedFees)
-        .ok_or(ContractError::NotInitialized)
+        .persistent()
+        .get(&DataKey::AgentAllowance(sender.clone(), agent.clone()))
+        .unwrap_or(0)
 }
 
-/// Checks if a settlement hash exists for duplicate detection.
+/// Sets the remaining allowance for a sender-agent pair and keeps the sender's total in step.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - Remittance ID to check
+/// * `sender` - Sender who approved the allowance
+/// * `agent` - Agent the allowance is for
+/// * `remaining` - New remaining allowance
 ///
 /// # Returns
 ///
-/// * `true` - Settlement has been executed
-/// * `false` - Settlement has not been executed
-pub fn has_settlement_hash(env: &Env, remittance_id: u64) -> bool {
+/// * `Ok(i128)` - The sender's total remaining allowance across all agents
+/// * `Err(ContractError::Overflow)` - Total would overflow
+pub fn set_allowance(env: &Env, sender: &Address, agent: &Address, remaining: i128) -> Result<i128, ContractError> {
+    let previous = get_allowance(env, sender, agent);
+    let total = get_sender_allowance_total(env, sender)
+        .checked_sub(previous)
+        .ok_or(ContractError::Overflow)?;
+    let total = checked_add_i128(total, remaining)?;
+
     env.storage()
         .persistent()
-        .has(&DataKey::SettlementHash(remittance_id))
+        .set(&DataKey::AgentAllowance(sender.clone(), agent.clone()), &remaining);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderAllowanceTotal(sender.clone()), &total);
+    Ok(total)
 }
 
-/// Marks a settlement as executed for duplicate prevention.
+/// Retrieves the sum of a sender's remaining allowances across all agents.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - Remittance ID to mark as settled
-pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
+/// * `sender` - Sender address
+///
+/// # Returns
+///
+/// * `i128` - Total remaining allowance (0 if none)
+pub fn get_sender_allowance_total(env: &Env, sender: &Address) -> i128 {
     env.storage()
         .persistent()
-        .set(&DataKey::SettlementHash(remittance_id), &true);
+        .get(&DataKey::SenderAllowanceTotal(sender.clone()))
+        .unwrap_or(0)
 }
 
-pub fn is_paused(env: &Env) -> bool {
+/// Checks if a remittance record exists for an ID.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - The remittance ID to check
+///
+/// # Returns
+///
+/// * `true` - A record is stored under this ID
+/// * `false` - No record exists
+pub fn has_remittance(env: &Env, id: u64) -> bool {
     env.storage()
-        .instance()
-        .get(&DataKey::Paused)
-        .unwrap_or(false)
+        .persistent()
+        .has(&DataKey::Remittance(id))
 }
 
-pub fn set_paused(env: &Env, paused: bool) {
-    env.storage().instance().set(&DataKey::Paused, &paused);
-}
+/// Sets an agent's registration status.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `registered` - Registration status (true = registered, false = removed)
+///
+/// The agent count and list only change when the status actually flips, so
+/// re-registering or re-removing an agent leaves them untouched. Removing an
+/// agent also clears its label.
+pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
+    if is_agent_registered(env, agent) != registered {
+        let count = get_agent_count(env);
+        let count = if registered {
+            count.saturating_add(1)
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage().instance().set(&DataKey::AgentCount, &count);
+
+        let mut agents = get_agent_list(env);
+        if registered {
+            if !agents.contains(agent) {
+                agents.push_back(agent.clone());
+            }
+        } else if let Some(index) = agents.first_index_of(agent) {
+            agents.remove(index);
+        }
+        env.storage().persistent().set(&DataKey::AgentList, &agents);
+
+        if !registered {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AgentLabel(agent.clone()));
+        }
+    }
 
-pub fn set_rate_limit_cooldown(env: &Env, cooldown_seconds: u64) {
     env.storage()
-        .instance()
-        .set(&DataKey::RateLimitCooldown, &cooldown_seconds);
+        .persistent()
+        .set(&DataKey::AgentRegistered(agent.clone()), &registered);
 }
 
-pub fn get_rate_limit_cooldown(env: &Env) -> Result<u64, ContractError> {
+/// Returns the number of currently registered agents.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Registered agent count, 0 if none have been registered
+pub fn get_agent_count(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .get(&DataKey::RateLimitCooldown)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::AgentCount)
+        .unwrap_or(0)
 }
 
-pub fn set_last_settlement_time(env: &Env, sender: &Address, timestamp: u64) {
+/// Retrieves all registered agents in registration order.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<Address>` - Registered agents, empty if none have been registered
+pub fn get_agent_list(env: &Env) -> Vec<Address> {
     env.storage()
         .persistent()
-        .set(&DataKey::LastSettlementTime(sender.clone()), &timestamp);
+        .get(&DataKey::AgentList)
+        .unwrap_or(Vec::new(env))
 }
 
-pub fn get_last_settlement_time(env: &Env, sender: &Address) -> Option<u64> {
+/// Checks if an address is registered as an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address to check
+///
+/// # Returns
+///
+/// * `true` - Address is registered
+/// * `false` - Address is not registered
+pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRegistered(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets whether an agent is eligible for batch settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `eligible` - `true` to allow the agent in batches, `false` to exclude it
+pub fn set_agent_batch_eligible(env: &Env, agent: &Address, eligible: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentBatchEligible(agent.clone()), &eligible);
+}
+
+/// Checks if an agent is eligible for batch settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address to check
+///
+/// # Returns
+///
+/// * `true` - Agent has been approved for batch settlement
+/// * `false` - Agent is not approved (default)
+pub fn is_agent_batch_eligible(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentBatchEligible(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets whether new remittances to an agent are blocked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+/// * `frozen` - `true` to block new inbound remittances, `false` to allow them
+pub fn set_agent_inbound_frozen(env: &Env, agent: &Address, frozen: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentInboundFrozen(agent.clone()), &frozen);
+}
+
+/// Checks if new remittances to an agent are blocked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address to check
+///
+/// # Returns
+///
+/// * `true` - Agent's inbound funds are frozen
+/// * `false` - Agent may receive new remittances (default)
+pub fn is_agent_inbound_frozen(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentInboundFrozen(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Retrieves the number of open remittances assigned to an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `u32` - Pending or accepted remittances awaiting settlement (0 if none)
+pub fn get_agent_pending_count(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPendingCount(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Records a new open remittance for an agent.
+///
+/// Also counts it towards the contract-wide active remittance total.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent receiving the remittance
+///
+/// # Returns
+///
+/// * `Ok(())` - Count incremented
+/// * `Err(ContractError::Overflow)` - Count would exceed u32::MAX
+pub fn increment_agent_pending_count(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let count = get_agent_pending_count(env, agent)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPendingCount(agent.clone()), &count);
+
+    let active = get_active_remittance_count(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::ActiveRemittanceCount, &active);
+    Ok(())
+}
+
+/// Records that one of an agent's open remittances reached a terminal state.
+///
+/// Also removes it from the contract-wide active remittance total. Both
+/// counts saturate at zero so remittances created before they existed can
+/// still be settled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent the remittance was assigned to
+pub fn decrement_agent_pending_count(env: &Env, agent: &Address) {
+    let count = get_agent_pending_count(env, agent).saturating_sub(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPendingCount(agent.clone()), &count);
+
+    let active = get_active_remittance_count(env).saturating_sub(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::ActiveRemittanceCount, &active);
+}
+
+/// Retrieves the number of open remittances across all agents.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Open (pending or accepted) remittance count
+pub fn get_active_remittance_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActiveRemittanceCount)
+        .unwrap_or(0)
+}
+
+/// Retrieves the contract-wide cap on open remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Maximum open remittances (0 for unlimited)
+pub fn get_max_active_remittances(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxActiveRemittances)
+        .unwrap_or(0)
+}
+
+/// Sets the contract-wide cap on open remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `max_active` - Maximum open remittances (0 for unlimited)
+pub fn set_max_active_remittances(env: &Env, max_active: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxActiveRemittances, &max_active);
+}
+
+/// Retrieves an agent's cap on open remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `u32` - Maximum open remittances (0 means unlimited)
+pub fn get_agent_max_pending(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentMaxPending(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets an agent's cap on open remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `max_pending` - Maximum open remittances (0 for unlimited)
+pub fn set_agent_max_pending(env: &Env, agent: &Address, max_pending: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentMaxPending(agent.clone()), &max_pending);
+}
+
+/// Retrieves the tokens an agent accepts remittances in.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `Vec<Address>` - Allowed tokens (empty means any supported token)
+pub fn get_agent_allowed_tokens(env: &Env, agent: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentAllowedTokens(agent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Sets the tokens an agent accepts remittances in.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `tokens` - Allowed tokens (empty for any supported token)
+pub fn set_agent_allowed_tokens(env: &Env, agent: &Address, tokens: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentAllowedTokens(agent.clone()), tokens);
+}
+
+/// Retrieves settlement activity counters for an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+///
+/// # Returns
+///
+/// * `AgentStats` - Stored counters (all zero if the agent has no activity)
+pub fn get_agent_stats(env: &Env, agent: &Address) -> AgentStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStats(agent.clone()))
+        .unwrap_or(AgentStats {
+            completed: 0,
+            expired: 0,
+            total_paid: 0,
+        })
+}
+
+fn set_agent_stats(env: &Env, agent: &Address, stats: &AgentStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStats(agent.clone()), stats);
+}
+
+/// Records a completed settlement against an agent's stats.
+///
+/// Also stamps the agent's last-active time with the current ledger timestamp.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent that was paid
+/// * `amount` - Payout amount transferred to the agent
+///
+/// # Returns
+///
+/// * `Ok(())` - Stats updated
+/// * `Err(ContractError::Overflow)` - Counter or total overflow
+pub fn record_agent_completion(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let mut stats = get_agent_stats(env, agent);
+    stats.completed = stats.completed.checked_add(1).ok_or(ContractError::Overflow)?;
+    stats.total_paid = checked_add_i128(stats.total_paid, amount)?;
+    set_agent_stats(env, agent, &stats);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentLastActive(agent.clone()), &env.ledger().timestamp());
+    Ok(())
+}
+
+/// Retrieves an agent's latest settlement nonce.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+///
+/// # Returns
+///
+/// * `u64` - Nonce of the agent's most recent payout (0 if none)
+pub fn get_agent_settlement_nonce(env: &Env, agent: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentSettlementNonce(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Advances an agent's settlement nonce for a new payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent that was paid
+///
+/// # Returns
+///
+/// * `Ok(u64)` - Nonce assigned to this payout, starting at 1
+/// * `Err(ContractError::Overflow)` - Nonce would overflow
+pub fn increment_agent_settlement_nonce(env: &Env, agent: &Address) -> Result<u64, ContractError> {
+    let nonce = get_agent_settlement_nonce(env, agent)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentSettlementNonce(agent.clone()), &nonce);
+    Ok(nonce)
+}
+
+/// Retrieves when an agent last settled a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent
+///
+/// # Returns
+///
+/// * `u64` - Ledger timestamp of the latest settlement (0 if none)
+pub fn get_agent_last_active(env: &Env, agent: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentLastActive(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Records an expired, reclaimed remittance against an agent's stats.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent that did not settle in time
+///
+/// # Returns
+///
+/// * `Ok(())` - Stats updated
+/// * `Err(ContractError::Overflow)` - Counter overflow
+pub fn record_agent_expiry(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    let mut stats = get_agent_stats(env, agent);
+    stats.expired = stats.expired.checked_add(1).ok_or(ContractError::Overflow)?;
+    set_agent_stats(env, agent, &stats);
+    Ok(())
+}
+
+/// Sets the accumulated platform fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fees` - Total accumulated fees
+pub fn set_accumulated_fees(env: &Env, fees: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccumulatedFees, &fees);
+}
+
+/// Retrieves the accumulated platform fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Total accumulated fees
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccumulatedFees)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Retrieves the total fees ever collected.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Lifetime fee revenue (0 when none collected)
+pub fn get_lifetime_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LifetimeFees)
+        .unwrap_or(0)
+}
+
+/// Sets the total fees ever collected.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fees` - Lifetime fee revenue
+pub fn set_lifetime_fees(env: &Env, fees: i128) {
+    env.storage().instance().set(&DataKey::LifetimeFees, &fees);
+}
+
+/// Retrieves the standing fee recipient.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Some(Address)` - Configured recipient
+/// * `None` - No recipient configured
+pub fn get_fee_recipient(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::FeeRecipient)
+}
+
+/// Sets the standing fee recipient.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `recipient` - Address to receive swept fees
+pub fn set_fee_recipient(env: &Env, recipient: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeRecipient, recipient);
+}
+
+/// Checks if settlement fees are swept to the fee recipient immediately.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `bool` - `true` if auto-sweep is enabled (defaults to `false`)
+pub fn is_auto_sweep(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AutoSweep)
+        .unwrap_or(false)
+}
+
+/// Enables or disables automatic fee sweeping.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `enabled` - Whether to sweep fees on settlement
+pub fn set_auto_sweep(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::AutoSweep, &enabled);
+}
+
+/// Retrieves the fee cap and its overflow treasury.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Some((cap, treasury))` - Retained fees are capped at `cap`
+/// * `None` - Fees accumulate without limit
+pub fn get_fee_cap(env: &Env) -> Option<(i128, Address)> {
+    env.storage().instance().get(&DataKey::FeeCap)
+}
+
+/// Sets the fee cap and its overflow treasury.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `cap` - Maximum retained fees
+/// * `treasury` - Address that receives fees above the cap
+pub fn set_fee_cap(env: &Env, cap: i128, treasury: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeCap, &(cap, treasury.clone()));
+}
+
+/// Removes the fee cap so fees accumulate without limit.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+pub fn clear_fee_cap(env: &Env) {
+    env.storage().instance().remove(&DataKey::FeeCap);
+}
+
+/// Retrieves the minimum net payout for settlements.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Minimum payout (0 when the check is disabled)
+pub fn get_min_payout(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinPayout)
+        .unwrap_or(0)
+}
+
+/// Sets the minimum net payout for settlements.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `min_payout` - Minimum payout (0 disables the check)
+pub fn set_min_payout(env: &Env, min_payout: i128) {
+    env.storage().instance().set(&DataKey::MinPayout, &min_payout);
+}
+
+/// Retrieves the minimum net payout override for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token address
+///
+/// # Returns
+///
+/// * `Some(i128)` - Minimum payout for settlements in this token
+/// * `None` - Token uses the global minimum payout
+pub fn get_token_min_payout(env: &Env, token: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenMinPayout(token.clone()))
+}
+
+/// Sets the minimum net payout override for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token address
+/// * `min_payout` - Minimum payout in the token's own units
+pub fn set_token_min_payout(env: &Env, token: &Address, min_payout: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenMinPayout(token.clone()), &min_payout);
+}
+
+/// Retrieves the default lifetime applied to remittances created without an expiry.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u64` - Validity in seconds (0 means no default expiry)
+pub fn get_default_validity_seconds(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DefaultValiditySeconds)
+        .unwrap_or(0)
+}
+
+/// Sets the default lifetime applied to remittances created without an expiry.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `seconds` - Validity in seconds (0 disables the default)
+pub fn set_default_validity_seconds(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DefaultValiditySeconds, &seconds);
+}
+
+/// Retrieves the amount-based default validity bands.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<ValidityBand>` - Bands sorted by ascending `min_amount` (empty when unset)
+pub fn get_validity_bands(env: &Env) -> Vec<ValidityBand> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ValidityBands)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the amount-based default validity bands.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `bands` - Bands sorted by ascending `min_amount`
+pub fn set_validity_bands(env: &Env, bands: &Vec<ValidityBand>) {
+    env.storage().instance().set(&DataKey::ValidityBands, bands);
+}
+
+/// Retrieves the grace window after expiry during which settlement is still allowed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u64` - Grace window in seconds (0 when expiry is a hard cutoff)
+pub fn get_expiry_grace_seconds(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExpiryGraceSeconds)
+        .unwrap_or(0)
+}
+
+/// Sets the grace window after expiry during which settlement is still allowed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `seconds` - Grace window in seconds (0 for a hard cutoff)
+pub fn set_expiry_grace_seconds(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ExpiryGraceSeconds, &seconds);
+}
+
+/// Retrieves the minimum age a remittance must reach before the sender may cancel it.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u64` - Minimum age in seconds (0 allows immediate cancellation)
+pub fn get_min_cancel_age_seconds(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinCancelAgeSeconds)
+        .unwrap_or(0)
+}
+
+/// Sets the minimum age a remittance must reach before the sender may cancel it.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `seconds` - Minimum age in seconds (0 allows immediate cancellation)
+pub fn set_min_cancel_age_seconds(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinCancelAgeSeconds, &seconds);
+}
+
+/// Retrieves which completion events a settlement emits.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `EventVerbosity` - Configured verbosity (defaults to `EventVerbosity::Full`)
+pub fn get_event_verbosity(env: &Env) -> EventVerbosity {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventVerbosity)
+        .unwrap_or(EventVerbosity::Full)
+}
+
+/// Sets which completion events a settlement emits.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `verbosity` - Verbosity to store
+pub fn set_event_verbosity(env: &Env, verbosity: &EventVerbosity) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EventVerbosity, verbosity);
+}
+
+/// Checks whether settlements emit balance-check events.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `true` - Diagnostic events are enabled
+/// * `false` - Disabled (default)
+pub fn is_balance_events_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::BalanceEvents)
+        .unwrap_or(false)
+}
+
+/// Enables or disables balance-check events on settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `enabled` - Whether to emit the events
+pub fn set_balance_events_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::BalanceEvents, &enabled);
+}
+
+/// Retrieves the fee override for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token address
+///
+/// # Returns
+///
+/// * `Some(u32)` - Fee rate in basis points for this token
+/// * `None` - Token uses the global platform fee
+pub fn get_token_fee_bps(env: &Env, token: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenFeeBps(token.clone()))
+}
+
+/// Sets the fee override for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token address
+/// * `fee_bps` - Fee rate in basis points
+pub fn set_token_fee_bps(env: &Env, token: &Address, fee_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenFeeBps(token.clone()), &fee_bps);
+}
+
+/// Retrieves the absolute minimum fee charged on a new remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Minimum fee (0 when no floor applies)
+pub fn get_min_fee(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::MinFee).unwrap_or(0)
+}
+
+/// Sets the absolute minimum fee charged on a new remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `min_fee` - Minimum fee (0 disables the floor)
+pub fn set_min_fee(env: &Env, min_fee: i128) {
+    env.storage().instance().set(&DataKey::MinFee, &min_fee);
+}
+
+/// Retrieves the share of each payout withheld until a second confirmation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Holdback in basis points (0 when settlement is single-step)
+pub fn get_holdback_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::HoldbackBps).unwrap_or(0)
+}
+
+/// Sets the share of each payout withheld until a second confirmation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `holdback_bps` - Holdback in basis points (0 disables the holdback)
+pub fn set_holdback_bps(env: &Env, holdback_bps: u32) {
+    env.storage().instance().set(&DataKey::HoldbackBps, &holdback_bps);
+}
+
+/// Retrieves the reward paid to a keeper per expired remittance refunded.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Reward per refund (0 when keepers are unpaid)
+pub fn get_keeper_reward(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::KeeperReward).unwrap_or(0)
+}
+
+/// Sets the reward paid to a keeper per expired remittance refunded.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `reward` - Reward per refund (0 disables rewards)
+pub fn set_keeper_reward(env: &Env, reward: i128) {
+    env.storage().instance().set(&DataKey::KeeperReward, &reward);
+}
+
+/// Retrieves the payout amount still held back for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+///
+/// # Returns
+///
+/// * `i128` - Amount awaiting `release_holdback` (0 if none)
+pub fn get_holdback(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Holdback(remittance_id))
+        .unwrap_or(0)
+}
+
+/// Records or clears the payout amount held back for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+/// * `amount` - Amount held back (0 clears the record)
+pub fn set_holdback(env: &Env, remittance_id: u64, amount: i128) {
+    let key = DataKey::Holdback(remittance_id);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves the address owed a remittance's held-back payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+///
+/// # Returns
+///
+/// * `Option<Address>` - Address that received the released part of the payout
+pub fn get_holdback_payee(env: &Env, remittance_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HoldbackPayee(remittance_id))
+}
+
+/// Records or clears the address owed a remittance's held-back payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+/// * `payee` - Address to pay on release (`None` clears the record)
+pub fn set_holdback_payee(env: &Env, remittance_id: u64, payee: Option<&Address>) {
+    let key = DataKey::HoldbackPayee(remittance_id);
+    match payee {
+        Some(payee) => env.storage().persistent().set(&key, payee),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the ceiling on configured fee rates.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Highest fee rate in basis points the admin may set (10000 by default)
+pub fn get_max_allowed_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxAllowedFeeBps)
+        .unwrap_or(10000)
+}
+
+/// Sets the ceiling on configured fee rates.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `max_fee_bps` - Highest fee rate in basis points the admin may set
+pub fn set_max_allowed_fee_bps(env: &Env, max_fee_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxAllowedFeeBps, &max_fee_bps);
+}
+
+/// Retrieves the amount-based fee schedule.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<FeeTier>` - Tiers sorted by ascending `min_amount` (empty when unset)
+pub fn get_fee_tiers(env: &Env) -> Vec<FeeTier> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeTiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the amount-based fee schedule.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `tiers` - Tiers sorted by ascending `min_amount`
+pub fn set_fee_tiers(env: &Env, tiers: &Vec<FeeTier>) {
+    env.storage().instance().set(&DataKey::FeeTiers, tiers);
+}
+
+/// Retrieves how new agents may be registered.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `RegistrationMode` - Current mode (`AdminOnly` if never set)
+pub fn get_registration_mode(env: &Env) -> RegistrationMode {
+    let open: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::OpenRegistration)
+        .unwrap_or(false);
+    if open {
+        RegistrationMode::Open
+    } else {
+        RegistrationMode::AdminOnly
+    }
+}
+
+/// Enables or disables agent self-registration.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `open` - Whether agents may register themselves
+pub fn set_open_registration(env: &Env, open: bool) {
+    env.storage().instance().set(&DataKey::OpenRegistration, &open);
+}
+
+/// Retrieves the stake required to self-register as an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Required stake (0 if none)
+pub fn get_registration_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegistrationStake)
+        .unwrap_or(0)
+}
+
+/// Sets the stake required to self-register as an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Required stake (0 for none)
+pub fn set_registration_stake(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::RegistrationStake, &amount);
+}
+
+/// Retrieves the tokens an agent has locked as stake.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `i128` - Staked amount (0 if none)
+pub fn get_agent_stake(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStake(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets an agent's stake and keeps the contract-wide total in step.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `amount` - New staked amount
+///
+/// # Returns
+///
+/// * `Ok(())` - Stake updated
+/// * `Err(ContractError::Overflow)` - Total stake would overflow
+pub fn set_agent_stake(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let previous = get_agent_stake(env, agent);
+    let total = get_total_staked(env)
+        .checked_sub(previous)
+        .ok_or(ContractError::Overflow)?;
+    let total = checked_add_i128(total, amount)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStake(agent.clone()), &amount);
+    env.storage().instance().set(&DataKey::TotalStaked, &total);
+    Ok(())
+}
+
+/// Retrieves the sum of all agent stakes held by the contract.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Total staked amount (0 if none)
+pub fn get_total_staked(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalStaked)
+        .unwrap_or(0)
+}
+
+/// Retrieves when an agent last deposited stake.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `u64` - Timestamp of the last deposit (0 if never staked)
+pub fn get_agent_staked_at(env: &Env, agent: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStakedAt(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Records when an agent last deposited stake.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `timestamp` - Deposit timestamp
+pub fn set_agent_staked_at(env: &Env, agent: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStakedAt(agent.clone()), &timestamp);
+}
+
+/// Retrieves the remittance amount above which agents must be staked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Stake threshold (0 when the requirement is disabled)
+pub fn get_stake_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StakeThreshold)
+        .unwrap_or(0)
+}
+
+/// Sets the remittance amount above which agents must be staked.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `threshold` - Stake threshold (0 disables the requirement)
+pub fn set_stake_threshold(env: &Env, threshold: i128) {
+    env.storage().instance().set(&DataKey::StakeThreshold, &threshold);
+}
+
+/// Retrieves how long a stake stays locked after a deposit.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u64` - Cooldown in seconds (`DEFAULT_UNSTAKE_COOLDOWN` if never set)
+pub fn get_unstake_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UnstakeCooldown)
+        .unwrap_or(DEFAULT_UNSTAKE_COOLDOWN)
+}
+
+/// Sets how long a stake stays locked after a deposit.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `seconds` - Cooldown in seconds
+pub fn set_unstake_cooldown(env: &Env, seconds: u64) {
+    env.storage().instance().set(&DataKey::UnstakeCooldown, &seconds);
+}
+
+/// Retrieves the scheduled fee update, if any.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Some((fee_bps, effective_at))` - Pending fee rate and its activation timestamp
+/// * `None` - No fee update scheduled
+pub fn get_scheduled_fee(env: &Env) -> Option<(u32, u64)> {
+    env.storage().instance().get(&DataKey::ScheduledFee)
+}
+
+/// Stores a fee update to take effect at `effective_at`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - Fee rate in basis points to apply
+/// * `effective_at` - Timestamp (seconds since epoch) from which the fee applies
+pub fn set_scheduled_fee(env: &Env, fee_bps: u32, effective_at: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ScheduledFee, &(fee_bps, effective_at));
+}
+
+/// Removes any scheduled fee update.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+pub fn clear_scheduled_fee(env: &Env) {
+    env.storage().instance().remove(&DataKey::ScheduledFee);
+}
+
+/// Retrieves the total amount held in escrow for unsettled remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Outstanding liability (0 if nothing is escrowed)
+pub fn get_outstanding_liability(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OutstandingLiability)
+        .unwrap_or(0)
+}
+
+/// Retrieves the minimum balance admin withdrawals must leave above outstanding liability.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Liquidity floor (0 when only liability must be covered)
+pub fn get_liquidity_floor(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LiquidityFloor)
+        .unwrap_or(0)
+}
+
+/// Sets the minimum balance admin withdrawals must leave above outstanding liability.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `floor` - Liquidity floor (0 to require only liability coverage)
+pub fn set_liquidity_floor(env: &Env, floor: i128) {
+    env.storage().instance().set(&DataKey::LiquidityFloor, &floor);
+}
+
+/// Increases the outstanding liability when funds enter escrow.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Amount escrowed
+///
+/// # Returns
+///
+/// * `Ok(())` - Liability updated
+/// * `Err(ContractError::Overflow)` - Liability would overflow
+pub fn add_outstanding_liability(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let liability = checked_add_i128(get_outstanding_liability(env), amount)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::OutstandingLiability, &liability);
+    Ok(())
+}
+
+/// Decreases the outstanding liability when escrowed funds are released.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - Amount released from escrow
+///
+/// # Returns
+///
+/// * `Ok(())` - Liability updated
+/// * `Err(ContractError::Overflow)` - Liability would underflow
+pub fn sub_outstanding_liability(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let liability = get_outstanding_liability(env)
+        .checked_sub(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::OutstandingLiability, &liability);
+    Ok(())
+}
+
+/// Retrieves the recorded yield awaiting sweep.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Yield pool balance (0 if none recorded)
+pub fn get_yield_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::YieldPool)
+        .unwrap_or(0)
+}
+
+/// Sets the recorded yield awaiting sweep.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `amount` - New yield pool balance
+pub fn set_yield_pool(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::YieldPool, &amount);
+}
+
+/// Checks if a settlement hash exists for duplicate detection.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to check
+///
+/// # Returns
+///
+/// * `true` - Settlement has been executed
+/// * `false` - Settlement has not been executed
+pub fn has_settlement_hash(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::SettlementHash(remittance_id))
+}
+
+/// Marks a settlement as executed for duplicate prevention.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to mark as settled
+pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementHash(remittance_id), &true);
+}
+
+/// Retrieves the settlement receipt recorded for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to look up
+///
+/// # Returns
+///
+/// * `Some(BytesN<32>)` - Receipt hash written at settlement
+/// * `None` - Remittance has not been settled
+pub fn get_settlement_receipt(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SettlementReceipt(remittance_id))
+}
+
+/// Stores the settlement receipt for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Settled remittance ID
+/// * `receipt` - Receipt hash computed at settlement
+pub fn set_settlement_receipt(env: &Env, remittance_id: u64, receipt: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementReceipt(remittance_id), receipt);
+}
+
+/// Retrieves the claim code hash for a claimable remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to look up
+///
+/// # Returns
+///
+/// * `Some(BytesN<32>)` - SHA-256 of the claim code
+/// * `None` - Remittance is not claimable
+pub fn get_claim_code_hash(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimCodeHash(remittance_id))
+}
+
+/// Locks a remittance to a claim code hash.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Claimable remittance ID
+/// * `code_hash` - SHA-256 of the claim code
+pub fn set_claim_code_hash(env: &Env, remittance_id: u64, code_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ClaimCodeHash(remittance_id), code_hash);
+}
+
+/// Retrieves the Ed25519 public key registered for an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `Some(BytesN<32>)` - Registered public key
+/// * `None` - Agent has not registered a key
+pub fn get_agent_public_key(env: &Env, agent: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPublicKey(agent.clone()))
+}
+
+/// Stores the Ed25519 public key for an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `public_key` - Ed25519 public key
+pub fn set_agent_public_key(env: &Env, agent: &Address, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPublicKey(agent.clone()), public_key);
+}
+
+/// Checks whether an address is an operator of an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `operator` - Candidate operator address
+///
+/// # Returns
+///
+/// * `true` - `operator` may confirm payouts for `agent`
+/// * `false` - Not an operator of `agent`
+pub fn is_agent_operator(env: &Env, agent: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentOperator(agent.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds or removes an operator for an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `operator` - Operator address
+/// * `enabled` - Whether `operator` may confirm payouts for `agent`
+pub fn set_agent_operator(env: &Env, agent: &Address, operator: &Address, enabled: bool) {
+    let key = DataKey::AgentOperator(agent.clone(), operator.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves an agent's display label.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+///
+/// # Returns
+///
+/// * `Option<String>` - The label, or None if none is set
+pub fn get_agent_label(env: &Env, agent: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentLabel(agent.clone()))
+}
+
+/// Sets an agent's display label.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `label` - Label to store
+pub fn set_agent_label(env: &Env, agent: &Address, label: &String) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentLabel(agent.clone()), label);
+}
+
+/// Checks whether the sender has requested a refund for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+///
+/// # Returns
+///
+/// * `true` - A refund request is open
+/// * `false` - No refund has been requested
+pub fn is_refund_requested(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundRequested(remittance_id))
+        .unwrap_or(false)
+}
+
+/// Opens or clears a refund request for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+/// * `requested` - Whether a refund request is open
+pub fn set_refund_requested(env: &Env, remittance_id: u64, requested: bool) {
+    let key = DataKey::RefundRequested(remittance_id);
+    if requested {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves the oracle that resolves conditional remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Option<Address>` - Oracle address, or `None` if unset
+pub fn get_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Oracle)
+}
+
+/// Sets the oracle that resolves conditional remittances.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `oracle` - Oracle address
+pub fn set_oracle(env: &Env, oracle: &Address) {
+    env.storage().instance().set(&DataKey::Oracle, oracle);
+}
+
+/// Checks whether a conditional remittance's condition has been met.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+///
+/// # Returns
+///
+/// * `true` - The oracle has marked the condition met
+/// * `false` - The condition is unmet or was never set
+pub fn is_condition_met(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConditionMet(remittance_id))
+        .unwrap_or(false)
+}
+
+/// Marks a conditional remittance's condition as met or unmet.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID
+/// * `met` - Whether the condition is met
+pub fn set_condition_met(env: &Env, remittance_id: u64, met: bool) {
+    let key = DataKey::ConditionMet(remittance_id);
+    if met {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks whether an agent's payout authorization nonce has been used.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `nonce` - Nonce to check
+///
+/// # Returns
+///
+/// * `true` - Nonce has already been consumed
+/// * `false` - Nonce is unused
+pub fn is_agent_nonce_used(env: &Env, agent: &Address, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AgentNonceUsed(agent.clone(), nonce))
+}
+
+/// Marks an agent's payout authorization nonce as consumed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `nonce` - Nonce to consume
+pub fn set_agent_nonce_used(env: &Env, agent: &Address, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentNonceUsed(agent.clone(), nonce), &true);
+}
+
+/// Checks whether new remittances may be created.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `true` - New remittances are accepted (default)
+/// * `false` - The contract is winding down
+pub fn is_accepting_new(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AcceptingNew)
+        .unwrap_or(true)
+}
+
+/// Sets whether new remittances may be created.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `accepting` - False to stop new business while existing remittances settle
+pub fn set_accepting_new(env: &Env, accepting: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AcceptingNew, &accepting);
+}
+
+/// Checks whether the sender allowlist is enforced.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `true` - Only allowlisted senders may create remittances
+/// * `false` - Anyone may create remittances (default)
+pub fn is_sender_allowlist_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::SenderAllowlistEnabled)
+        .unwrap_or(false)
+}
+
+/// Sets whether the sender allowlist is enforced.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `enabled` - True to restrict creation to allowlisted senders
+pub fn set_sender_allowlist_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SenderAllowlistEnabled, &enabled);
+}
+
+/// Checks if a sender is on the allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender address to check
+///
+/// # Returns
+///
+/// * `true` - Sender is allowlisted
+/// * `false` - Sender is not allowlisted
+pub fn is_sender_allowlisted(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderAllowed(sender.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds a sender to or removes it from the allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender address
+/// * `allowed` - Whether the sender is allowlisted
+pub fn set_sender_allowlisted(env: &Env, sender: &Address, allowed: bool) {
+    let key = DataKey::SenderAllowed(sender.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks if any operation is currently paused.
+pub fn is_paused(env: &Env) -> bool {
+    let flags = get_pause_flags(env);
+    flags.create || flags.confirm || flags.cancel || flags.withdraw
+}
+
+/// Sets or clears every pause flag at once.
+pub fn set_paused(env: &Env, paused: bool) {
+    set_pause_flags(
+        env,
+        &PauseFlags {
+            create: paused,
+            confirm: paused,
+            cancel: paused,
+            withdraw: paused,
+        },
+    );
+}
+
+/// Retrieves the per-operation pause flags.
+///
+/// Falls back to the legacy blanket pause status when no flags have been
+/// stored yet.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `PauseFlags` - Current pause switches
+pub fn get_pause_flags(env: &Env) -> PauseFlags {
+    if let Some(flags) = env.storage().instance().get(&DataKey::PauseFlags) {
+        return flags;
+    }
+
+    let paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false);
+    PauseFlags {
+        create: paused,
+        confirm: paused,
+        cancel: paused,
+        withdraw: paused,
+    }
+}
+
+/// Stores the per-operation pause flags.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `flags` - Pause switches to store
+pub fn set_pause_flags(env: &Env, flags: &PauseFlags) {
+    env.storage().instance().set(&DataKey::PauseFlags, flags);
+}
+
+/// Retrieves the volume threshold that triggers an automatic pause.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `i128` - Threshold amount (0 when the circuit breaker is disabled)
+pub fn get_auto_pause_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AutoPauseThreshold)
+        .unwrap_or(0)
+}
+
+/// Sets the volume threshold that triggers an automatic pause.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `threshold` - Threshold amount (0 disables the circuit breaker)
+pub fn set_auto_pause_threshold(env: &Env, threshold: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AutoPauseThreshold, &threshold);
+}
+
+pub fn set_rate_limit_cooldown(env: &Env, cooldown_seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RateLimitCooldown, &cooldown_seconds);
+}
+
+pub fn get_rate_limit_cooldown(env: &Env) -> Result<u64, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RateLimitCooldown)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn set_last_settlement_time(env: &Env, sender: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastSettlementTime(sender.clone()), &timestamp);
+}
+
+pub fn get_last_settlement_time(env: &Env, sender: &Address) -> Option<u64> {
     env.storage()
         .persistent()
         .get(&DataKey::LastSettlementTime(sender.clone()))
@@ -432,6 +2664,70 @@ pub fn set_user_transfers(env: &Env, user: &Address, transfers: &Vec<TransferRec
         .set(&DataKey::UserTransfers(user.clone()), transfers);
 }
 
+/// Appends a transfer to a sender's history for rolling limit checks.
+///
+/// Records are only kept while rolling limits are configured, and entries
+/// older than the longest configured window are pruned on each write.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `user` - Sender whose history is updated
+/// * `amount` - Amount of the new transfer
+pub fn record_user_transfer(env: &Env, user: &Address, amount: i128) {
+    let limits = get_rolling_limits(env);
+    if limits.is_empty() {
+        return;
+    }
+
+    let mut longest_window: u64 = 0;
+    for limit in limits.iter() {
+        longest_window = longest_window.max((limit.window_days as u64) * 86400);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut kept = Vec::new(env);
+    for record in get_user_transfers(env, user).iter() {
+        if now.saturating_sub(record.timestamp) < longest_window {
+            kept.push_back(record);
+        }
+    }
+    kept.push_back(TransferRecord {
+        timestamp: now,
+        amount,
+    });
+
+    set_user_transfers(env, user, &kept);
+}
+
+/// Retrieves all configured rolling window limits.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<RollingLimit>` - Configured limits (empty if none)
+pub fn get_rolling_limits(env: &Env) -> Vec<RollingLimit> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RollingLimits)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the full set of rolling window limits.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `limits` - Limits to store, at most one per window length
+pub fn set_rolling_limits(env: &Env, limits: &Vec<RollingLimit>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RollingLimits, limits);
+}
+
 // === Admin Role Management ===
 
 pub fn is_admin(env: &Env, address: &Address) -> bool {
@@ -478,11 +2774,73 @@ pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
 }
 
 pub fn set_token_whitelisted(env: &Env, token: &Address, whitelisted: bool) {
+    if is_token_whitelisted(env, token) != whitelisted {
+        let mut tokens = get_supported_tokens(env);
+        if whitelisted {
+            if !tokens.contains(token) {
+                tokens.push_back(token.clone());
+            }
+        } else if let Some(index) = tokens.first_index_of(token) {
+            tokens.remove(index);
+        }
+        env.storage().persistent().set(&DataKey::SupportedTokens, &tokens);
+    }
+
     env.storage()
         .persistent()
         .set(&DataKey::TokenWhitelisted(token.clone()), &whitelisted);
 }
 
+/// Retrieves all whitelisted tokens in whitelisting order.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Vec<Address>` - Whitelisted tokens, empty if none have been whitelisted
+pub fn get_supported_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SupportedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Checks whether a token is on the denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token contract address
+///
+/// # Returns
+///
+/// * `true` - Token is denied
+/// * `false` - Token is not denied
+pub fn is_token_denied(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenDenied(token.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds a token to or removes it from the denylist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token contract address
+/// * `denied` - Whether the token is denied
+pub fn set_token_denied(env: &Env, token: &Address, denied: bool) {
+    let key = DataKey::TokenDenied(token.clone());
+    if denied {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 // === Settlement Event Emission Tracking ===
 
 /// Checks if the settlement completion event has been emitted for a remittance.
@@ -528,6 +2886,198 @@ pub fn set_settlement_event_emitted(env: &Env, remittance_id: u64) {
         .set(&DataKey::SettlementEventEmitted(remittance_id), &true);
 }
 
+// === Agent Liquidity Pools ===
+
+/// Retrieves an agent's pre-funded pool balance for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent owning the pool
+/// * `token` - Token held in the pool
+///
+/// # Returns
+///
+/// * `i128` - Pool balance (defaults to 0 if never funded)
+pub fn get_agent_pool(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPool(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets an agent's pool balance for a token.
+///
+/// Keeps the token's pool total in step with the change.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the agent owning the pool
+/// * `token` - Token held in the pool
+/// * `amount` - New pool balance
+pub fn set_agent_pool(env: &Env, agent: &Address, token: &Address, amount: i128) {
+    let total = get_agent_pool_total(env, token) - get_agent_pool(env, agent, token) + amount;
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPoolTotal(token.clone()), &total);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPool(agent.clone(), token.clone()), &amount);
+}
+
+/// Retrieves the sum of all agent pool balances held in a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token held in the pools
+///
+/// # Returns
+///
+/// * `i128` - Total pooled amount (0 if no pool holds the token)
+pub fn get_agent_pool_total(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPoolTotal(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Retrieves the USDC-to-token conversion rate used for pool settlements.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Pool token the rate converts into
+///
+/// # Returns
+///
+/// * `Some(u32)` - Rate in basis points (10000 = 1:1)
+/// * `None` - No rate configured for this token
+pub fn get_fx_rate(env: &Env, token: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FxRate(token.clone()))
+}
+
+/// Sets the USDC-to-token conversion rate used for pool settlements.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Pool token the rate converts into
+/// * `rate_bps` - Rate in basis points (10000 = 1:1)
+pub fn set_fx_rate(env: &Env, token: &Address, rate_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FxRate(token.clone()), &rate_bps);
+}
+
+/// Retrieves the conversion rate for a quote currency.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `currency` - Quote currency code (e.g. "MXN")
+///
+/// # Returns
+///
+/// * `Some(u32)` - Quote currency units per USDC unit, in basis points (10000 = 1:1)
+/// * `None` - Currency has no configured rate
+pub fn get_quote_rate(env: &Env, currency: &String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QuoteRate(currency.clone()))
+}
+
+/// Sets the conversion rate for a quote currency.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `currency` - Quote currency code (e.g. "MXN")
+/// * `rate_bps` - Quote currency units per USDC unit, in basis points (10000 = 1:1)
+pub fn set_quote_rate(env: &Env, currency: &String, rate_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::QuoteRate(currency.clone()), &rate_bps);
+}
+
+// === Storage TTL Tracking ===
+
+/// Extends instance storage (admin and configuration) to `TTL_EXTEND_TO` ledgers.
+///
+/// Records the resulting live-until ledger so it can be reported without a
+/// host TTL query, which contracts cannot perform.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_EXTEND_TO, TTL_EXTEND_TO);
+    let live_until = env.ledger().sequence().saturating_add(TTL_EXTEND_TO);
+    env.storage()
+        .instance()
+        .set(&DataKey::InstanceLiveUntil, &live_until);
+}
+
+/// Extends a remittance record to `TTL_EXTEND_TO` ledgers and records its live-until ledger.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - ID of the remittance record to extend
+pub fn extend_remittance_ttl(env: &Env, id: u64) {
+    let live_until = env.ledger().sequence().saturating_add(TTL_EXTEND_TO);
+    let live_key = DataKey::RemittanceLiveUntil(id);
+    env.storage().persistent().set(&live_key, &live_until);
+    env.storage()
+        .persistent()
+        .extend_ttl(&live_key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Remittance(id), TTL_EXTEND_TO, TTL_EXTEND_TO);
+}
+
+/// Returns the remaining TTL of instance storage in ledgers.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `u32` - Ledgers until instance storage is archived (0 if never extended)
+pub fn get_instance_ttl(env: &Env) -> u32 {
+    let live_until: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::InstanceLiveUntil)
+        .unwrap_or(0);
+    live_until.saturating_sub(env.ledger().sequence())
+}
+
+/// Returns the remaining TTL of a remittance record in ledgers.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - ID of the remittance record
+///
+/// # Returns
+///
+/// * `u32` - Ledgers until the record is archived (0 if never extended)
+pub fn get_remittance_ttl(env: &Env, id: u64) -> u32 {
+    let live_until: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RemittanceLiveUntil(id))
+        .unwrap_or(0);
+    live_until.saturating_sub(env.ledger().sequence())
+}
+
 
 // === Settlement Counter ===
 
@@ -563,6 +3113,21 @@ pub fn get_settlement_counter(env: &Env) -> u64 {
         .unwrap_or(0)
 }
 
+/// Sets the settlement counter directly.
+///
+/// Only used when seeding a fresh deployment from an exported configuration;
+/// normal settlement flows must go through `increment_settlement_counter`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `count` - Settlement count to store
+pub fn set_settlement_counter(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementCounter, &count);
+}
+
 /// Increments the settlement counter atomically.
 ///
 /// This function should only be called after a settlement is successfully finalized
@@ -577,7 +3142,7 @@ pub fn get_settlement_counter(env: &Env) -> u64 {
 /// # Returns
 ///
 /// * `Ok(())` - Counter incremented successfully
-/// * `Err(ContractError::SettlementCounterOverflow)` - Counter would overflow u64::MAX
+/// * `Err(ContractError::Overflow)` - Counter would overflow u64::MAX
 
 /// # Panics
 ///
@@ -595,7 +3160,7 @@ pub fn increment_settlement_counter(env: &Env) -> Result<(), ContractError> {
     let current = get_settlement_counter(env);
     let new_count = current
         .checked_add(1)
-        .ok_or(ContractError::SettlementCounterOverflow)?;
+        .ok_or(ContractError::Overflow)?;
     env.storage()
         .instance()
         .set(&DataKey::SettlementCounter, &new_count);
@@ -259,7 +259,7 @@ fn test_confirm_payout() {
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Settled);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     assert_eq!(get_token_balance(&token, &agent), 975);
     assert_eq!(contract.get_accumulated_fees(), 25);
@@ -315,10 +315,12 @@ fn test_cancel_remittance() {
     contract.cancel_remittance(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
 
     assert_eq!(get_token_balance(&token, &sender), 10000);
     assert_eq!(get_token_balance(&token, &contract.address), 0);
+    // Fees are only earned on completed settlements
+    assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
 #[test]
@@ -391,7 +393,10 @@ fn test_cancel_remittance_full_refund() {
 
     // Verify remittance status is Cancelled
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Cancelled);
+
+    // Fees are only earned on completed settlements
+    assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
 #[test]
@@ -563,9 +568,12 @@ fn test_cancel_remittance_multiple_remittances() {
     let r2 = contract.get_remittance(&remittance_id2);
     let r3 = contract.get_remittance(&remittance_id3);
 
-    assert_eq!(r1.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r1.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(r2.status, crate::types::RemittanceStatus::Pending);
-    assert_eq!(r3.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(r3.status, crate::types::RemittanceStatus::Cancelled);
+
+    // Fees are only earned on completed settlements
+    assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
 #[test]
@@ -631,7 +639,7 @@ fn test_cancel_remittance_preserves_remittance_data() {
     assert_eq!(cancelled.amount, original.amount);
     assert_eq!(cancelled.fee, original.fee);
     assert_eq!(cancelled.expiry, original.expiry);
-    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Failed);
+    assert_eq!(cancelled.status, crate::types::RemittanceStatus::Cancelled);
     assert_eq!(original.status, crate::types::RemittanceStatus::Pending);
 }
 
@@ -849,6 +857,35 @@ fn test_withdraw_fees_valid_address() {
     assert_eq!(contract.get_accumulated_fees(), 0);
 }
 
+#[test]
+fn test_contract_address_rejected_by_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    // Fees sent to the contract would never leave it
+    let result = contract.try_withdraw_fees(&contract.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidRecipientAddress));
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    // The contract cannot act as the agent of an ordinary remittance
+    let result = contract.try_create_remittance(&sender, &contract.address, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAddress));
+}
+
 #[test]
 fn test_confirm_payout_valid_address() {
     let env = Env::default();
@@ -1189,7 +1226,7 @@ fn test_duplicate_prevention_with_expiry() {
     contract.confirm_payout(&remittance_id);
 
     let remittance = contract.get_remittance(&remittance_id);
-    assert_eq!(remittance.status, crate::types::RemittanceStatus::Settled);
+    assert_eq!(remittance.status, crate::types::RemittanceStatus::Completed);
 
     // Even with valid expiry, duplicate should be prevented
     // (This would require manual status manipulation to test, covered by test_duplicate_settlement_prevention)
@@ -1301,7 +1338,7 @@ fn test_get_settlement_valid() {
 }
 
 #[test]
-#[should_panic(expected = "RemittanceNotFound")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_get_settlement_invalid_id() {
     let env = Env::default();
     env.mock_all_auths();
@@ -4636,9 +4673,6 @@ fn test_error_handler_all_errors_have_unique_codes() {
         crate::ContractError::DuplicateSettlement,
         crate::ContractError::ContractPaused,
         crate::ContractError::Unauthorized,
-        crate::ContractError::AdminAlreadyExists,
-        crate::ContractError::AdminNotFound,
-        crate::ContractError::CannotRemoveLastAdmin,
         crate::ContractError::TokenNotWhitelisted,
         crate::ContractError::TokenAlreadyWhitelisted,
     ];
@@ -4649,7 +4683,7 @@ fn test_error_handler_all_errors_have_unique_codes() {
         assert!(codes.insert(response.code), "Duplicate error code found: {}", response.code);
     }
     
-    assert_eq!(codes.len(), 19, "Expected 19 unique error codes");
+    assert_eq!(codes.len(), 16, "Expected 16 unique error codes");
 }
 
 #[test]
@@ -4770,7 +4804,7 @@ fn test_error_handler_no_information_leakage() {
     // Test that error messages don't leak sensitive information
     let errors = vec![
         crate::ContractError::RemittanceNotFound,
-        crate::ContractError::AdminNotFound,
+        crate::ContractError::TokenNotWhitelisted,
         crate::ContractError::AgentNotRegistered,
     ];
     
@@ -4801,6 +4835,8 @@ fn test_error_handler_consistency_across_categories() {
         crate::ContractError::InvalidAmount,
         crate::ContractError::InvalidFeeBps,
         crate::ContractError::InvalidAddress,
+        crate::ContractError::InvalidRecipientAddress,
+        crate::ContractError::FeeAboveCap,
     ];
     
     for error in validation_errors {
@@ -4834,9 +4870,6 @@ fn test_error_handler_high_severity_errors() {
         crate::ContractError::DuplicateSettlement,
         crate::ContractError::ContractPaused,
         crate::ContractError::Unauthorized,
-        crate::ContractError::AdminAlreadyExists,
-        crate::ContractError::AdminNotFound,
-        crate::ContractError::CannotRemoveLastAdmin,
         crate::ContractError::TokenNotWhitelisted,
         crate::ContractError::TokenAlreadyWhitelisted,
     ];
@@ -5216,7 +5249,7 @@ fn test_settlement_completion_event_after_state_commit() {
     // Verify state was committed before event emission
     let remittance = contract.get_remittance(&id);
     assert!(remittance.is_ok());
-    assert_eq!(remittance.unwrap().status, crate::RemittanceStatus::Settled);
+    assert_eq!(remittance.unwrap().status, crate::RemittanceStatus::Completed);
 
     // Verify event was emitted
     let events = env.events().all();
@@ -5682,3 +5715,6505 @@ fn test_settlement_counter_preserves_storage_integrity() {
     assert_eq!(contract.get_total_settlements_count(), 5);
 }
 
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Config Snapshot Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_export_import_config_round_trip() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &3600);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+    contract.create_remittance(&sender, &agent, &500, &None);
+    contract.pause();
+
+    let snapshot = contract.export_config();
+
+    // Seed a fresh deployment from the old one
+    let new_contract = create_swiftremit_contract(&env);
+    new_contract.import_config(&snapshot);
+
+    let imported = new_contract.export_config();
+    assert_eq!(imported, snapshot);
+    assert_eq!(imported.admin, admin);
+    assert_eq!(imported.usdc_token, token.address);
+    assert_eq!(imported.platform_fee_bps, 250);
+    assert!(imported.paused);
+    assert_eq!(imported.remittance_counter, 2);
+    assert_eq!(imported.settlement_counter, 1);
+    assert_eq!(imported.accumulated_fees, 25);
+    assert_eq!(imported.rate_limit_cooldown, 3600);
+
+    assert!(new_contract.is_paused());
+    assert_eq!(new_contract.get_platform_fee_bps(), 250);
+    assert_eq!(new_contract.get_total_settlements_count(), 1);
+    assert_eq!(new_contract.get_token_decimals(), contract.get_token_decimals());
+    assert_eq!(new_contract.get_schema_version(), crate::STORAGE_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_import_config_rejects_token_as_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let mut snapshot = contract.export_config();
+    snapshot.admin = token.address.clone();
+
+    let new_contract = create_swiftremit_contract(&env);
+    let result = new_contract.try_import_config(&snapshot);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAddress));
+    assert!(!new_contract.is_initialized());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_import_config_already_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let snapshot = contract.export_config();
+    contract.import_config(&snapshot);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Acceptance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_accept_then_confirm_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.accept_remittance(&id);
+
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::types::RemittanceStatus::Accepted
+    );
+
+    contract.confirm_payout(&id);
+
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::types::RemittanceStatus::Completed
+    );
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_sender_cannot_cancel_accepted_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.accept_remittance(&id);
+
+    contract.cancel_remittance(&id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_accept_remittance_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.accept_remittance(&id);
+    contract.accept_remittance(&id);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Liquidity Pool Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_confirm_payout_from_pool_pays_local_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let local = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.whitelist_token(&admin, &local.address);
+    // 1 USDC unit pays out 1.5 local units
+    contract.set_fx_rate(&admin, &local.address, &15000);
+
+    token.mint(&sender, &10000);
+    local.mint(&agent, &5000);
+
+    contract.fund_agent_pool(&agent, &local.address, &2000);
+    assert_eq!(contract.get_agent_pool(&agent, &local.address), 2000);
+    assert_eq!(get_token_balance(&local, &agent), 3000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout_from_pool(&id, &local.address, &recipient);
+
+    // 975 USDC payout converted at 1.5x
+    assert_eq!(get_token_balance(&local, &recipient), 1462);
+    assert_eq!(contract.get_agent_pool(&agent, &local.address), 538);
+    // Agent is reimbursed in USDC, platform fee accrues as usual
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::types::RemittanceStatus::Completed
+    );
+
+    let result = contract.try_withdraw_agent_pool(&agent, &local.address, &539);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientPoolLiquidity));
+
+    contract.withdraw_agent_pool(&agent, &local.address, &538);
+    assert_eq!(contract.get_agent_pool(&agent, &local.address), 0);
+    assert_eq!(get_token_balance(&local, &agent), 3538);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+fn test_confirm_payout_from_pool_insufficient_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let local = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.whitelist_token(&admin, &local.address);
+    contract.set_fx_rate(&admin, &local.address, &10000);
+
+    token.mint(&sender, &10000);
+    local.mint(&agent, &500);
+    contract.fund_agent_pool(&agent, &local.address, &500);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout_from_pool(&id, &local.address, &recipient);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+fn test_confirm_payout_from_pool_requires_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let local = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.whitelist_token(&admin, &local.address);
+
+    token.mint(&sender, &10000);
+    local.mint(&agent, &5000);
+    contract.fund_agent_pool(&agent, &local.address, &5000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout_from_pool(&id, &local.address, &recipient);
+}
+
+#[test]
+fn test_confirm_payout_from_pool_records_settlement_like_confirm_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let local = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.whitelist_token(&admin, &local.address);
+    contract.set_fx_rate(&admin, &local.address, &10000);
+
+    token.mint(&sender, &10000);
+    local.mint(&agent, &5000);
+    contract.fund_agent_pool(&agent, &local.address, &5000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let deadline = env.ledger().timestamp() + 3600;
+    contract.set_settle_by(&id, &deadline);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+
+    contract.confirm_payout_from_pool(&id, &local.address, &recipient);
+
+    assert!(contract.get_remittance(&id).late);
+    assert_eq!(contract.get_agent_settlement_nonce(&agent), 1);
+    assert_eq!(contract.get_agent_stats(&agent).completed, 1);
+    assert!(contract.get_settlement_receipt(&id).is_some());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Storage TTL Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_ttl_status_decreases_until_bumped() {
+    use soroban_sdk::testutils::storage::Instance as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let status = contract.get_ttl_status();
+    assert_eq!(status.instance_ttl, crate::TTL_EXTEND_TO);
+    assert_eq!(status.min_remittance_ttl, crate::TTL_EXTEND_TO);
+    assert_eq!(status.remittances_sampled, 1);
+    assert_eq!(status.min_ttl, crate::TTL_EXTEND_TO);
+
+    // Reported TTL must agree with the host's view of instance storage
+    let host_ttl = env.as_contract(&contract.address, || env.storage().instance().get_ttl());
+    assert_eq!(host_ttl, status.instance_ttl);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1000);
+
+    let aged = contract.get_ttl_status();
+    assert_eq!(aged.instance_ttl, crate::TTL_EXTEND_TO - 1000);
+    assert_eq!(aged.min_ttl, crate::TTL_EXTEND_TO - 1000);
+    assert!(aged.min_ttl < status.min_ttl);
+
+    let host_ttl = env.as_contract(&contract.address, || env.storage().instance().get_ttl());
+    assert_eq!(host_ttl, aged.instance_ttl);
+
+    contract.bump_ttl();
+
+    let bumped = contract.get_ttl_status();
+    assert_eq!(bumped.instance_ttl, crate::TTL_EXTEND_TO);
+    assert_eq!(bumped.min_ttl, crate::TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_ttl_status_ignores_settled_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    let status = contract.get_ttl_status();
+    assert_eq!(status.remittances_sampled, 0);
+    assert_eq!(status.min_remittance_ttl, u32::MAX);
+    assert_eq!(status.min_ttl, status.instance_ttl);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Expired Reclaim Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_reclaim_expired_refunds_full_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+    contract.accept_remittance(&id);
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+
+    contract.reclaim_expired(&id);
+
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
+    // Fees are only earned on completed settlements
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::types::RemittanceStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_reclaim_expired_leaves_earned_fees_unchanged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let settled = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&settled);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    let expiry = env.ledger().timestamp() + 60;
+    let id = contract.create_remittance(&sender, &agent, &2000, &Some(expiry));
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    contract.reclaim_expired(&id);
+
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+}
+
+#[test]
+fn test_reclaim_expired_closes_refund_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 60;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+    contract.request_refund(&id);
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    contract.reclaim_expired(&id);
+
+    assert!(!contract.is_refund_requested(&id));
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+    let result = contract.try_approve_refund(&agent, &id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoRefundRequested));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+fn test_reclaim_before_expiry_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+    contract.reclaim_expired(&id);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batch Eligibility Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_batch_settle_with_eligible_agents() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent1);
+    contract.register_agent(&agent2);
+    contract.set_agent_batch_eligible(&agent1, &true);
+    contract.set_agent_batch_eligible(&agent2, &true);
+    assert!(contract.is_agent_batch_eligible(&agent1));
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent1, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent2, &2000, &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    let result = contract.batch_settle_with_netting(&entries);
+    assert_eq!(result.settled_ids.len(), 2);
+    assert_eq!(get_token_balance(&token, &agent1), 975);
+    assert_eq!(get_token_balance(&token, &agent2), 1950);
+
+    // Each receipt matches its remittance's payout and fee
+    assert_eq!(result.receipts.len(), 2);
+    for (i, id) in [id1, id2].iter().enumerate() {
+        let receipt = result.receipts.get(i as u32).unwrap();
+        let remittance = contract.get_remittance(id);
+        assert_eq!(receipt.remittance_id, *id);
+        assert_eq!(receipt.fee, remittance.fee);
+        assert_eq!(receipt.payout_amount, remittance.amount - remittance.fee);
+    }
+}
+
+#[test]
+fn test_batch_settle_rejects_non_eligible_agent_atomically() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let vetted = Address::generate(&env);
+    let unvetted = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&vetted);
+    contract.register_agent(&unvetted);
+    contract.set_agent_batch_eligible(&vetted, &true);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &vetted, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &unvetted, &2000, &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(crate::ContractError::AgentNotBatchEligible)
+    );
+
+    // Nothing in the batch was settled
+    assert_eq!(
+        contract.get_remittance(&id1).status,
+        crate::types::RemittanceStatus::Pending
+    );
+    assert_eq!(get_token_balance(&token, &vetted), 0);
+    assert_eq!(get_token_balance(&token, &contract.address), 3000);
+
+    // Single settlement is unaffected by batch eligibility
+    contract.confirm_payout(&id2);
+    assert_eq!(get_token_balance(&token, &unvetted), 1950);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Bulk Agent Registration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_register_and_remove_agents_in_bulk() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let mut agents = soroban_sdk::Vec::new(&env);
+    for _ in 0..5 {
+        agents.push_back(Address::generate(&env));
+    }
+
+    contract.register_agents(&agents);
+
+    for agent in agents.iter() {
+        assert!(contract.is_agent_registered(&agent));
+    }
+
+    let mut removed = soroban_sdk::Vec::new(&env);
+    removed.push_back(agents.get(0).unwrap());
+    removed.push_back(agents.get(1).unwrap());
+    contract.remove_agents(&removed);
+
+    assert!(!contract.is_agent_registered(&agents.get(0).unwrap()));
+    assert!(!contract.is_agent_registered(&agents.get(1).unwrap()));
+    assert!(contract.is_agent_registered(&agents.get(2).unwrap()));
+}
+
+#[test]
+fn test_register_agents_deduplicates_input() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let mut agents = soroban_sdk::Vec::new(&env);
+    agents.push_back(agent.clone());
+    agents.push_back(other.clone());
+    agents.push_back(agent.clone());
+
+    contract.register_agents(&agents);
+
+    // One registration event per distinct agent
+    let registrations = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            *topics
+                == (symbol_short!("agent"), symbol_short!("register")).into_val(&env)
+        })
+        .count();
+    assert_eq!(registrations, 2);
+
+    assert!(contract.is_agent_registered(&agent));
+    assert!(contract.is_agent_registered(&other));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_register_agents_rejects_oversized_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let mut agents = soroban_sdk::Vec::new(&env);
+    for _ in 0..(crate::MAX_BATCH_SIZE + 1) {
+        agents.push_back(Address::generate(&env));
+    }
+
+    contract.register_agents(&agents);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Settlement Flag Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_is_settled_tracks_settlement_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert!(!contract.is_settled(&id));
+
+    contract.confirm_payout(&id);
+    assert!(contract.is_settled(&id));
+
+    // Unknown IDs are never settled
+    assert!(!contract.is_settled(&999));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Rounding Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fee_rounding_modes_on_create() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    // Default preserves the original truncating behavior
+    assert_eq!(contract.get_rounding_mode(), crate::RoundingMode::Down);
+
+    // 1020 * 250 / 10000 = 25.5
+    let down = contract.create_remittance(&sender, &agent, &1020, &None);
+    assert_eq!(contract.get_remittance(&down).fee, 25);
+
+    contract.set_rounding_mode(&crate::RoundingMode::Up);
+    assert_eq!(contract.get_rounding_mode(), crate::RoundingMode::Up);
+    // 1001 * 250 / 10000 = 25.025
+    let up = contract.create_remittance(&sender, &agent, &1001, &None);
+    assert_eq!(contract.get_remittance(&up).fee, 26);
+
+    contract.set_rounding_mode(&crate::RoundingMode::HalfUp);
+    let half_up = contract.create_remittance(&sender, &agent, &1020, &None);
+    assert_eq!(contract.get_remittance(&half_up).fee, 26);
+    let half_down = contract.create_remittance(&sender, &agent, &1001, &None);
+    assert_eq!(contract.get_remittance(&half_down).fee, 25);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Expiry Extension Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_extend_expiry_allows_later_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+
+    contract.extend_expiry(&id, &(expiry + 3600));
+    assert_eq!(contract.get_remittance(&id).expiry, Some(expiry + 3600));
+
+    // Past the original deadline but within the extended one
+    env.ledger().with_mut(|li| li.timestamp = expiry + 60);
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+fn test_extend_expiry_rejects_earlier_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+
+    contract.extend_expiry(&id, &(expiry - 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_extend_expiry_rejects_completed_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+    contract.confirm_payout(&id);
+
+    contract.extend_expiry(&id, &(expiry + 3600));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Settlement Lookup Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_settlement_exists_and_not_found_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert!(!contract.settlement_exists(&1));
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert!(contract.settlement_exists(&id));
+    assert_eq!(contract.get_settlement(&id).id, id);
+
+    // Missing IDs surface the distinct not-found error code
+    assert!(!contract.settlement_exists(&999));
+    assert_eq!(
+        contract.try_get_settlement(&999).unwrap_err(),
+        Ok(crate::ContractError::RemittanceNotFound)
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Rolling Limit Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rolling_limit_enforced_and_ages_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    contract.set_rolling_limit(&7, &1000);
+    contract.set_rolling_limit(&30, &5000);
+    assert_eq!(contract.get_rolling_limits().len(), 2);
+
+    contract.create_remittance(&sender, &agent, &600, &None);
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    contract.create_remittance(&sender, &agent, &400, &None);
+
+    // 7-day volume is now exactly at the ceiling
+    let result = contract.try_create_remittance(&sender, &agent, &1, &None);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(crate::ContractError::RollingLimitExceeded)
+    );
+
+    // Seven days after the first transfer it no longer counts
+    env.ledger().with_mut(|li| li.timestamp += 6 * 86400);
+    contract.create_remittance(&sender, &agent, &600, &None);
+
+    // The 400 transfer has not aged out yet
+    let result = contract.try_create_remittance(&sender, &agent, &1, &None);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(crate::ContractError::RollingLimitExceeded)
+    );
+}
+
+#[test]
+fn test_rolling_limit_replace_and_remove() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    contract.set_rolling_limit(&7, &500);
+    contract.set_rolling_limit(&7, &2000);
+
+    let limits = contract.get_rolling_limits();
+    assert_eq!(limits.len(), 1);
+    assert_eq!(
+        limits.get(0).unwrap(),
+        crate::RollingLimit { window_days: 7, limit: 2000 }
+    );
+
+    contract.create_remittance(&sender, &agent, &1500, &None);
+
+    contract.set_rolling_limit(&7, &0);
+    assert_eq!(contract.get_rolling_limits().len(), 0);
+    contract.create_remittance(&sender, &agent, &5000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Stats Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_stats_track_settlements_and_expiries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+
+    token.mint(&sender, &100000);
+
+    let empty = contract.get_agent_stats(&agent);
+    assert_eq!(empty.completed, 0);
+    assert_eq!(empty.expired, 0);
+    assert_eq!(empty.total_paid, 0);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+
+    let id3 = contract.create_remittance(&sender, &agent, &4000, &None);
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id3 });
+    contract.batch_settle_with_netting(&entries);
+
+    let expiry = env.ledger().timestamp() + 60;
+    let id4 = contract.create_remittance(&sender, &agent, &500, &Some(expiry));
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    contract.reclaim_expired(&id4);
+
+    let stats = contract.get_agent_stats(&agent);
+    assert_eq!(stats.completed, 3);
+    assert_eq!(stats.expired, 1);
+    assert_eq!(stats.total_paid, 975 + 1950 + 3900);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Granular Pause Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_pause_only_confirmations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let flags = crate::PauseFlags {
+        create: false,
+        confirm: true,
+        cancel: false,
+        withdraw: false,
+    };
+    contract.set_pause_flags(&flags);
+    assert_eq!(contract.get_pause_flags(), flags);
+
+    // Creations still succeed while confirmations are halted
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let result = contract.try_confirm_payout(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::ContractPaused));
+
+    contract.set_pause_flags(&crate::PauseFlags {
+        create: false,
+        confirm: false,
+        cancel: false,
+        withdraw: false,
+    });
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_pause_shortcut_sets_all_flags() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.pause();
+    let flags = contract.get_pause_flags();
+    assert!(flags.create && flags.confirm && flags.cancel && flags.withdraw);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::ContractPaused));
+
+    contract.unpause();
+    assert_eq!(
+        contract.get_pause_flags(),
+        crate::PauseFlags {
+            create: false,
+            confirm: false,
+            cancel: false,
+            withdraw: false,
+        }
+    );
+    contract.create_remittance(&sender, &agent, &1000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Yield Accounting Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_record_and_sweep_yield_leaves_fees_and_principal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let settled = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&settled);
+    let pending = contract.create_remittance(&sender, &agent, &2000, &None);
+
+    // Yield arrives from the external source
+    token.mint(&contract.address, &100);
+    contract.record_yield(&100);
+    assert_eq!(contract.get_yield_pool(), 100);
+
+    contract.sweep_yield(&treasury);
+
+    assert_eq!(get_token_balance(&token, &treasury), 100);
+    assert_eq!(contract.get_yield_pool(), 0);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(get_token_balance(&token, &contract.address), 2025);
+
+    // Escrowed principal is still fully available to the agent
+    contract.confirm_payout(&pending);
+    assert_eq!(get_token_balance(&token, &agent), 975 + 1950);
+}
+
+#[test]
+fn test_sweep_yield_cannot_touch_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Recorded yield that never actually arrived would come out of escrow
+    contract.record_yield(&500);
+
+    let result = contract.try_sweep_yield(&treasury);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(crate::ContractError::InsufficientContractBalance)
+    );
+    assert_eq!(get_token_balance(&token, &contract.address), 1000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Balance Precheck Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_remittance_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &999);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(crate::ContractError::InsufficientBalance)
+    );
+    assert_eq!(get_token_balance(&token, &sender), 999);
+
+    // Exactly the held balance is fine
+    contract.create_remittance(&sender, &agent, &999, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Amount Adjustment Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_adjust_amount_increase_and_decrease() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    contract.adjust_amount(&id, &2000);
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.amount, 2000);
+    assert_eq!(remittance.fee, 50);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+    assert_eq!(get_token_balance(&token, &contract.address), 2000);
+
+    // Fee is recomputed at the rate in effect at adjustment time
+    contract.update_fee(&100);
+    contract.adjust_amount(&id, &400);
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.amount, 400);
+    assert_eq!(remittance.fee, 4);
+    assert_eq!(get_token_balance(&token, &sender), 9600);
+    assert_eq!(get_token_balance(&token, &contract.address), 400);
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 396);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_adjust_amount_rejects_non_positive() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.adjust_amount(&id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_adjust_amount_rejects_accepted_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.accept_remittance(&id);
+    contract.adjust_amount(&id, &1500);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Routing Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fees_accumulate_when_auto_sweep_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_fee_recipient(&treasury);
+    assert_eq!(contract.get_fee_recipient(), Some(treasury.clone()));
+    assert!(!contract.is_auto_sweep());
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(get_token_balance(&token, &treasury), 0);
+    assert_eq!(get_token_balance(&token, &contract.address), 25);
+}
+
+#[test]
+fn test_fees_routed_to_recipient_when_auto_sweep_on() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+    contract.set_fee_recipient(&treasury);
+    contract.set_auto_sweep(&true);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(get_token_balance(&token, &treasury), 25);
+
+    let batched = contract.create_remittance(&sender, &agent, &2000, &None);
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: batched });
+    contract.batch_settle_with_netting(&entries);
+
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(get_token_balance(&token, &treasury), 75);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Idempotent Creation Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_remittance_idempotent_returns_same_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let key = soroban_sdk::BytesN::from_array(&env, &[7u8; 32]);
+
+    let first = contract.create_remittance_idempotent(&sender, &agent, &1000, &None, &key);
+    let retry = contract.create_remittance_idempotent(&sender, &agent, &1000, &None, &key);
+
+    assert_eq!(first, retry);
+    // Only one transfer happened
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    assert_eq!(get_token_balance(&token, &contract.address), 1000);
+
+    // A different key creates a new remittance
+    let other_key = soroban_sdk::BytesN::from_array(&env, &[8u8; 32]);
+    let second = contract.create_remittance_idempotent(&sender, &agent, &1000, &None, &other_key);
+    assert_ne!(second, first);
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Outstanding Liability Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_outstanding_liability_tracks_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert_eq!(contract.get_outstanding_liability(), 0);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    let _id3 = contract.create_remittance(&sender, &agent, &3000, &None);
+    assert_eq!(contract.get_outstanding_liability(), 6000);
+
+    contract.confirm_payout(&id1);
+    assert_eq!(contract.get_outstanding_liability(), 5000);
+
+    contract.cancel_remittance(&id2);
+    assert_eq!(contract.get_outstanding_liability(), 3000);
+
+    // Contract balance covers liability plus accumulated fees
+    let balance = get_token_balance(&token, &contract.address);
+    assert_eq!(balance, contract.get_outstanding_liability() + contract.get_accumulated_fees());
+}
+
+#[test]
+fn test_outstanding_liability_zero_after_all_terminal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1500, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2500, &None);
+
+    contract.cancel_remittance(&id1);
+    contract.confirm_payout(&id2);
+
+    assert_eq!(contract.get_outstanding_liability(), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Reassignment Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_reassign_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let new_agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.register_agent(&new_agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.reassign_agent(&remittance_id, &new_agent);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.agent, new_agent);
+
+    // Payout now goes to the new agent
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &new_agent), 975);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+}
+
+#[test]
+fn test_reassign_agent_unregistered_target() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let result = contract.try_reassign_agent(&remittance_id, &stranger);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentNotRegistered));
+    assert_eq!(contract.get_remittance(&remittance_id).agent, agent);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Minimum Payout Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_min_payout_blocks_dust_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_min_payout(&500);
+    assert_eq!(contract.get_min_payout(), 500);
+
+    token.mint(&sender, &10000);
+
+    // 400 - 10 fee = 390 payout, below the minimum
+    let small = contract.create_remittance(&sender, &agent, &400, &None);
+    let result = contract.try_confirm_payout(&small);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+
+    // 1000 - 25 fee = 975 payout, allowed
+    let large = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&large);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_min_payout_applies_to_batch_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+    contract.set_min_payout(&500);
+
+    token.mint(&sender, &10000);
+
+    let small = contract.create_remittance(&sender, &agent, &400, &None);
+    let large = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: large });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: small });
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+    assert_eq!(get_token_balance(&token, &agent), 0);
+
+    // Disabling the threshold lets the dust remittance through
+    contract.set_min_payout(&0);
+    contract.confirm_payout(&small);
+    assert_eq!(get_token_balance(&token, &agent), 390);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Split Remittance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_split_remittance_settles_legs_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+    let agent3 = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent1);
+    contract.register_agent(&agent2);
+    contract.register_agent(&agent3);
+
+    token.mint(&sender, &10000);
+
+    let mut legs = soroban_sdk::Vec::new(&env);
+    legs.push_back(crate::SplitLeg { agent: agent1.clone(), amount: 1000 });
+    legs.push_back(crate::SplitLeg { agent: agent2.clone(), amount: 2000 });
+    legs.push_back(crate::SplitLeg { agent: agent3.clone(), amount: 4000 });
+
+    let ids = contract.create_split_remittance(&sender, &legs, &None);
+    assert_eq!(ids.len(), 3);
+    assert_eq!(get_token_balance(&token, &sender), 3000);
+    assert_eq!(get_token_balance(&token, &contract.address), 7000);
+    assert_eq!(contract.get_outstanding_liability(), 7000);
+
+    let second = contract.get_remittance(&ids.get(1).unwrap());
+    assert_eq!(second.agent, agent2);
+    assert_eq!(second.amount, 2000);
+    assert_eq!(second.fee, 50);
+
+    contract.confirm_payout(&ids.get(0).unwrap());
+    contract.confirm_payout(&ids.get(2).unwrap());
+    assert_eq!(get_token_balance(&token, &agent1), 975);
+    assert_eq!(get_token_balance(&token, &agent3), 3900);
+
+    // The remaining leg is still pending and can be cancelled on its own
+    contract.cancel_remittance(&ids.get(1).unwrap());
+    assert_eq!(get_token_balance(&token, &sender), 5000);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+}
+
+#[test]
+fn test_create_split_remittance_rejects_unregistered_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let mut legs = soroban_sdk::Vec::new(&env);
+    legs.push_back(crate::SplitLeg { agent: agent.clone(), amount: 1000 });
+    legs.push_back(crate::SplitLeg { agent: stranger, amount: 1000 });
+
+    let result = contract.try_create_split_remittance(&sender, &legs, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentNotRegistered));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+#[test]
+fn test_create_split_remittance_fails_whole_split_over_active_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent1);
+    contract.register_agent(&agent2);
+
+    token.mint(&sender, &10000);
+
+    contract.set_max_active_remittances(&2);
+
+    // Two legs would fit, the third crosses the cap
+    let mut legs = soroban_sdk::Vec::new(&env);
+    legs.push_back(crate::SplitLeg { agent: agent1.clone(), amount: 1000 });
+    legs.push_back(crate::SplitLeg { agent: agent2.clone(), amount: 1000 });
+    legs.push_back(crate::SplitLeg { agent: agent1.clone(), amount: 1000 });
+
+    let result = contract.try_create_split_remittance(&sender, &legs, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TooManyActiveRemittances));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_active_remittance_count(), 0);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+    assert_eq!(contract.get_agent_pending_count(&agent1), 0);
+}
+
+#[test]
+fn test_create_split_remittance_counts_legs_against_agent_capacity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_max_pending(&agent, &1);
+
+    token.mint(&sender, &10000);
+
+    let mut legs = soroban_sdk::Vec::new(&env);
+    legs.push_back(crate::SplitLeg { agent: agent.clone(), amount: 1000 });
+    legs.push_back(crate::SplitLeg { agent: agent.clone(), amount: 1000 });
+
+    let result = contract.try_create_split_remittance(&sender, &legs, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentAtCapacity));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Balance Check Event Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_balance_check_event_after_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    assert!(!contract.is_balance_events_enabled());
+    contract.emit_balance_events(&true);
+    assert!(contract.is_balance_events_enabled());
+
+    token.mint(&sender, &10000);
+
+    let _pending = contract.create_remittance(&sender, &agent, &3000, &None);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+
+    assert_eq!(event.0, contract.address);
+    assert_eq!(
+        event.1,
+        (symbol_short!("settle"), symbol_short!("balance")).into_val(&env)
+    );
+
+    let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    let balance: i128 = soroban_sdk::FromVal::from_val(&env, &event_data.get(3).unwrap());
+    let fees: i128 = soroban_sdk::FromVal::from_val(&env, &event_data.get(4).unwrap());
+
+    // 3000 still escrowed plus the 25 fee from the settled remittance
+    assert_eq!(balance, 3025);
+    assert_eq!(fees, 25);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Scheduled Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_scheduled_fee_applies_at_effective_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let effective_at = env.ledger().timestamp() + 3600;
+    contract.schedule_fee_update(&500, &effective_at);
+    assert_eq!(contract.get_scheduled_fee(), Some((500, effective_at)));
+
+    // Before the effective time the current fee applies
+    let before = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&before).fee, 25);
+    assert_eq!(contract.get_platform_fee_bps(), 250);
+
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+
+    let after = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&after).fee, 50);
+    assert_eq!(contract.get_platform_fee_bps(), 500);
+    assert_eq!(contract.get_scheduled_fee(), None);
+}
+
+#[test]
+fn test_cancel_scheduled_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let effective_at = env.ledger().timestamp() + 3600;
+    contract.schedule_fee_update(&500, &effective_at);
+    contract.cancel_scheduled_fee();
+    assert_eq!(contract.get_scheduled_fee(), None);
+
+    env.ledger().with_mut(|li| li.timestamp = effective_at + 1);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, 25);
+}
+
+#[test]
+fn test_schedule_fee_update_rejects_invalid_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let result = contract.try_schedule_fee_update(&10001, &0);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Update Event Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_update_fee_event_reports_old_and_new_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    contract.update_fee(&500);
+
+    let events = env.events().all();
+    let event = events
+        .iter()
+        .find(|e| e.1 == (symbol_short!("fee"), symbol_short!("updated")).into_val(&env))
+        .expect("fee_updated event should be emitted");
+
+    let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    let event_admin: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(3).unwrap());
+    let old_fee: u32 = soroban_sdk::FromVal::from_val(&env, &event_data.get(4).unwrap());
+    let new_fee: u32 = soroban_sdk::FromVal::from_val(&env, &event_data.get(5).unwrap());
+
+    assert_eq!(event_admin, admin);
+    assert_eq!(old_fee, 250);
+    assert_eq!(new_fee, 500);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Open Registration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_self_register_agent_fails_while_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    assert_eq!(contract.get_registration_mode(), crate::RegistrationMode::AdminOnly);
+
+    let result = contract.try_self_register_agent(&agent);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::RegistrationClosed));
+    assert!(!contract.is_agent_registered(&agent));
+
+    // Admin registration still works
+    contract.register_agent(&agent);
+    assert!(contract.is_agent_registered(&agent));
+}
+
+#[test]
+fn test_self_register_agent_with_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.set_open_registration(&true);
+    contract.set_registration_stake(&500);
+
+    assert_eq!(contract.get_registration_mode(), crate::RegistrationMode::Open);
+
+    token.mint(&agent, &1000);
+    contract.self_register_agent(&agent);
+
+    assert!(contract.is_agent_registered(&agent));
+    assert_eq!(get_token_balance(&token, &agent), 500);
+    assert_eq!(get_token_balance(&token, &contract.address), 500);
+
+    let result = contract.try_self_register_agent(&agent);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentAlreadyRegistered));
+
+    // Self-registered agents can receive remittances
+    token.mint(&sender, &1000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 1475);
+}
+
+#[test]
+fn test_self_register_agent_insufficient_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.set_open_registration(&true);
+    contract.set_registration_stake(&500);
+
+    token.mint(&agent, &100);
+
+    let result = contract.try_self_register_agent(&agent);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientBalance));
+    assert!(!contract.is_agent_registered(&agent));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Stake Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_stake_required_above_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_stake_threshold(&1000);
+
+    token.mint(&sender, &10000);
+    token.mint(&agent, &1000);
+
+    // At or below the threshold no stake is needed
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1001, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientStake));
+
+    contract.stake_agent(&agent, &400);
+    assert_eq!(contract.get_agent_stake(&agent), 400);
+    assert_eq!(get_token_balance(&token, &agent), 600);
+
+    contract.create_remittance(&sender, &agent, &5000, &None);
+}
+
+#[test]
+fn test_slash_agent_moves_stake_to_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&agent, &1000);
+    contract.stake_agent(&agent, &1000);
+
+    contract.slash_agent(&agent, &300);
+    assert_eq!(contract.get_agent_stake(&agent), 700);
+    assert_eq!(contract.get_accumulated_fees(), 300);
+    assert_eq!(contract.get_lifetime_fees(), 300);
+
+    let result = contract.try_slash_agent(&agent, &701);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientStake));
+}
+
+#[test]
+fn test_unstake_agent_respects_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_unstake_cooldown(&86400);
+
+    token.mint(&agent, &1000);
+    contract.stake_agent(&agent, &1000);
+
+    let result = contract.try_unstake_agent(&agent, &500);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::StakeLocked));
+
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+
+    contract.unstake_agent(&agent, &500);
+    assert_eq!(contract.get_agent_stake(&agent), 500);
+    assert_eq!(get_token_balance(&token, &agent), 500);
+    assert_eq!(get_token_balance(&token, &contract.address), 500);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Per-Token Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_token_fee_override_applied() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let other_token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // An override on another token leaves the settlement token on the global fee
+    contract.set_token_fee_bps(&other_token.address, &100);
+    assert_eq!(contract.get_token_fee_bps(&other_token.address), Some(100));
+    assert_eq!(contract.get_token_fee_bps(&token.address), None);
+
+    let global = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&global).fee, 25);
+
+    contract.set_token_fee_bps(&token.address, &50);
+
+    let overridden = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&overridden).fee, 5);
+}
+
+#[test]
+fn test_set_token_fee_bps_rejects_invalid_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let result = contract.try_set_token_fee_bps(&token.address, &10001);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Remittance Counter Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_remittance_counter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert_eq!(contract.get_remittance_counter(), 0);
+
+    contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+    let last = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    assert_eq!(contract.get_remittance_counter(), 3);
+    assert_eq!(contract.get_remittance_counter(), last);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Mutual Cancellation Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_mutual_cancel_accepted_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.accept_remittance(&remittance_id);
+
+    // The sender alone can no longer cancel
+    assert!(contract.try_cancel_remittance(&remittance_id).is_err());
+
+    contract.mutual_cancel(&remittance_id);
+
+    // Both parties had to authorize
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == sender));
+    assert!(auths.iter().any(|(addr, _)| *addr == agent));
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+
+    let result = contract.try_mutual_cancel(&remittance_id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+#[test]
+fn test_mutual_cancel_closes_refund_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.request_refund(&remittance_id);
+
+    contract.mutual_cancel(&remittance_id);
+
+    // The sender was refunded once, so there is nothing left to approve
+    assert!(!contract.is_refund_requested(&remittance_id));
+    let result = contract.try_approve_refund(&agent, &remittance_id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoRefundRequested));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Settlement Deadline Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_settle_by_on_time_not_flagged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let deadline = env.ledger().timestamp() + 3600;
+    contract.set_settle_by(&remittance_id, &deadline);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline);
+    contract.confirm_payout(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.settle_by, Some(deadline));
+    assert!(!remittance.late);
+
+    let late_topic = (symbol_short!("settle"), symbol_short!("late")).into_val(&env);
+    assert!(!env.events().all().iter().any(|e| e.1 == late_topic));
+}
+
+#[test]
+fn test_settle_by_late_settlement_flagged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let deadline = env.ledger().timestamp() + 3600;
+    contract.set_settle_by(&remittance_id, &deadline);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+
+    // Late settlement still pays out
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert!(remittance.late);
+
+    let late_topic = (symbol_short!("settle"), symbol_short!("late")).into_val(&env);
+    let events = env.events().all();
+    let event = events
+        .iter()
+        .find(|e| e.1 == late_topic)
+        .expect("late settlement event should be emitted");
+
+    let event_data: soroban_sdk::Vec<soroban_sdk::Val> =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    let event_id: u64 = soroban_sdk::FromVal::from_val(&env, &event_data.get(3).unwrap());
+    let event_agent: Address = soroban_sdk::FromVal::from_val(&env, &event_data.get(4).unwrap());
+    let event_deadline: u64 = soroban_sdk::FromVal::from_val(&env, &event_data.get(5).unwrap());
+
+    assert_eq!(event_id, remittance_id);
+    assert_eq!(event_agent, agent);
+    assert_eq!(event_deadline, deadline);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Withdraw All Fees Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_all_fees_sweeps_in_one_call() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.confirm_payout(&id1);
+    contract.confirm_payout(&id2);
+    assert_eq!(contract.get_accumulated_fees(), 75);
+
+    contract.withdraw_all_fees(&treasury);
+
+    assert_eq!(get_token_balance(&token, &treasury), 75);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+}
+
+#[test]
+fn test_withdraw_all_fees_skips_zero_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    // No fees is not an error, and nothing is emitted
+    contract.withdraw_all_fees(&treasury);
+
+    let withdrawn_topic = (symbol_short!("fee"), symbol_short!("withdraw")).into_val(&env);
+    assert!(!env.events().all().iter().any(|e| e.1 == withdrawn_topic));
+    assert_eq!(get_token_balance(&token, &treasury), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batch Rollback Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_batch_settle_reverts_when_recipient_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+
+    // Revocable asset so the issuer can deauthorize one agent's trustline
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    asset.issuer().set_flag(soroban_sdk::xdr::AccountFlags::RevocableFlag);
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent1);
+    contract.register_agent(&agent2);
+    contract.set_agent_batch_eligible(&agent1, &true);
+    contract.set_agent_batch_eligible(&agent2, &true);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent1, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent2, &2000, &None);
+
+    token.set_authorized(&agent2, &false);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert!(result.is_err());
+
+    // Nothing from the batch persisted, including agent1's earlier transfer
+    assert_eq!(get_token_balance(&token, &agent1), 0);
+    assert_eq!(get_token_balance(&token, &agent2), 0);
+    assert_eq!(get_token_balance(&token, &contract.address), 3000);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_outstanding_liability(), 3000);
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Pending);
+    assert!(!contract.is_settled(&id1));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Storage Migration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_migrate_range_backfills_legacy_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let legacy_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let current_id = contract.create_remittance(&sender, &agent, &2000, &None);
+
+    // Rewrite the first record in the pre-migration layout and roll the schema back
+    env.as_contract(&contract.address, || {
+        let mut fields = crate::get_remittance_fields(&env, legacy_id).unwrap();
+        fields.remove(soroban_sdk::Symbol::new(&env, "settle_by"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "late"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "created_at"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "recipient"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "tag"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "cancel_reason"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "conditional"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "failed_attempts"));
+        crate::set_remittance_fields(&env, legacy_id, &fields);
+        crate::set_schema_version(&env, 1);
+    });
+
+    assert_eq!(contract.migrate(), crate::STORAGE_SCHEMA_VERSION);
+    assert_eq!(contract.get_schema_version(), crate::STORAGE_SCHEMA_VERSION);
+
+    // Only the legacy record needed backfilling
+    assert_eq!(contract.migrate_range(&1, &current_id), 1);
+
+    let migrated = contract.get_remittance(&legacy_id);
+    assert_eq!(migrated.amount, 1000);
+    assert_eq!(migrated.settle_by, None);
+    assert!(!migrated.late);
+    assert_eq!(migrated.created_at, 0);
+    assert_eq!(migrated.recipient, None);
+    assert_eq!(migrated.tag, 0);
+    assert_eq!(migrated.cancel_reason, 0);
+    assert!(!migrated.conditional);
+    assert_eq!(migrated.failed_attempts, 0);
+
+    // Migrated records work with current code paths
+    contract.confirm_payout(&legacy_id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    // Re-running is a no-op
+    assert_eq!(contract.migrate_range(&1, &current_id), 0);
+}
+
+#[test]
+fn test_migrate_refuses_to_run_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    // Fresh deployments are already current
+    let result = contract.try_migrate();
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AlreadyInitialized));
+
+    let result = contract.try_migrate_range(&5, &4);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidBatchSize));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Cap Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fee_cap_forwards_overflow_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_fee_cap(&40, &treasury);
+    assert_eq!(contract.get_fee_cap(), Some((40, treasury.clone())));
+
+    token.mint(&sender, &10000);
+
+    // First fee (25) stays under the cap
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id1);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(get_token_balance(&token, &treasury), 0);
+
+    // Second fee (50) pushes the total to 75; 35 overflows
+    let id2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.confirm_payout(&id2);
+    assert_eq!(contract.get_accumulated_fees(), 40);
+    assert_eq!(get_token_balance(&token, &treasury), 35);
+    assert_eq!(get_token_balance(&token, &contract.address), 40);
+}
+
+#[test]
+fn test_fee_cap_zero_disables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_fee_cap(&10, &treasury);
+    contract.set_fee_cap(&0, &treasury);
+    assert_eq!(contract.get_fee_cap(), None);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(get_token_balance(&token, &treasury), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Allowance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_allowance_drawn_down_across_creations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.approve_agent_allowance(&sender, &agent, &3000);
+    assert_eq!(contract.get_allowance(&sender, &agent), 3000);
+
+    let id1 = contract.create_remittance_from_allowance(&sender, &agent, &1000, &None);
+    // The draw is authorized by the agent, not the sender
+    assert!(!env.auths().iter().any(|(addr, _)| *addr == sender));
+    assert_eq!(contract.get_allowance(&sender, &agent), 2000);
+
+    let id2 = contract.create_remittance_from_allowance(&sender, &agent, &2000, &None);
+    assert_eq!(contract.get_allowance(&sender, &agent), 0);
+
+    // Allowance draws publish the same created event as create_remittance
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("created")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, Address, i128, i128, u32, u32, i128) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.3, id2);
+    assert_eq!(data.5, agent);
+    assert_eq!(data.7, 50);
+    assert_eq!(data.8, 250);
+
+    assert_eq!(contract.get_remittance(&id1).amount, 1000);
+    assert_eq!(contract.get_remittance(&id2).amount, 2000);
+    assert_eq!(get_token_balance(&token, &sender), 7000);
+    assert_eq!(get_token_balance(&token, &contract.address), 3000);
+
+    let result = contract.try_create_remittance_from_allowance(&sender, &agent, &1, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AllowanceExceeded));
+}
+
+#[test]
+fn test_allowances_for_multiple_agents_coexist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent1 = Address::generate(&env);
+    let agent2 = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent1);
+    contract.register_agent(&agent2);
+
+    token.mint(&sender, &10000);
+
+    contract.approve_agent_allowance(&sender, &agent1, &1000);
+    contract.approve_agent_allowance(&sender, &agent2, &2000);
+
+    // Approving agent2 must not shrink the token approval backing agent1
+    contract.create_remittance_from_allowance(&sender, &agent1, &1000, &None);
+    contract.create_remittance_from_allowance(&sender, &agent2, &2000, &None);
+
+    assert_eq!(contract.get_allowance(&sender, &agent1), 0);
+    assert_eq!(contract.get_allowance(&sender, &agent2), 0);
+    assert_eq!(get_token_balance(&token, &contract.address), 3000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Initialization State Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_is_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    assert!(!contract.is_initialized());
+
+    contract.initialize(&admin, &token.address, &250, &0);
+    assert!(contract.is_initialized());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Settlement Receipt Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_settlement_receipt_recorded_on_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_settlement_receipt(&remittance_id), None);
+
+    contract.confirm_payout(&remittance_id);
+
+    let receipt = contract.get_settlement_receipt(&remittance_id).unwrap();
+    let expected = crate::compute_settlement_receipt(
+        &env,
+        remittance_id,
+        &sender,
+        &agent,
+        975,
+        env.ledger().timestamp(),
+    );
+    assert_eq!(receipt, expected);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|e| e.1 == (symbol_short!("settle"), symbol_short!("complete")).into_val(&env))
+        .unwrap();
+    let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(&env, &event.2);
+    let emitted: soroban_sdk::BytesN<32> = soroban_sdk::FromVal::from_val(&env, &data.get(8).unwrap());
+    assert_eq!(emitted, receipt);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Quoted Remittance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_remittance_quoted_converts_at_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // 20 MXN per USDC
+    let mxn = soroban_sdk::String::from_str(&env, "MXN");
+    contract.set_rate(&mxn, &200000);
+    assert_eq!(contract.get_rate(&mxn), Some(200000));
+
+    let remittance_id = contract.create_remittance_quoted(&sender, &agent, &20000, &mxn, &None);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 1000);
+    assert_eq!(remittance.fee, 25);
+    assert_eq!(remittance.quote_amount, Some(20000));
+    assert_eq!(remittance.quote_currency, Some(mxn));
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+}
+
+#[test]
+fn test_create_remittance_quoted_rejects_unknown_currency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let php = soroban_sdk::String::from_str(&env, "PHP");
+    let result = contract.try_create_remittance_quoted(&sender, &agent, &1000, &php, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FxRateNotSet));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Zero Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_zero_fee_settlement_pays_full_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &0, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).fee, 0);
+
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(get_token_balance(&token, &agent), 1000);
+    assert_eq!(get_token_balance(&token, &contract.address), 0);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+
+    let events = env.events().all();
+    let settled = events
+        .iter()
+        .find(|e| e.1 == (symbol_short!("settle"), symbol_short!("complete")).into_val(&env))
+        .unwrap();
+    let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(&env, &settled.2);
+    let amount: i128 = soroban_sdk::FromVal::from_val(&env, &data.get(7).unwrap());
+    assert_eq!(amount, 1000);
+
+    let completed = events
+        .iter()
+        .find(|e| e.1 == (symbol_short!("remit"), symbol_short!("complete")).into_val(&env))
+        .unwrap();
+    let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(&env, &completed.2);
+    let amount: i128 = soroban_sdk::FromVal::from_val(&env, &data.get(5).unwrap());
+    assert_eq!(amount, 1000);
+
+    let result = contract.try_withdraw_fees(&admin);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoFeesToWithdraw));
+}
+
+#[test]
+fn test_update_fee_to_zero_mid_operation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let before = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    contract.update_fee(&0);
+    assert_eq!(contract.get_platform_fee_bps(), 0);
+
+    let after = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&after).fee, 0);
+
+    // The fee is fixed at creation, so the earlier remittance still pays it
+    contract.confirm_payout(&before);
+    contract.confirm_payout(&after);
+
+    assert_eq!(get_token_balance(&token, &agent), 975 + 1000);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Default Validity Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_default_validity_sets_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    contract.set_default_validity_seconds(&3600);
+    assert_eq!(contract.get_default_validity_seconds(), 3600);
+
+    let defaulted = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&defaulted).expiry, Some(8600));
+
+    // An explicit expiry still wins
+    let explicit = contract.create_remittance(&sender, &agent, &1000, &Some(6000));
+    assert_eq!(contract.get_remittance(&explicit).expiry, Some(6000));
+}
+
+#[test]
+fn test_default_validity_zero_keeps_no_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert_eq!(contract.get_default_validity_seconds(), 0);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).expiry, None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Signed Payout Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+// Agent Ed25519 key used to sign relayed payouts.
+fn signed_payout_key() -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[7u8; 32])
+}
+
+// Signs the payload `contract` expects for `remittance_id` and `nonce`.
+fn sign_payout<'a>(
+    env: &Env,
+    contract: &SwiftRemitContractClient<'a>,
+    remittance_id: u64,
+    nonce: u64,
+) -> soroban_sdk::BytesN<64> {
+    use ed25519_dalek::Signer;
+
+    let payload = env.as_contract(&contract.address, || {
+        crate::compute_payout_signing_payload(env, remittance_id, nonce)
+    });
+    let mut message = alloc::vec![0u8; payload.len() as usize];
+    payload.copy_into_slice(&mut message);
+
+    soroban_sdk::BytesN::from_array(env, &signed_payout_key().sign(&message).to_bytes())
+}
+
+fn setup_signed_payout<'a>(
+    env: &Env,
+) -> (SwiftRemitContractClient<'a>, token::StellarAssetClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_public_key(
+        &agent,
+        &soroban_sdk::BytesN::from_array(env, &signed_payout_key().verifying_key().to_bytes()),
+    );
+
+    token.mint(&sender, &10000);
+
+    (contract, token, sender, agent)
+}
+
+#[test]
+fn test_confirm_payout_signed_with_valid_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract, token, sender, agent) = setup_signed_payout(&env);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let signature = sign_payout(&env, &contract, remittance_id, 7);
+    contract.confirm_payout_signed(&remittance_id, &agent, &signature, &7);
+
+    // The relayer needed no signature from the agent
+    assert!(env.auths().is_empty());
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_confirm_payout_signed_rejects_reused_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract, _token, sender, agent) = setup_signed_payout(&env);
+    let first = contract.create_remittance(&sender, &agent, &1000, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let signature = sign_payout(&env, &contract, first, 7);
+    contract.confirm_payout_signed(&first, &agent, &signature, &7);
+
+    let signature = sign_payout(&env, &contract, second, 7);
+    let result = contract.try_confirm_payout_signed(&second, &agent, &signature, &7);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NonceReused));
+}
+
+#[test]
+fn test_confirm_payout_signed_rejects_tampered_message() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract, token, sender, agent) = setup_signed_payout(&env);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Signature was made over nonce 7, not 8. ed25519_verify traps in the
+    // host rather than returning a ContractError.
+    let signature = sign_payout(&env, &contract, remittance_id, 7);
+    let result = contract.try_confirm_payout_signed(&remittance_id, &agent, &signature, &8);
+    assert_eq!(result.unwrap_err(), Err(soroban_sdk::InvokeError::Abort));
+
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_confirm_payout_signed_rejects_signature_for_other_deployment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract, token, sender, agent) = setup_signed_payout(&env);
+    let (other, _, _, _) = setup_signed_payout(&env);
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Same key, ID and nonce, but signed for another deployment
+    let signature = sign_payout(&env, &other, remittance_id, 7);
+    let result = contract.try_confirm_payout_signed(&remittance_id, &agent, &signature, &7);
+    assert_eq!(result.unwrap_err(), Err(soroban_sdk::InvokeError::Abort));
+
+    assert_eq!(get_token_balance(&token, &agent), 0);
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Pending);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Inbound Freeze Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_frozen_agent_cannot_receive_but_can_settle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let existing = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    contract.freeze_agent_inbound(&agent);
+    assert!(contract.is_agent_inbound_frozen(&agent));
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentFrozen));
+
+    // Pre-existing work still settles
+    contract.confirm_payout(&existing);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert!(contract.is_agent_registered(&agent));
+
+    contract.unfreeze_agent_inbound(&agent);
+    assert!(!contract.is_agent_inbound_frozen(&agent));
+    contract.create_remittance(&sender, &agent, &1000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Capacity Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_max_pending_caps_new_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.set_agent_max_pending(&agent, &2);
+    assert_eq!(contract.get_agent_max_pending(&agent), 2);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &None);
+    let second = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_agent_pending_count(&agent), 2);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentAtCapacity));
+
+    // Settling and cancelling free up slots
+    contract.confirm_payout(&first);
+    contract.cancel_remittance(&second);
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+
+    contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_agent_pending_count(&agent), 2);
+
+    // Zero lifts the cap
+    contract.set_agent_max_pending(&agent, &0);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_agent_pending_count(&agent), 3);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Claimable Remittance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_claim_remittance_with_correct_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"PIN-4821");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+    let remittance_id = contract.create_claimable(&sender, &1000, &code_hash, &None);
+
+    contract.claim_remittance(&remittance_id, &code, &recipient);
+
+    assert_eq!(get_token_balance(&token, &recipient), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+    assert_eq!(contract.get_remittance(&remittance_id).status, crate::RemittanceStatus::Completed);
+
+    // A code works only once
+    let result = contract.try_claim_remittance(&remittance_id, &code, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+#[test]
+fn test_claim_remittance_rejects_wrong_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"PIN-4821");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+    let remittance_id = contract.create_claimable(&sender, &1000, &code_hash, &None);
+
+    let wrong = soroban_sdk::Bytes::from_slice(&env, b"PIN-0000");
+    let result = contract.try_claim_remittance(&remittance_id, &wrong, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidClaimCode));
+    assert_eq!(get_token_balance(&token, &recipient), 0);
+
+    // An agent remittance has no claim code at all
+    let agent = Address::generate(&env);
+    contract.register_agent(&agent);
+    let agent_remittance = contract.create_remittance(&sender, &agent, &1000, &None);
+    let result = contract.try_claim_remittance(&agent_remittance, &code, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidClaimCode));
+}
+
+#[test]
+fn test_claim_code_is_bound_to_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.set_holdback_bps(&2000);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"PIN-4821");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+    let remittance_id = contract.create_claimable(&sender, &1000, &code_hash, &None);
+
+    // A front-runner replaying the code cannot redirect the payout
+    let result = contract.try_claim_remittance(&remittance_id, &code, &attacker);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidClaimCode));
+    assert_eq!(get_token_balance(&token, &attacker), 0);
+
+    // Claims settle like any other payout, holdback included
+    contract.claim_remittance(&remittance_id, &code, &recipient);
+    assert_eq!(get_token_balance(&token, &recipient), 780);
+    assert!(contract.get_settlement_receipt(&remittance_id).is_some());
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.recipient, Some(recipient.clone()));
+    assert_eq!(remittance.status, crate::RemittanceStatus::PartiallyReleased);
+
+    // With no agent, the sender confirms delivery
+    contract.release_holdback(&remittance_id);
+    assert!(env.auths().iter().any(|(addr, _)| *addr == sender));
+    assert_eq!(get_token_balance(&token, &recipient), 975);
+}
+
+#[test]
+fn test_unclaimed_remittance_reclaimable_after_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"PIN-4821");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+    let expiry = env.ledger().timestamp() + 3600;
+    let remittance_id = contract.create_claimable(&sender, &1000, &code_hash, &Some(expiry));
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+
+    let result = contract.try_claim_remittance(&remittance_id, &code, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::SettlementExpired));
+
+    contract.reclaim_expired(&remittance_id);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batch Cancel Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_batch_cancel_skips_other_senders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let other = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    token.mint(&other, &10000);
+
+    let own1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let own2 = contract.create_remittance(&sender, &agent, &2000, &None);
+    let settled = contract.create_remittance(&sender, &agent, &500, &None);
+    let foreign = contract.create_remittance(&other, &agent, &3000, &None);
+    contract.confirm_payout(&settled);
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(own1);
+    ids.push_back(foreign);
+    ids.push_back(own2);
+    ids.push_back(settled);
+    ids.push_back(999);
+
+    assert_eq!(contract.batch_cancel(&sender, &ids, &2), 2);
+
+    assert_eq!(contract.get_remittance(&own1).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_remittance(&own2).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_remittance(&own1).cancel_reason, 2);
+    assert_eq!(contract.get_remittance(&own2).cancel_reason, 2);
+    assert_eq!(contract.get_remittance(&foreign).cancel_reason, 0);
+    assert_eq!(contract.get_remittance(&foreign).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &sender), 9500);
+    assert_eq!(get_token_balance(&token, &other), 7000);
+}
+
+#[test]
+fn test_batch_cancel_rejects_oversized_list() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for id in 0..(crate::MAX_BATCH_SIZE as u64 + 1) {
+        ids.push_back(id);
+    }
+
+    let result = contract.try_batch_cancel(&sender, &ids, &0);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidBatchSize));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Expiry Grace Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_confirm_payout_within_expiry_grace() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_expiry_grace_seconds(&300);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 120);
+
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+
+    let grace_events = env
+        .events()
+        .all()
+        .iter()
+        .filter(|e| e.1 == (symbol_short!("settle"), symbol_short!("grace")).into_val(&env))
+        .count();
+    assert_eq!(grace_events, 1);
+}
+
+#[test]
+fn test_reclaim_blocked_until_grace_ends() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_expiry_grace_seconds(&300);
+
+    token.mint(&sender, &10000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &Some(expiry));
+
+    // Inside the grace window the agent still has priority
+    env.ledger().with_mut(|li| li.timestamp = expiry + 300);
+    let result = contract.try_reclaim_expired(&remittance_id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::CancelTooSoon));
+
+    // After it closes, settlement fails and the sender can reclaim
+    env.ledger().with_mut(|li| li.timestamp = expiry + 301);
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::SettlementExpired));
+
+    contract.reclaim_expired(&remittance_id);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Quote Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_quote_fee_matches_stored_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    let assert_quote_matches = |amount: i128| {
+        let quoted = contract.quote_fee(&agent, &amount);
+        let id = contract.create_remittance(&sender, &agent, &amount, &None);
+        assert_eq!(contract.get_remittance(&id).fee, quoted);
+    };
+
+    // Platform fee, default rounding
+    assert_quote_matches(1000);
+    assert_quote_matches(999);
+
+    // Rounding mode changes the result on fractional fees
+    contract.set_rounding_mode(&crate::RoundingMode::Up);
+    assert_eq!(contract.quote_fee(&agent, &999), 25);
+    assert_quote_matches(999);
+
+    // Per-token override takes precedence over the platform fee
+    contract.set_token_fee_bps(&token.address, &100);
+    assert_eq!(contract.quote_fee(&agent, &1000), 10);
+    assert_quote_matches(1000);
+
+    let result = contract.try_quote_fee(&Address::generate(&env), &1000);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentNotRegistered));
+}
+
+#[test]
+fn test_quote_fee_does_not_modify_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let effective_at = env.ledger().timestamp() + 3600;
+    contract.schedule_fee_update(&500, &effective_at);
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+
+    // The due scheduled fee is quoted without being promoted
+    assert_eq!(contract.quote_fee(&agent, &1000), 50);
+    assert_eq!(contract.get_platform_fee_bps(), 250);
+    assert_eq!(contract.get_scheduled_fee(), Some((500, effective_at)));
+
+    // Creation promotes it and charges the quoted fee
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 50);
+    assert_eq!(contract.get_platform_fee_bps(), 500);
+    assert_eq!(contract.get_scheduled_fee(), None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Token Recovery Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_recover_foreign_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let stray = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Someone sends the wrong token straight to the contract
+    stray.mint(&contract.address, &777);
+
+    assert_eq!(contract.recover_token(&stray.address, &treasury), 777);
+    assert_eq!(get_token_balance(&stray, &treasury), 777);
+    assert_eq!(get_token_balance(&stray, &contract.address), 0);
+
+    // Escrow in the settlement token is untouched and cannot be recovered
+    assert_eq!(get_token_balance(&token, &contract.address), 1000);
+    let result = contract.try_recover_token(&token.address, &treasury);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::CannotRecoverSettlementToken));
+
+    // Whitelisted tokens back agent pools and are refused too
+    let pool_token = create_token_contract(&env, &admin);
+    contract.whitelist_token(&admin, &pool_token.address);
+    pool_token.mint(&contract.address, &50);
+    let result = contract.try_recover_token(&pool_token.address, &treasury);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::CannotRecoverSettlementToken));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Remittance Existence Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_remittance_exists() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert!(!contract.remittance_exists(&1));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert!(contract.remittance_exists(&remittance_id));
+    assert!(!contract.remittance_exists(&(remittance_id + 1)));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Auto-Pause Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_large_create_trips_auto_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_auto_pause_threshold(&5000);
+    assert_eq!(contract.get_auto_pause_threshold(), 5000);
+
+    token.mint(&sender, &20000);
+
+    // At the threshold nothing happens
+    contract.create_remittance(&sender, &agent, &5000, &None);
+    assert!(!contract.is_paused());
+
+    // Crossing it lets the operation through, then pauses everything
+    let big = contract.create_remittance(&sender, &agent, &6000, &None);
+    assert!(contract.is_paused());
+    let tripped = env
+        .events()
+        .all()
+        .iter()
+        .any(|e| e.1 == (symbol_short!("admin"), symbol_short!("autopause")).into_val(&env));
+    assert!(tripped);
+
+    let result = contract.try_create_remittance(&sender, &agent, &100, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::ContractPaused));
+    let result = contract.try_confirm_payout(&big);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::ContractPaused));
+
+    contract.unpause();
+    contract.confirm_payout(&big);
+}
+
+#[test]
+fn test_batch_total_trips_auto_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+    contract.set_auto_pause_threshold(&5000);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent, &3000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &3000, &None);
+    assert!(!contract.is_paused());
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id1 });
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id2 });
+    contract.batch_settle_with_netting(&entries);
+
+    assert!(contract.is_paused());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Allowed Token Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_allowed_tokens_restrict_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let other = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // Agent only handles the other token, so the settlement token is refused
+    let mut tokens = soroban_sdk::Vec::new(&env);
+    tokens.push_back(other.address.clone());
+    contract.set_agent_allowed_tokens(&agent, &agent, &tokens);
+    assert_eq!(contract.get_agent_allowed_tokens(&agent), tokens);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TokenNotWhitelisted));
+
+    // Admin adds the settlement token to the agent's list
+    tokens.push_back(token.address.clone());
+    contract.set_agent_allowed_tokens(&admin, &agent, &tokens);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // An empty list accepts any token
+    contract.set_agent_allowed_tokens(&agent, &agent, &soroban_sdk::Vec::new(&env));
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let stranger = Address::generate(&env);
+    let result = contract.try_set_agent_allowed_tokens(&stranger, &agent, &tokens);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Unauthorized));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Count Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_count_tracks_registrations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    assert_eq!(contract.get_agent_count(), 0);
+
+    contract.register_agent(&agent);
+    contract.register_agent(&other);
+    assert_eq!(contract.get_agent_count(), 2);
+
+    // Re-registering an existing agent doesn't double count
+    contract.register_agent(&agent);
+    assert_eq!(contract.get_agent_count(), 2);
+
+    contract.remove_agent(&other);
+    assert_eq!(contract.get_agent_count(), 1);
+
+    // The removal event carries the count after the change
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("agent"), symbol_short!("removed")).into_val(&env)
+    );
+    let data: (u32, u32, u64, Address, u32) = soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.4, 1);
+
+    // Removing an already-removed agent leaves the count alone
+    contract.remove_agent(&other);
+    assert_eq!(contract.get_agent_count(), 1);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Token Decimals Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_token_decimals_recorded_at_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    let result = contract.try_get_token_decimals();
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NotInitialized));
+
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let expected = token::Client::new(&env, &token.address).decimals();
+    assert_eq!(contract.get_token_decimals(), expected);
+    assert_eq!(contract.get_token_decimals(), 7);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Minimum Cancel Age Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_cancel_rejected_before_min_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_min_cancel_age_seconds(&600);
+    assert_eq!(contract.get_min_cancel_age_seconds(), 600);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).created_at, 1000);
+
+    let result = contract.try_cancel_remittance(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::CancelTooSoon));
+
+    // Batch cancellation skips remittances that are too young
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(id);
+    assert_eq!(contract.batch_cancel(&sender, &ids, &0), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1600);
+    contract.cancel_remittance(&id);
+
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+#[test]
+fn test_zero_min_cancel_age_allows_immediate_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    assert_eq!(contract.get_min_cancel_age_seconds(), 0);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.cancel_remittance(&id);
+
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Amend Remittance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_amend_remittance_amount_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(5000));
+    contract.amend_remittance(&id, &Some(2000), &None);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.amount, 2000);
+    assert_eq!(remittance.fee, 50);
+    assert_eq!(remittance.expiry, Some(5000));
+    assert_eq!(get_token_balance(&token, &sender), 8000);
+    assert_eq!(get_token_balance(&token, &contract.address), 2000);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("amended")).into_val(&env)
+    );
+}
+
+#[test]
+fn test_amend_remittance_expiry_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(5000));
+
+    // Shortening is allowed as long as the expiry is not in the past
+    contract.amend_remittance(&id, &None, &Some(3000));
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.expiry, Some(3000));
+    assert_eq!(remittance.amount, 1000);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    let result = contract.try_amend_remittance(&id, &None, &Some(999));
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidExpiry));
+}
+
+#[test]
+fn test_amend_remittance_amount_and_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.amend_remittance(&id, &Some(1200), &Some(8000));
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.amount, 1200);
+    assert_eq!(remittance.fee, 30);
+    assert_eq!(remittance.expiry, Some(8000));
+    assert_eq!(get_token_balance(&token, &sender), 8800);
+
+    let event = env.events().all().last().unwrap();
+    let data: (u32, u32, u64, u64, Address, i128, i128, i128, Option<u64>, Option<u64>) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.5, 2000);
+    assert_eq!(data.6, 1200);
+    assert_eq!(data.8, None);
+    assert_eq!(data.9, Some(8000));
+
+    // Only pending remittances can be amended
+    contract.confirm_payout(&id);
+    let result = contract.try_amend_remittance(&id, &Some(1500), &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Batch Settlement Simulation Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_simulate_batch_settle_matches_real_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let vetted = Address::generate(&env);
+    let unvetted = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&vetted);
+    contract.register_agent(&unvetted);
+    contract.set_agent_batch_eligible(&vetted, &true);
+
+    token.mint(&sender, &10000);
+
+    let good = contract.create_remittance(&sender, &vetted, &1000, &None);
+    let cancelled = contract.create_remittance(&sender, &vetted, &1000, &None);
+    contract.cancel_remittance(&cancelled);
+    let ineligible = contract.create_remittance(&sender, &unvetted, &1000, &None);
+
+    let mut entries = soroban_sdk::Vec::new(&env);
+    for id in [good, cancelled, ineligible, 99, good] {
+        entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
+    }
+
+    let report = contract.simulate_batch_settle(&entries);
+    assert_eq!(report.succeeded, soroban_sdk::vec![&env, good]);
+
+    let expected = [
+        (cancelled, crate::ContractError::InvalidStatus),
+        (ineligible, crate::ContractError::AgentNotBatchEligible),
+        (99, crate::ContractError::RemittanceNotFound),
+        (good, crate::ContractError::DuplicateSettlement),
+    ];
+    assert_eq!(report.failed.len(), expected.len() as u32);
+    for (i, (id, error)) in expected.iter().enumerate() {
+        let failure = report.failed.get(i as u32).unwrap();
+        assert_eq!(failure.remittance_id, *id);
+        assert_eq!(failure.error_code, *error as u32);
+    }
+
+    // Nothing moved during the simulation
+    assert_eq!(contract.get_remittance(&good).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &vetted), 0);
+
+    // Each reported failure makes the real batch fail with the same error
+    for (id, error) in expected.iter().take(3) {
+        let mut single = soroban_sdk::Vec::new(&env);
+        single.push_back(crate::BatchSettlementEntry { remittance_id: good });
+        single.push_back(crate::BatchSettlementEntry { remittance_id: *id });
+        let result = contract.try_batch_settle_with_netting(&single);
+        assert_eq!(result.unwrap_err(), Ok(*error));
+    }
+
+    // The entries reported as succeeding settle for real
+    let mut passing = soroban_sdk::Vec::new(&env);
+    passing.push_back(crate::BatchSettlementEntry { remittance_id: good });
+    let result = contract.batch_settle_with_netting(&passing);
+    assert_eq!(result.settled_ids, report.succeeded);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Overflow Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Smallest amount whose fee multiplication overflows at `fee_bps`.
+fn overflowing_fee_amount(fee_bps: u32) -> i128 {
+    i128::MAX / fee_bps as i128 + 1
+}
+
+#[test]
+fn test_create_remittance_fee_overflow_returns_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &10000, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &i128::MAX);
+
+    let amount = overflowing_fee_amount(10000);
+    let result = contract.try_create_remittance(&sender, &agent, &amount, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Overflow));
+
+    // The quote view shares the same guarded math
+    let result = contract.try_quote_fee(&agent, &amount);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Overflow));
+
+    // Just below the boundary the fee is computed normally
+    let id = contract.create_remittance(&sender, &agent, &(amount - 1), &None);
+    assert_eq!(contract.get_remittance(&id).fee, amount - 1);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Operator Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_operator_confirms_payout_for_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let teller = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.add_agent_operator(&agent, &teller);
+    assert!(contract.is_agent_operator(&agent, &teller));
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout_by_operator(&id, &teller);
+
+    // Funds go to the agent, not the operator
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(get_token_balance(&token, &teller), 0);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("settle"), symbol_short!("operator")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, Address) = soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.4, agent);
+    assert_eq!(data.5, teller);
+}
+
+#[test]
+fn test_unlisted_operator_cannot_confirm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let teller = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.add_agent_operator(&agent, &teller);
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let result = contract.try_confirm_payout_by_operator(&id, &stranger);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Unauthorized));
+
+    // Revoked operators lose access
+    contract.remove_agent_operator(&agent, &teller);
+    assert!(!contract.is_agent_operator(&agent, &teller));
+    let result = contract.try_confirm_payout_by_operator(&id, &teller);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Unauthorized));
+
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Liquidity Floor Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_fees_refuses_to_breach_liquidity_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // One settled (fee 25) and one still escrowed (1000)
+    let settled = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&settled);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Withdrawing the fees would leave 1000, below escrow plus a 10 floor
+    contract.set_liquidity_floor(&10);
+    assert_eq!(contract.get_liquidity_floor(), 10);
+    let result = contract.try_withdraw_fees(&treasury);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientContractBalance));
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    let result = contract.try_set_liquidity_floor(&-1);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAmount));
+}
+
+#[test]
+fn test_withdraw_fees_above_liquidity_floor_succeeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    // Buffer the admin has funded on top of escrow
+    token.mint(&contract.address, &100);
+
+    let settled = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&settled);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // 1125 held; withdrawing 25 leaves 1100 >= 1000 escrow + 50 floor
+    contract.set_liquidity_floor(&50);
+    contract.withdraw_fees(&treasury);
+
+    assert_eq!(get_token_balance(&token, &treasury), 25);
+    assert_eq!(get_token_balance(&token, &contract.address), 1100);
+}
+
+#[test]
+fn test_liquidity_floor_excludes_agent_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    token.mint(&agent, &100);
+    contract.stake_agent(&agent, &100);
+
+    let settled = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&settled);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // 1125 held; withdrawing 25 leaves 1100, which only covers 1000 escrow
+    // plus a 50 floor if the agent's 100 stake is counted
+    contract.set_liquidity_floor(&50);
+    let result = contract.try_withdraw_fees(&treasury);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientContractBalance));
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    contract.set_liquidity_floor(&0);
+    contract.withdraw_fees(&treasury);
+    assert_eq!(get_token_balance(&token, &contract.address), 1100);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Token Denylist Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_denied_token_cannot_be_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let malicious = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    contract.deny_token(&malicious.address);
+    assert!(contract.is_token_denied(&malicious.address));
+
+    let result = contract.try_whitelist_token(&admin, &malicious.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TokenNotWhitelisted));
+    assert!(!contract.is_token_whitelisted(&malicious.address));
+
+    // Lifting the block allows whitelisting again
+    contract.allow_token(&malicious.address);
+    assert!(!contract.is_token_denied(&malicious.address));
+    contract.whitelist_token(&admin, &malicious.address);
+    assert!(contract.is_token_whitelisted(&malicious.address));
+}
+
+#[test]
+fn test_denied_token_blocks_remittance_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.deny_token(&token.address);
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TokenNotWhitelisted));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+
+    contract.allow_token(&token.address);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Snapshot Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fee_update_does_not_change_existing_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 25);
+
+    contract.update_fee(&1000);
+
+    // The stored fee is a snapshot of the rate at creation
+    assert_eq!(contract.get_remittance(&id).fee, 25);
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_accumulated_fees(), 25);
+
+    // New remittances use the updated rate
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 100);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Tier Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_set_fee_tiers_applies_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    let tiers = soroban_sdk::vec![
+        &env,
+        crate::FeeTier { min_amount: 1000, fee_bps: 200 },
+        crate::FeeTier { min_amount: 10000, fee_bps: 100 },
+    ];
+    contract.set_fee_tiers(&tiers);
+    assert_eq!(contract.get_fee_tiers(), tiers);
+
+    // Below the first tier the platform fee applies
+    let id = contract.create_remittance(&sender, &agent, &500, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 12);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 20);
+
+    let id = contract.create_remittance(&sender, &agent, &20000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 200);
+    assert_eq!(contract.quote_fee(&agent, &20000), 200);
+}
+
+#[test]
+fn test_set_fee_tiers_rejects_invalid_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let out_of_order = soroban_sdk::vec![
+        &env,
+        crate::FeeTier { min_amount: 10000, fee_bps: 100 },
+        crate::FeeTier { min_amount: 1000, fee_bps: 200 },
+    ];
+    let result = contract.try_set_fee_tiers(&out_of_order);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+
+    let duplicate = soroban_sdk::vec![
+        &env,
+        crate::FeeTier { min_amount: 1000, fee_bps: 200 },
+        crate::FeeTier { min_amount: 1000, fee_bps: 100 },
+    ];
+    let result = contract.try_set_fee_tiers(&duplicate);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+
+    let too_high = soroban_sdk::vec![&env, crate::FeeTier { min_amount: 0, fee_bps: 10001 }];
+    let result = contract.try_set_fee_tiers(&too_high);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+
+    assert!(contract.get_fee_tiers().is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Event Verbosity Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_minimal_event_verbosity_emits_single_completion_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    assert_eq!(contract.get_event_verbosity(), crate::EventVerbosity::Full);
+
+    contract.set_event_verbosity(&crate::EventVerbosity::Minimal);
+    assert_eq!(contract.get_event_verbosity(), crate::EventVerbosity::Minimal);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    let count = |topics: (soroban_sdk::Symbol, soroban_sdk::Symbol)| {
+        let topics: soroban_sdk::Vec<soroban_sdk::Val> = topics.into_val(&env);
+        env.events().all().iter().filter(|e| e.1 == topics).count()
+    };
+    assert_eq!(count((symbol_short!("settle"), symbol_short!("complete"))), 1);
+    assert_eq!(count((symbol_short!("remit"), symbol_short!("complete"))), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Explicit Recipient Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_payout_goes_to_explicit_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance_with_recipient(&sender, &agent, &recipient, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).recipient, Some(recipient.clone()));
+
+    contract.confirm_payout(&id);
+
+    // The agent authorized the release but the recipient was paid
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, agent);
+    assert_eq!(get_token_balance(&token, &recipient), 975);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|e| e.1 == (symbol_short!("settle"), symbol_short!("complete")).into_val(&env))
+        .unwrap();
+    let data: (u32, u32, u64, u64, Address, Address, Address, i128, soroban_sdk::BytesN<32>) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.5, recipient);
+}
+
+#[test]
+fn test_remittance_without_recipient_pays_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).recipient, None);
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Initialization Address Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_initialize_rejects_admin_equal_to_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+
+    let result = contract.try_initialize(&token.address, &token.address, &250, &0);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAddress));
+    assert!(!contract.is_initialized());
+
+    // Distinct addresses still initialize normally
+    contract.initialize(&admin, &token.address, &250, &0);
+    assert!(contract.is_initialized());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Last Active Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_last_active_updates_on_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+
+    token.mint(&sender, &10000);
+    assert_eq!(contract.get_agent_last_active(&agent), 0);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_agent_last_active(&agent), 1000);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_agent_last_active(&agent), 5000);
+
+    // Batch settlement counts as activity too
+    env.ledger().with_mut(|li| li.timestamp = 9000);
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let mut entries = soroban_sdk::Vec::new(&env);
+    entries.push_back(crate::BatchSettlementEntry { remittance_id: id });
+    contract.batch_settle_with_netting(&entries);
+    assert_eq!(contract.get_agent_last_active(&agent), 9000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Minimum Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_min_fee_raises_small_bps_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &10, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    contract.set_min_fee(&5);
+    assert_eq!(contract.get_min_fee(), 5);
+
+    // 10 bps of 1000 is 1, raised to the floor
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 5);
+
+    // Above the floor the bps fee stands
+    let id = contract.create_remittance(&sender, &agent, &50000, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 50);
+}
+
+#[test]
+fn test_min_fee_rejects_amount_not_exceeding_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.set_min_fee(&100);
+
+    let result = contract.try_create_remittance(&sender, &agent, &100, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+
+    let id = contract.create_remittance(&sender, &agent, &101, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 100);
+
+    // A zero floor restores the plain bps fee
+    contract.set_min_fee(&0);
+    let id = contract.create_remittance(&sender, &agent, &100, &None);
+    assert_eq!(contract.get_remittance(&id).fee, 2);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Token Migration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_migrate_token_requires_no_outstanding_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let new_token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Must be paused first
+    let result = contract.try_migrate_token(&new_token.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+
+    contract.pause();
+    let result = contract.try_migrate_token(&new_token.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PendingRemittancesExist));
+    assert_eq!(contract.get_usdc_token(), token.address);
+
+    contract.unpause();
+    contract.confirm_payout(&id);
+    contract.pause();
+
+    // Fees earned in the old token must be withdrawn first
+    let result = contract.try_migrate_token(&new_token.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PendingRemittancesExist));
+
+    contract.unpause();
+    contract.withdraw_fees(&admin);
+    contract.pause();
+
+    contract.migrate_token(&new_token.address);
+    assert_eq!(contract.get_usdc_token(), new_token.address);
+}
+
+#[test]
+fn test_migrate_token_refuses_stakes_and_pools_in_old_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let new_token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    contract.whitelist_token(&admin, &token.address);
+    contract.set_unstake_cooldown(&0);
+
+    token.mint(&agent, &5000);
+    contract.stake_agent(&agent, &1000);
+    contract.fund_agent_pool(&agent, &token.address, &500);
+
+    contract.pause();
+    let result = contract.try_migrate_token(&new_token.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PendingRemittancesExist));
+    contract.unpause();
+
+    contract.unstake_agent(&agent, &1000);
+    contract.pause();
+    let result = contract.try_migrate_token(&new_token.address);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PendingRemittancesExist));
+    contract.unpause();
+
+    contract.withdraw_agent_pool(&agent, &token.address, &500);
+    contract.pause();
+    contract.migrate_token(&new_token.address);
+    assert_eq!(contract.get_usdc_token(), new_token.address);
+}
+
+#[test]
+fn test_migrate_token_after_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let new_token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.cancel_remittance(&id);
+
+    contract.pause();
+    contract.migrate_token(&new_token.address);
+    contract.unpause();
+
+    // New remittances escrow the new token
+    new_token.mint(&sender, &5000);
+    contract.create_remittance(&sender, &agent, &2000, &None);
+    let token_client = token::Client::new(&env, &new_token.address);
+    assert_eq!(token_client.balance(&contract.address), 2000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Create And Accept Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_and_accept_escrows_and_accepts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_and_accept(&sender, &agent, &1000, &None);
+
+    // Both parties signed the call
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == sender));
+    assert!(auths.iter().any(|(address, _)| *address == agent));
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Accepted);
+
+    let token_client = token::Client::new(&env, &token.address);
+    assert_eq!(token_client.balance(&contract.address), 1000);
+    assert_eq!(token_client.balance(&sender), 9000);
+
+    contract.confirm_payout(&id);
+    assert_eq!(token_client.balance(&agent), 975);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Running Total Overflow Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_checked_add_i128_reports_overflow() {
+    assert_eq!(crate::checked_add_i128(i128::MAX - 1, 1), Ok(i128::MAX));
+    assert_eq!(
+        crate::checked_add_i128(i128::MAX, 1),
+        Err(crate::ContractError::Overflow)
+    );
+}
+
+#[test]
+fn test_running_totals_near_max_fail_with_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Agent's paid total is one settlement away from overflowing
+    env.as_contract(&contract.address, || {
+        crate::storage::record_agent_completion(&env, &agent, i128::MAX - 10).unwrap();
+    });
+
+    let result = contract.try_confirm_payout(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Overflow));
+
+    // Escrow liability is one remittance away from overflowing
+    env.as_contract(&contract.address, || {
+        crate::storage::add_outstanding_liability(&env, i128::MAX - 1000).unwrap();
+    });
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Overflow));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Enumeration Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_agents_tracks_registration_and_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    let agent_c = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    assert_eq!(contract.get_agents().len(), 0);
+
+    contract.register_agent(&agent_a);
+    contract.register_agent(&agent_b);
+    contract.register_agent(&agent_c);
+    // Re-registering does not duplicate
+    contract.register_agent(&agent_b);
+
+    assert_eq!(
+        contract.get_agents(),
+        soroban_sdk::vec![&env, agent_a.clone(), agent_b.clone(), agent_c.clone()]
+    );
+
+    contract.remove_agent(&agent_b);
+
+    assert_eq!(
+        contract.get_agents(),
+        soroban_sdk::vec![&env, agent_a.clone(), agent_c.clone()]
+    );
+    assert_eq!(contract.get_agent_count(), 2);
+}
+
+#[test]
+fn test_get_agents_paged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    let agent_c = Address::generate(&env);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    contract.register_agent(&agent_a);
+    contract.register_agent(&agent_b);
+    contract.register_agent(&agent_c);
+
+    assert_eq!(
+        contract.get_agents_paged(&0, &2),
+        soroban_sdk::vec![&env, agent_a.clone(), agent_b.clone()]
+    );
+    assert_eq!(contract.get_agents_paged(&2, &2), soroban_sdk::vec![&env, agent_c.clone()]);
+    assert_eq!(contract.get_agents_paged(&3, &2).len(), 0);
+    assert_eq!(contract.get_agents_paged(&1, &u32::MAX).len(), 2);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Wind-Down Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_not_accepting_new_blocks_creation_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert!(contract.is_accepting_new());
+
+    let to_confirm = contract.create_remittance(&sender, &agent, &1000, &None);
+    let to_cancel = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    contract.set_accepting_new(&false);
+    assert!(!contract.is_accepting_new());
+
+    let result = contract.try_create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NotAcceptingRemittances));
+
+    // Existing obligations still settle
+    contract.confirm_payout(&to_confirm);
+    contract.cancel_remittance(&to_cancel);
+    assert_eq!(
+        contract.get_remittance(&to_confirm).status,
+        crate::RemittanceStatus::Completed
+    );
+    assert_eq!(
+        contract.get_remittance(&to_cancel).status,
+        crate::RemittanceStatus::Cancelled
+    );
+
+    contract.set_accepting_new(&true);
+    contract.create_remittance(&sender, &agent, &1000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Remittance Created Event Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_created_event_reports_resolved_fee_bps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // Override the global 250 bps for the settlement token
+    contract.set_token_fee_bps(&token.address, &100);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("created")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, Address, i128, i128, u32, u32, i128) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.3, id);
+    assert_eq!(data.6, 1000);
+    assert_eq!(data.7, 10);
+    assert_eq!(data.8, 100);
+}
+
+#[test]
+fn test_created_event_emitted_for_claimable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"PIN-4821");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+    let id = contract.create_claimable(&sender, &1000, &code_hash, &None);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("created")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, Address, i128, i128, u32, u32, i128) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.3, id);
+    assert_eq!(data.4, sender);
+    // The contract stands in for the agent
+    assert_eq!(data.5, contract.address);
+    assert_eq!(data.6, 1000);
+    assert_eq!(data.7, 25);
+    assert_eq!(data.8, 250);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Partial Fee Withdrawal Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_fees_amount_leaves_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &4000, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(contract.get_accumulated_fees(), 100);
+
+    contract.withdraw_fees_amount(&treasury, &30);
+
+    assert_eq!(get_token_balance(&token, &treasury), 30);
+    assert_eq!(contract.get_accumulated_fees(), 70);
+
+    // The full sweep still takes whatever is left
+    contract.withdraw_fees(&treasury);
+    assert_eq!(get_token_balance(&token, &treasury), 100);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+}
+
+#[test]
+fn test_withdraw_fees_amount_rejects_invalid_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &4000, &None);
+    contract.confirm_payout(&id);
+
+    let result = contract.try_withdraw_fees_amount(&treasury, &101);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoFeesToWithdraw));
+
+    let result = contract.try_withdraw_fees_amount(&treasury, &0);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAmount));
+
+    assert_eq!(contract.get_accumulated_fees(), 100);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Remittance Tag Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_list_remittances_by_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    let payroll_a = contract.create_remittance_with_tag(&sender, &agent, &1000, &None, &7);
+    let untagged = contract.create_remittance(&sender, &agent, &1000, &None);
+    let one_off = contract.create_remittance_with_tag(&sender, &agent, &1000, &None, &3);
+    let payroll_b = contract.create_remittance_with_tag(&sender, &agent, &1000, &None, &7);
+
+    assert_eq!(contract.get_remittance(&payroll_a).tag, 7);
+    assert_eq!(contract.get_remittance(&untagged).tag, 0);
+
+    let payroll = contract.list_remittances_by_tag(&7, &1, &10);
+    assert_eq!(payroll.len(), 2);
+    assert_eq!(payroll.get(0).unwrap().id, payroll_a);
+    assert_eq!(payroll.get(1).unwrap().id, payroll_b);
+
+    let one_offs = contract.list_remittances_by_tag(&3, &1, &10);
+    assert_eq!(one_offs.len(), 1);
+    assert_eq!(one_offs.get(0).unwrap().id, one_off);
+
+    assert_eq!(contract.list_remittances_by_tag(&0, &1, &10).len(), 1);
+
+    // Paging scans a fixed window of IDs
+    let first_page = contract.list_remittances_by_tag(&7, &1, &2);
+    assert_eq!(first_page.len(), 1);
+    assert_eq!(first_page.get(0).unwrap().id, payroll_a);
+    let next_page = contract.list_remittances_by_tag(&7, &3, &2);
+    assert_eq!(next_page.len(), 1);
+    assert_eq!(next_page.get(0).unwrap().id, payroll_b);
+    assert_eq!(contract.list_remittances_by_tag(&7, &5, &2).len(), 0);
+}
+
+#[test]
+fn test_created_event_carries_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    contract.create_remittance_with_tag(&sender, &agent, &1000, &None, &42);
+
+    let event = env.events().all().last().unwrap();
+    let data: (u32, u32, u64, u64, Address, Address, i128, i128, u32, u32, i128) =
+        soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.9, 42);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Contract Balance Shortfall Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_confirm_payout_fails_cleanly_when_contract_is_short() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let drain = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Move escrow out behind the contract's back
+    let token_client = token::Client::new(&env, &token.address);
+    token_client.transfer(&contract.address, &drain, &100);
+
+    let result = contract.try_confirm_payout(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InsufficientContractBalance));
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &agent), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Refund Request Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_refund_request_approved_by_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.request_refund(&id);
+    assert!(contract.is_refund_requested(&id));
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("refund"), symbol_short!("requested")).into_val(&env)
+    );
+
+    contract.approve_refund(&agent, &id);
+
+    // Same terminal state as a sender cancellation
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Cancelled);
+    assert!(!contract.is_refund_requested(&id));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("refund"), symbol_short!("approved")).into_val(&env)
+    );
+}
+
+#[test]
+fn test_refund_request_denied_stays_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.request_refund(&id);
+    contract.deny_refund(&admin, &id);
+
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+    assert!(!contract.is_refund_requested(&id));
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+
+    // Nothing left to approve
+    let result = contract.try_approve_refund(&agent, &id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoRefundRequested));
+
+    // The remittance still settles normally
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_refund_request_rejects_double_request_and_strangers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.request_refund(&id);
+
+    let result = contract.try_request_refund(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::RefundAlreadyRequested));
+
+    let result = contract.try_approve_refund(&stranger, &id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::Unauthorized));
+}
+
+#[test]
+fn test_cancellation_closes_refund_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let single = contract.create_remittance(&sender, &agent, &1000, &None);
+    let batched = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.request_refund(&single);
+    contract.request_refund(&batched);
+
+    contract.cancel_remittance(&single);
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(batched);
+    assert_eq!(contract.batch_cancel(&sender, &ids, &0), 1);
+
+    // Both paths refund once and leave nothing to approve
+    for id in [single, batched] {
+        assert!(!contract.is_refund_requested(&id));
+        let result = contract.try_approve_refund(&agent, &id);
+        assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NoRefundRequested));
+    }
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Label Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_label_set_and_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    assert_eq!(contract.get_agent_label(&agent), None);
+
+    let label = soroban_sdk::String::from_str(&env, "Lagos Ikeja Branch");
+    contract.set_agent_label(&agent, &label);
+    assert_eq!(contract.get_agent_label(&agent), Some(label.clone()));
+
+    // Survives a freeze
+    contract.freeze_agent_inbound(&agent);
+    contract.unfreeze_agent_inbound(&agent);
+    assert_eq!(contract.get_agent_label(&agent), Some(label));
+}
+
+#[test]
+fn test_agent_label_length_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    let max = soroban_sdk::String::from_str(&env, "abcdefghijklmnopqrstuvwxyz012345");
+    contract.set_agent_label(&agent, &max);
+
+    let too_long = soroban_sdk::String::from_str(&env, "abcdefghijklmnopqrstuvwxyz0123456");
+    let result = contract.try_set_agent_label(&agent, &too_long);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidSymbol));
+    assert_eq!(contract.get_agent_label(&agent), Some(max));
+}
+
+#[test]
+fn test_agent_label_cleared_on_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    contract.set_agent_label(&agent, &soroban_sdk::String::from_str(&env, "Accra Central"));
+    contract.remove_agent(&agent);
+    assert_eq!(contract.get_agent_label(&agent), None);
+
+    contract.register_agent(&agent);
+    assert_eq!(contract.get_agent_label(&agent), None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Active Remittance Cap Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_max_active_remittances_caps_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent_a);
+    contract.register_agent(&agent_b);
+
+    token.mint(&sender, &100000);
+
+    contract.set_max_active_remittances(&2);
+    assert_eq!(contract.get_max_active_remittances(), 2);
+
+    // The cap spans agents
+    let first = contract.create_remittance(&sender, &agent_a, &1000, &None);
+    contract.create_remittance(&sender, &agent_b, &1000, &None);
+    assert_eq!(contract.get_active_remittance_count(), 2);
+
+    let result = contract.try_create_remittance(&sender, &agent_a, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TooManyActiveRemittances));
+
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_active_remittance_count(), 1);
+
+    contract.create_remittance(&sender, &agent_a, &1000, &None);
+    assert_eq!(contract.get_active_remittance_count(), 2);
+
+    // Zero lifts the cap
+    contract.set_max_active_remittances(&0);
+    contract.create_remittance(&sender, &agent_a, &1000, &None);
+    assert_eq!(contract.get_active_remittance_count(), 3);
+}
+
+#[test]
+fn test_active_remittance_count_tracks_claimable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    token.mint(&sender, &10000);
+
+    let code = soroban_sdk::Bytes::from_slice(&env, b"claim-code-1234");
+    let code_hash = crate::compute_claim_code_hash(&env, &code, &recipient);
+
+    contract.set_max_active_remittances(&1);
+    let id = contract.create_claimable(&sender, &1000, &code_hash, &None);
+    assert_eq!(contract.get_active_remittance_count(), 1);
+
+    let result = contract.try_create_claimable(&sender, &1000, &code_hash, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::TooManyActiveRemittances));
+
+    contract.claim_remittance(&id, &code, &recipient);
+    assert_eq!(contract.get_active_remittance_count(), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Supported Token List Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_supported_tokens_follows_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let eurc = create_token_contract(&env, &admin);
+    let xlm = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    assert_eq!(contract.get_supported_tokens().len(), 0);
+
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.whitelist_token(&admin, &xlm.address);
+    assert_eq!(
+        contract.get_supported_tokens(),
+        soroban_sdk::vec![&env, eurc.address.clone(), xlm.address.clone()]
+    );
+
+    contract.remove_whitelisted_token(&admin, &eurc.address);
+    assert_eq!(contract.get_supported_tokens(), soroban_sdk::vec![&env, xlm.address.clone()]);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Batch Settlement Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_batch_settle_for_agent_settles_only_that_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let other_agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.register_agent(&other_agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+    contract.set_agent_batch_eligible(&other_agent, &true);
+
+    token.mint(&sender, &100000);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &None);
+    let other = contract.create_remittance(&sender, &other_agent, &1000, &None);
+    let expired = contract.create_remittance(&sender, &agent, &1000, &Some(1500));
+    let second = contract.create_remittance(&sender, &agent, &2000, &None);
+    let third = contract.create_remittance(&sender, &agent, &3000, &None);
+    let gated = contract.create_conditional_remittance(&sender, &agent, &1000, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+
+    // The first page skips the expired remittance and other agents
+    let result = contract.batch_settle_for_agent(&agent, &1, &4);
+    assert_eq!(result.settled_ids, soroban_sdk::vec![&env, first, second]);
+
+    assert_eq!(contract.get_remittance(&first).status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&second).status, crate::RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&third).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&expired).status, crate::RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&other).status, crate::RemittanceStatus::Pending);
+    assert_eq!(get_token_balance(&token, &agent), 975 + 1950);
+
+    // An unmet condition is skipped rather than failing the batch
+    let result = contract.batch_settle_for_agent(&agent, &5, &4);
+    assert_eq!(result.settled_ids, soroban_sdk::vec![&env, third]);
+    assert_eq!(contract.get_remittance(&gated).status, crate::RemittanceStatus::Pending);
+
+    // Nothing left to settle
+    let result = contract.batch_settle_for_agent(&agent, &1, &10);
+    assert_eq!(result.settled_ids.len(), 0);
+
+    let result = contract.try_batch_settle_for_agent(&admin, &1, &10);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AgentNotBatchEligible));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Initialize With Agents Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_initialize_with_agents_registers_all() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize_with_agents(
+        &admin,
+        &token.address,
+        &300,
+        &0,
+        &soroban_sdk::vec![&env, agent_a.clone(), agent_b.clone()],
+    );
+
+    assert!(contract.is_initialized());
+    assert_eq!(contract.get_platform_fee_bps(), 300);
+    assert!(contract.is_agent_registered(&agent_a));
+    assert!(contract.is_agent_registered(&agent_b));
+    assert_eq!(contract.get_agent_count(), 2);
+
+    let result = contract.try_initialize_with_agents(
+        &admin,
+        &token.address,
+        &300,
+        &0,
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::AlreadyInitialized));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Cancellation Reason Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_cancel_with_reason_is_stored_and_emitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.cancel_remittance_with_reason(&id, &2);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(remittance.cancel_reason, 2);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("cancel")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, i128, u32) = soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.3, id);
+    assert_eq!(data.6, 2);
+
+    // Plain cancellation records no reason
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.cancel_remittance(&id);
+    assert_eq!(contract.get_remittance(&id).cancel_reason, 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Validity Band Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_validity_bands_pick_expiry_by_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &100000);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    contract.set_default_validity_seconds(&3600);
+    contract.set_validity_band(&10000, &600);
+    contract.set_validity_band(&1000, &1800);
+
+    let bands = contract.get_validity_bands();
+    assert_eq!(bands.len(), 2);
+    assert_eq!(bands.get(0).unwrap().min_amount, 1000);
+    assert_eq!(bands.get(1).unwrap().min_amount, 10000);
+
+    // Below every band the global default applies
+    let small = contract.create_remittance(&sender, &agent, &500, &None);
+    assert_eq!(contract.get_remittance(&small).expiry, Some(8600));
+
+    let medium = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(contract.get_remittance(&medium).expiry, Some(6800));
+
+    let large = contract.create_remittance(&sender, &agent, &20000, &None);
+    assert_eq!(contract.get_remittance(&large).expiry, Some(5600));
+
+    // An explicit expiry still wins
+    let explicit = contract.create_remittance(&sender, &agent, &20000, &Some(9000));
+    assert_eq!(contract.get_remittance(&explicit).expiry, Some(9000));
+}
+
+#[test]
+fn test_validity_band_replace_and_remove() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    contract.set_validity_band(&1000, &1800);
+    contract.set_validity_band(&1000, &900);
+
+    let bands = contract.get_validity_bands();
+    assert_eq!(bands.len(), 1);
+    assert_eq!(bands.get(0).unwrap().seconds, 900);
+
+    contract.set_validity_band(&1000, &0);
+    assert_eq!(contract.get_validity_bands().len(), 0);
+
+    let result = contract.try_set_validity_band(&-1, &600);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAmount));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Lifetime Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_lifetime_fees_survive_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+    assert_eq!(contract.get_lifetime_fees(), 0);
+
+    let first = contract.create_remittance(&sender, &agent, &4000, &None);
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_accumulated_fees(), 100);
+    assert_eq!(contract.get_lifetime_fees(), 100);
+
+    contract.withdraw_fees(&treasury);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+    assert_eq!(contract.get_lifetime_fees(), 100);
+
+    let second = contract.create_remittance(&sender, &agent, &2000, &None);
+    contract.confirm_payout(&second);
+    assert_eq!(contract.get_accumulated_fees(), 50);
+    assert_eq!(contract.get_lifetime_fees(), 150);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Rejection Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_rejects_pending_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    assert_eq!(get_token_balance(&token, &sender), 9000);
+    contract.request_refund(&id);
+
+    contract.reject_remittance(&id);
+
+    assert!(env.auths().iter().any(|(address, _)| *address == agent));
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+    assert_eq!(contract.get_agent_pending_count(&agent), 0);
+    assert!(!contract.is_refund_requested(&id));
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("remit"), symbol_short!("rejected")).into_val(&env)
+    );
+
+    // A rejected remittance cannot be rejected again
+    let result = contract.try_reject_remittance(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+#[test]
+fn test_reject_remittance_requires_assigned_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // Only the stranger signs, so the agent's authorization is missing
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &stranger,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &contract.address,
+            fn_name: "reject_remittance",
+            args: (id,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    assert!(contract.try_reject_remittance(&id).is_err());
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Sender Allowlist Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_sender_allowlist_disabled_allows_anyone() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert!(!contract.is_sender_allowlist_enabled());
+    assert!(contract.is_sender_allowed(&sender));
+
+    contract.create_remittance(&sender, &agent, &1000, &None);
+}
+
+#[test]
+fn test_sender_allowlist_enabled_restricts_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let corporate = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&corporate, &10000);
+    token.mint(&outsider, &10000);
+
+    contract.set_sender_allowlist_enabled(&true);
+    contract.allow_sender(&corporate);
+
+    assert!(contract.is_sender_allowed(&corporate));
+    assert!(!contract.is_sender_allowed(&outsider));
+
+    contract.create_remittance(&corporate, &agent, &1000, &None);
+
+    let result = contract.try_create_remittance(&outsider, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::SenderNotAllowed));
+
+    contract.disallow_sender(&corporate);
+    let result = contract.try_create_remittance(&corporate, &agent, &1000, &None);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::SenderNotAllowed));
+
+    // Turning the allowlist off reopens creation to everyone
+    contract.set_sender_allowlist_enabled(&false);
+    contract.create_remittance(&outsider, &agent, &1000, &None);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Agent Settlement Nonce Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_agent_settlement_nonce_increments_per_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    assert_eq!(contract.get_agent_settlement_nonce(&agent), 0);
+
+    let completed_nonce = |env: &Env| -> u64 {
+        let event = env
+            .events()
+            .all()
+            .iter()
+            .filter(|e| e.1 == (symbol_short!("remit"), symbol_short!("complete")).into_val(env))
+            .last()
+            .unwrap();
+        let data: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::FromVal::from_val(env, &event.2);
+        soroban_sdk::FromVal::from_val(env, &data.get(6).unwrap())
+    };
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&first);
+    assert_eq!(completed_nonce(&env), 1);
+    assert_eq!(contract.get_agent_settlement_nonce(&agent), 1);
+
+    let second = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&second);
+    assert_eq!(completed_nonce(&env), 2);
+    assert_eq!(contract.get_agent_settlement_nonce(&agent), 2);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Conditional Remittance Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_conditional_remittance_settles_after_oracle_condition() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_oracle(&oracle);
+    assert_eq!(contract.get_oracle(), Some(oracle.clone()));
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_conditional_remittance(&sender, &agent, &1000, &None);
+    assert!(contract.get_remittance(&id).conditional);
+    assert!(!contract.is_condition_met(&id));
+
+    let result = contract.try_confirm_payout(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::ConditionNotMet));
+
+    contract.set_condition(&id, &true);
+    assert!(env.auths().iter().any(|(address, _)| *address == oracle));
+    assert!(contract.is_condition_met(&id));
+
+    contract.confirm_payout(&id);
+    assert_eq!(get_token_balance(&token, &agent), 975);
+}
+
+#[test]
+fn test_set_condition_rejects_plain_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    // No oracle configured yet
+    let result = contract.try_set_condition(&id, &true);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::OracleNotSet));
+
+    contract.set_oracle(&oracle);
+    let result = contract.try_set_condition(&id, &true);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Bulk Remittance Lookup Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_remittances_skips_missing_ids_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let first = contract.create_remittance(&sender, &agent, &1000, &None);
+    let second = contract.create_remittance(&sender, &agent, &2000, &None);
+
+    let found = contract.get_remittances(&soroban_sdk::vec![&env, second, 99, first, 0]);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.get(0).unwrap().id, second);
+    assert_eq!(found.get(1).unwrap().id, first);
+
+    let mut too_many = soroban_sdk::Vec::new(&env);
+    for id in 0..=crate::MAX_BATCH_SIZE as u64 {
+        too_many.push_back(id);
+    }
+    let result = contract.try_get_remittances(&too_many);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidBatchSize));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Max Fee Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_create_with_max_fee_accepts_fee_within_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let capped = contract.create_remittance_with_max_fee(&sender, &agent, &1000, &None, &Some(25));
+    assert_eq!(contract.get_remittance(&capped).fee, 25);
+
+    let uncapped = contract.create_remittance_with_max_fee(&sender, &agent, &1000, &None, &None);
+    assert_eq!(contract.get_remittance(&uncapped).fee, 25);
+}
+
+#[test]
+fn test_create_with_max_fee_rejects_fee_above_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    // The admin raises the fee before the sender's transaction lands
+    contract.update_fee(&500);
+
+    let result = contract.try_create_remittance_with_max_fee(&sender, &agent, &1000, &None, &Some(25));
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+    assert_eq!(get_token_balance(&token, &sender), 10000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Payout Holdback Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_holdback_releases_payout_in_two_steps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_holdback_bps(&2000);
+    assert_eq!(contract.get_holdback_bps(), 2000);
+
+    token.mint(&sender, &10000);
+
+    // Payout is 975; 20% of it (195) is held back
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    assert_eq!(get_token_balance(&token, &agent), 780);
+    assert_eq!(contract.get_holdback(&id), 195);
+    assert_eq!(contract.get_outstanding_liability(), 195);
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::RemittanceStatus::PartiallyReleased
+    );
+
+    // The first confirmation cannot be repeated
+    assert!(contract.try_confirm_payout(&id).is_err());
+
+    contract.release_holdback(&id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_holdback(&id), 0);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
+
+    let result = contract.try_release_holdback(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+}
+
+#[test]
+fn test_zero_holdback_settles_in_one_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
+
+    let result = contract.try_release_holdback(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidStatus));
+
+    let result = contract.try_set_holdback_bps(&10001);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+}
+
+#[test]
+fn test_holdback_applies_to_batch_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_agent_batch_eligible(&agent, &true);
+    contract.set_holdback_bps(&2000);
+
+    token.mint(&sender, &10000);
+
+    let id1 = contract.create_remittance(&sender, &agent, &1000, &None);
+    let id2 = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    let entries = soroban_sdk::vec![
+        &env,
+        crate::BatchSettlementEntry { remittance_id: id1 },
+        crate::BatchSettlementEntry { remittance_id: id2 },
+    ];
+    contract.batch_settle_with_netting(&entries);
+
+    // Each 975 payout keeps 195 back
+    assert_eq!(get_token_balance(&token, &agent), 1560);
+    assert_eq!(contract.get_holdback(&id1), 195);
+    assert_eq!(contract.get_outstanding_liability(), 390);
+    assert_eq!(
+        contract.get_remittance(&id1).status,
+        crate::RemittanceStatus::PartiallyReleased
+    );
+
+    contract.release_holdback(&id1);
+    contract.release_holdback(&id2);
+
+    assert_eq!(get_token_balance(&token, &agent), 1950);
+    assert_eq!(contract.get_outstanding_liability(), 0);
+    assert_eq!(contract.get_remittance(&id2).status, crate::RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_netted_holdback_is_withheld_per_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let party_a = Address::generate(&env);
+    let party_b = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &0, &0);
+    contract.register_agent(&party_a);
+    contract.register_agent(&party_b);
+    contract.set_agent_batch_eligible(&party_a, &true);
+    contract.set_agent_batch_eligible(&party_b, &true);
+
+    token.mint(&party_a, &10000);
+    token.mint(&party_b, &10000);
+
+    // A -> B 1000 and B -> A 900 net to 100 for B
+    let id1 = contract.create_remittance(&party_a, &party_b, &1000, &None);
+    let id2 = contract.create_remittance(&party_b, &party_a, &900, &None);
+    let entries = soroban_sdk::vec![
+        &env,
+        crate::BatchSettlementEntry { remittance_id: id1 },
+        crate::BatchSettlementEntry { remittance_id: id2 },
+    ];
+
+    // B's 500 holdback exceeds the 100 net
+    contract.set_holdback_bps(&5000);
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::NetSettlementValidationFailed));
+    assert_eq!(contract.get_remittance(&id1).status, crate::RemittanceStatus::Pending);
+
+    // Only B's own 50 holdback comes out of B's transfer
+    contract.set_holdback_bps(&500);
+    contract.batch_settle_with_netting(&entries);
+
+    assert_eq!(get_token_balance(&token, &party_b), 9150);
+    assert_eq!(contract.get_holdback(&id1), 50);
+    assert_eq!(contract.get_holdback(&id2), 45);
+    assert_eq!(get_token_balance(&token, &contract.address), 1850);
+}
+
+#[test]
+fn test_holdback_applies_to_pool_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let local = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.whitelist_token(&admin, &local.address);
+    contract.set_fx_rate(&admin, &local.address, &10000);
+    contract.set_holdback_bps(&2000);
+
+    token.mint(&sender, &10000);
+    local.mint(&agent, &5000);
+    contract.fund_agent_pool(&agent, &local.address, &5000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout_from_pool(&id, &local.address, &recipient);
+
+    // The recipient is paid in full; the agent's reimbursement is held back
+    assert_eq!(get_token_balance(&local, &recipient), 975);
+    assert_eq!(get_token_balance(&token, &agent), 780);
+    assert_eq!(contract.get_holdback(&id), 195);
+    assert_eq!(
+        contract.get_remittance(&id).status,
+        crate::RemittanceStatus::PartiallyReleased
+    );
+
+    contract.release_holdback(&id);
+
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(get_token_balance(&token, &recipient), 0);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Completed);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Failed Settlement Attempt Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_attempt_payout_records_failed_attempts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let id = contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+
+    assert!(!contract.attempt_payout(&id));
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("settle"), symbol_short!("failed")).into_val(&env)
+    );
+    let data: (u32, u32, u64, u64, Address, u32, u32) = soroban_sdk::FromVal::from_val(&env, &event.2);
+    assert_eq!(data.5, crate::ContractError::SettlementExpired as u32);
+    assert_eq!(data.6, 1);
+
+    assert!(!contract.attempt_payout(&id));
+    assert_eq!(contract.get_remittance(&id).failed_attempts, 2);
+    assert_eq!(contract.get_remittance(&id).status, crate::RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_attempt_payout_settles_valid_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    token.mint(&sender, &10000);
+
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+
+    assert!(contract.attempt_payout(&id));
+    assert_eq!(get_token_balance(&token, &agent), 975);
+    assert_eq!(contract.get_remittance(&id).failed_attempts, 0);
+
+    // A second attempt is a duplicate and is recorded rather than reverted
+    assert!(!contract.attempt_payout(&id));
+    assert_eq!(contract.get_remittance(&id).failed_attempts, 1);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Keeper Sweep Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_keeper_sweep_refunds_expired_and_pays_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_keeper_reward(&10);
+
+    token.mint(&sender, &10000);
+
+    // Earn 100 in fees to fund keeper rewards
+    let settled = contract.create_remittance(&sender, &agent, &4000, &None);
+    contract.confirm_payout(&settled);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let first = contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+    let second = contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+    let live = contract.create_remittance(&sender, &agent, &1000, &Some(9000));
+    assert_eq!(get_token_balance(&token, &sender), 3000);
+    contract.request_refund(&first);
+
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+
+    let result = contract.keeper_sweep_expired(&keeper, &1, &10);
+    assert_eq!(result.refunded, 2);
+    assert_eq!(result.next_id, 0);
+
+    assert_eq!(get_token_balance(&token, &sender), 5000);
+    assert_eq!(get_token_balance(&token, &keeper), 20);
+    assert_eq!(contract.get_accumulated_fees(), 80);
+    assert_eq!(contract.get_remittance(&first).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_remittance(&second).status, crate::RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_remittance(&live).status, crate::RemittanceStatus::Pending);
+    assert!(!contract.is_refund_requested(&first));
+    assert_eq!(contract.get_agent_pending_count(&agent), 1);
+    assert_eq!(contract.get_outstanding_liability(), 1000);
+
+    // Nothing left to sweep
+    assert_eq!(contract.keeper_sweep_expired(&keeper, &1, &10).refunded, 0);
+}
+
+#[test]
+fn test_keeper_reward_is_limited_to_accumulated_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_keeper_reward(&500);
+
+    token.mint(&sender, &10000);
+
+    let settled = contract.create_remittance(&sender, &agent, &4000, &None);
+    contract.confirm_payout(&settled);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+    contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+    contract.create_remittance(&sender, &agent, &1000, &Some(2000));
+
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+
+    // The first page covers the settled remittance and one expired one
+    let result = contract.keeper_sweep_expired(&keeper, &1, &2);
+    assert_eq!(result.refunded, 1);
+    assert_eq!(result.next_id, 3);
+    assert_eq!(get_token_balance(&token, &keeper), 100);
+    assert_eq!(contract.get_accumulated_fees(), 0);
+
+    // Refunds continue unpaid once fees are exhausted
+    let result = contract.keeper_sweep_expired(&keeper, &result.next_id, &10);
+    assert_eq!(result.refunded, 2);
+    assert_eq!(result.next_id, 0);
+    assert_eq!(get_token_balance(&token, &keeper), 100);
+    assert_eq!(get_token_balance(&token, &sender), 6000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Per-Token Minimum Payout Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_token_min_payout_applies_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    let new_token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+
+    contract.set_min_payout(&100);
+    contract.set_token_min_payout(&token.address, &500);
+    contract.set_token_min_payout(&new_token.address, &2000);
+    assert_eq!(contract.get_token_min_payout(&token.address), Some(500));
+
+    token.mint(&sender, &10000);
+
+    // 390 payout clears the global minimum but not the token's own
+    let small = contract.create_remittance(&sender, &agent, &400, &None);
+    let result = contract.try_confirm_payout(&small);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+    contract.cancel_remittance(&small);
+
+    let large = contract.create_remittance(&sender, &agent, &1000, &None);
+    contract.confirm_payout(&large);
+    contract.withdraw_fees(&admin);
+
+    contract.pause();
+    contract.migrate_token(&new_token.address);
+    contract.unpause();
+
+    new_token.mint(&sender, &10000);
+
+    // The same 975 payout is dust under the new token's threshold
+    let id = contract.create_remittance(&sender, &agent, &1000, &None);
+    let result = contract.try_confirm_payout(&id);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+}
+
+#[test]
+fn test_token_min_payout_falls_back_to_global() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    contract.register_agent(&agent);
+    contract.set_min_payout(&500);
+
+    token.mint(&sender, &10000);
+
+    assert_eq!(contract.get_token_min_payout(&token.address), None);
+
+    let small = contract.create_remittance(&sender, &agent, &400, &None);
+    let result = contract.try_confirm_payout(&small);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::PayoutTooSmall));
+
+    let result = contract.try_set_token_min_payout(&token.address, &-1);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidAmount));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Fee Cap Tests
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_max_allowed_fee_bps_rejects_higher_rates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+    assert_eq!(contract.get_max_allowed_fee_bps(), 10000);
+
+    contract.set_max_allowed_fee_bps(&1000);
+    assert_eq!(contract.get_max_allowed_fee_bps(), 1000);
+
+    let result = contract.try_update_fee(&1500);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+    assert_eq!(contract.get_platform_fee_bps(), 250);
+
+    let result = contract.try_set_token_fee_bps(&token.address, &1500);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+
+    let result = contract.try_schedule_fee_update(&1500, &1000);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+
+    let tiers = soroban_sdk::vec![&env, crate::FeeTier { min_amount: 0, fee_bps: 1500 }];
+    let result = contract.try_set_fee_tiers(&tiers);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+
+    // Rates at the cap are still accepted
+    contract.update_fee(&1000);
+    assert_eq!(contract.get_platform_fee_bps(), 1000);
+}
+
+#[test]
+fn test_max_allowed_fee_bps_cannot_undercut_current_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let result = contract.try_set_max_allowed_fee_bps(&200);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+
+    let result = contract.try_set_max_allowed_fee_bps(&10001);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::InvalidFeeBps));
+}
+
+#[test]
+fn test_max_allowed_fee_bps_cannot_undercut_fee_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+
+    let contract = create_swiftremit_contract(&env);
+    contract.initialize(&admin, &token.address, &250, &0);
+
+    let tiers = soroban_sdk::vec![
+        &env,
+        crate::FeeTier { min_amount: 0, fee_bps: 300 },
+        crate::FeeTier { min_amount: 10000, fee_bps: 800 },
+    ];
+    contract.set_fee_tiers(&tiers);
+
+    let result = contract.try_set_max_allowed_fee_bps(&500);
+    assert_eq!(result.unwrap_err(), Ok(crate::ContractError::FeeAboveCap));
+    assert_eq!(contract.get_max_allowed_fee_bps(), 10000);
+
+    contract.set_max_allowed_fee_bps(&800);
+    assert_eq!(contract.get_max_allowed_fee_bps(), 800);
+}
@@ -9,6 +9,8 @@ use soroban_sdk::{contracttype, Address, String, Vec};
 ///
 /// Remittances progress through these states:
 /// - `Pending`: Initial state after creation, awaiting agent confirmation
+/// - `Accepted`: Agent has committed to servicing the remittance; sender can no longer cancel
+/// - `PartiallyReleased`: Payout confirmed but a holdback awaits `release_holdback`
 /// - `Completed`: Agent has confirmed payout and received funds
 /// - `Cancelled`: Sender has cancelled and received refund
 #[contracttype]
@@ -16,10 +18,46 @@ use soroban_sdk::{contracttype, Address, String, Vec};
 pub enum RemittanceStatus {
     /// Remittance is awaiting agent confirmation
     Pending,
+    /// Agent has accepted the remittance and will service the payout
+    Accepted,
     /// Remittance has been paid out to the agent
     Completed,
     /// Remittance has been cancelled and refunded to sender
     Cancelled,
+    /// Payout was confirmed but part of it is held back until the agent confirms delivery
+    PartiallyReleased,
+}
+
+/// Rounding applied to the fractional part of a basis-point fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Truncate toward zero (default; slightly favors the sender)
+    Down,
+    /// Round any fractional part up
+    Up,
+    /// Round to nearest, with exact halves rounded up
+    HalfUp,
+}
+
+/// How many events a settlement emits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventVerbosity {
+    /// Only `SettlementCompleted` is emitted per settlement
+    Minimal,
+    /// Both `SettlementCompleted` and `RemittanceCompleted` are emitted (default)
+    Full,
+}
+
+/// How new agents may join the network.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistrationMode {
+    /// Only the admin can register agents (default)
+    AdminOnly,
+    /// Agents may register themselves, subject to the registration stake
+    Open,
 }
 
 /// A remittance transaction record.
@@ -43,6 +81,43 @@ pub struct Remittance {
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
     pub expiry: Option<u64>,
+    /// Optional soft deadline (seconds since epoch); settling after it still succeeds but is flagged late
+    pub settle_by: Option<u64>,
+    /// Whether the remittance was settled after its `settle_by` deadline
+    pub late: bool,
+    /// Amount in the quote currency the sender priced the remittance in, if quoted
+    pub quote_amount: Option<i128>,
+    /// Quote currency code (e.g. "MXN") used to derive `amount`, if quoted
+    pub quote_currency: Option<String>,
+    /// Ledger timestamp at which the remittance was created (0 for records migrated from older layouts)
+    pub created_at: u64,
+    /// Third party paid on settlement instead of the agent, if set
+    pub recipient: Option<Address>,
+    /// Client-defined reporting category (0 means untagged)
+    pub tag: u32,
+    /// Client-defined reason the sender cancelled (0 when unspecified or not cancelled)
+    pub cancel_reason: u32,
+    /// Whether settlement waits for the oracle to mark the condition met
+    pub conditional: bool,
+    /// Settlement attempts rejected through `attempt_payout`
+    pub failed_attempts: u32,
+}
+
+impl Remittance {
+    /// Address that receives the payout: the explicit recipient if set, otherwise the agent.
+    pub fn payee(&self) -> Address {
+        self.recipient.clone().unwrap_or(self.agent.clone())
+    }
+}
+
+/// One leg of a split remittance: an agent and the amount routed to them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitLeg {
+    /// Registered agent who will receive this leg's payout
+    pub agent: Address,
+    /// Amount allocated to this leg (in USDC)
+    pub amount: i128,
 }
 
 /// Entry for batch settlement processing.
@@ -61,6 +136,50 @@ pub struct BatchSettlementEntry {
 pub struct BatchSettlementResult {
     /// List of successfully settled remittance IDs
     pub settled_ids: Vec<u64>,
+    /// Per-remittance payout breakdown, in the same order as `settled_ids`
+    pub receipts: Vec<SettlementReceipt>,
+}
+
+/// Outcome of one page of a keeper sweep.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperSweepResult {
+    /// Number of expired remittances refunded
+    pub refunded: u32,
+    /// First remittance ID not yet scanned (0 once the scan has reached the last ID)
+    pub next_id: u64,
+}
+
+/// Outcome of a dry-run batch settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchSimulationResult {
+    /// IDs that pass every batch settlement check
+    pub succeeded: Vec<u64>,
+    /// IDs that would be rejected, with the reason
+    pub failed: Vec<BatchSimulationFailure>,
+}
+
+/// A batch entry that would fail settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchSimulationFailure {
+    /// ID of the rejected remittance
+    pub remittance_id: u64,
+    /// `ContractError` code the real batch would fail with
+    pub error_code: u32,
+}
+
+/// Payout breakdown for one remittance settled in a batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementReceipt {
+    /// ID of the settled remittance
+    pub remittance_id: u64,
+    /// Amount released to the agent (amount minus fee)
+    pub payout_amount: i128,
+    /// Platform fee retained from the remittance
+    pub fee: i128,
 }
 
 /// Result of a settlement simulation.
@@ -78,6 +197,26 @@ pub struct SettlementSimulation {
     pub error_message: Option<u32>,
 }
 
+/// Fee rate applied to remittances of at least `min_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    /// Smallest remittance amount this tier applies to
+    pub min_amount: i128,
+    /// Fee rate in basis points for amounts in this tier
+    pub fee_bps: u32,
+}
+
+/// Default validity applied to remittances of at least `min_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidityBand {
+    /// Smallest remittance amount this band applies to
+    pub min_amount: i128,
+    /// Validity window in seconds for amounts in this band
+    pub seconds: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimit {
@@ -92,3 +231,61 @@ pub struct TransferRecord {
     pub timestamp: u64,
     pub amount: i128,
 }
+
+/// Remaining time-to-live of the contract's critical storage entries.
+///
+/// All TTL values are expressed in ledgers remaining before the entry is
+/// archived. Monitoring should call `bump_ttl` when `min_ttl` gets low.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlStatus {
+    /// Ledger sequence the status was computed at
+    pub ledger: u32,
+    /// Remaining TTL of instance storage (admin and configuration)
+    pub instance_ttl: u32,
+    /// Lowest remaining TTL among the sampled unsettled remittances (u32::MAX if none)
+    pub min_remittance_ttl: u32,
+    /// Number of unsettled remittances included in the sample
+    pub remittances_sampled: u32,
+    /// Lowest remaining TTL across all reported entries
+    pub min_ttl: u32,
+}
+
+/// Ceiling on a sender's cumulative volume over a trailing window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RollingLimit {
+    /// Length of the trailing window in days
+    pub window_days: u32,
+    /// Maximum cumulative amount a sender may send within the window
+    pub limit: i128,
+}
+
+/// Settlement activity counters for an agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentStats {
+    /// Number of remittances the agent has settled
+    pub completed: u64,
+    /// Number of the agent's remittances reclaimed by senders after expiry
+    pub expired: u64,
+    /// Total amount paid out to the agent across settlements
+    pub total_paid: i128,
+}
+
+/// Per-operation pause switches.
+///
+/// Lets operators halt a single class of operation during a partial incident
+/// instead of pausing the whole contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseFlags {
+    /// Blocks remittance creation
+    pub create: bool,
+    /// Blocks payout confirmation and settlement
+    pub confirm: bool,
+    /// Blocks cancellations and expired reclaims
+    pub cancel: bool,
+    /// Blocks fee and pool withdrawals
+    pub withdraw: bool,
+}
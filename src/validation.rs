@@ -5,41 +5,54 @@
 
 use soroban_sdk::{Address, Env};
 
-use crate::{ContractError, is_agent_registered, is_paused, get_remittance, RemittanceStatus};
+use crate::{checked_add_i128, ContractError, get_agent_allowed_tokens, get_liquidity_floor, get_outstanding_liability, get_agent_max_pending, get_agent_pending_count, get_expiry_grace_seconds, is_agent_inbound_frozen, is_agent_registered, is_paused, get_agent_stake, get_total_staked, get_default_validity_seconds, get_validity_bands, get_min_payout, get_stake_threshold, get_pause_flags, get_remittance, get_rolling_limits, get_user_transfers, PauseFlags, RemittanceStatus};
 
 /// Centralized validation module for all API requests.
 /// Validates required fields before controller logic to prevent invalid data
 /// from reaching business logic.
 
-/// Validates that an address is properly formatted and not empty.
+/// Validates an address acting as a party to a contract operation.
 ///
-/// Stellar addresses in Soroban are represented by the Address type,
-/// which is already validated by the SDK, but we check for additional constraints.
+/// Stellar addresses in Soroban are represented by the Address type, which
+/// the SDK already guarantees is well formed. What remains to check is the
+/// role: the contract itself cannot sign as an admin, sender or agent, so
+/// its own address is rejected.
 ///
 /// # Arguments
 ///
+/// * `env` - The contract execution environment
 /// * `address` - Address to validate
 ///
 /// # Returns
 ///
 /// * `Ok(())` - Address is valid
-/// * `Err(ContractError::InvalidAddress)` - Address validation failed
+/// * `Err(ContractError::InvalidAddress)` - Address is the contract itself
+pub fn validate_address(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if *address == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(())
+}
+
+/// Validates an address that funds are paid out to.
 ///
-/// # Notes
+/// Fee withdrawals, payouts and treasury transfers sent to the contract's
+/// own address would never leave it, so that destination is rejected with
+/// its own code rather than the generic `InvalidAddress`.
 ///
-/// The Address type in Soroban SDK is guaranteed to be valid by the runtime.
-/// This function primarily serves as a placeholder for future validation logic
-/// and to make the code more explicit about validation requirements.
-pub fn validate_address(address: &Address) -> Result<(), ContractError> {
-    // The Address type in Soroban SDK is already validated by the runtime.
-    // However, we can add additional checks if needed.
-    // For now, we ensure the address is not a zero/empty address by checking
-    // that it can be properly serialized.
-
-    // In Soroban, the Address type is guaranteed to be valid by the SDK,
-    // so this function primarily serves as a placeholder for future validation logic
-    // and to make the code more explicit about validation requirements.
-
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `address` - Destination address to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - Address can receive funds
+/// * `Err(ContractError::InvalidRecipientAddress)` - Address is the contract itself
+pub fn validate_recipient_address(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if *address == env.current_contract_address() {
+        return Err(ContractError::InvalidRecipientAddress);
+    }
     Ok(())
 }
 
@@ -67,6 +80,94 @@ pub fn validate_agent_registered(env: &Env, agent: &Address) -> Result<(), Contr
     Ok(())
 }
 
+/// Validates that an agent is not frozen for new inbound remittances.
+///
+/// A frozen agent can still settle remittances it already holds, so this
+/// check belongs only on paths that direct new funds to the agent.
+pub fn validate_agent_not_frozen(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    if is_agent_inbound_frozen(env, agent) {
+        return Err(ContractError::AgentFrozen);
+    }
+    Ok(())
+}
+
+/// Validates that an agent has room for `additional` more open remittances.
+pub fn validate_agent_capacity(env: &Env, agent: &Address, additional: u32) -> Result<(), ContractError> {
+    let max_pending = get_agent_max_pending(env, agent);
+    if max_pending > 0 && get_agent_pending_count(env, agent).saturating_add(additional) > max_pending {
+        return Err(ContractError::AgentAtCapacity);
+    }
+    Ok(())
+}
+
+/// Validates that the contract has room for `additional` more open remittances.
+pub fn validate_active_remittance_cap(env: &Env, additional: u32) -> Result<(), ContractError> {
+    let max_active = crate::get_max_active_remittances(env);
+    if max_active > 0 && crate::get_active_remittance_count(env).saturating_add(additional) > max_active {
+        return Err(ContractError::TooManyActiveRemittances);
+    }
+    Ok(())
+}
+
+/// Validates that an agent accepts remittances in `token`.
+///
+/// An agent with no allowed-token list accepts any supported token.
+pub fn validate_agent_accepts_token(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+) -> Result<(), ContractError> {
+    let allowed = get_agent_allowed_tokens(env, agent);
+    if !allowed.is_empty() && !allowed.contains(token) {
+        return Err(ContractError::TokenNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Validates that a token has not been denied by the admin.
+///
+/// The denylist takes precedence over the whitelist. Denied tokens report
+/// `TokenNotWhitelisted`, as they are not accepted for any use.
+pub fn validate_token_not_denied(env: &Env, token: &Address) -> Result<(), ContractError> {
+    if crate::is_token_denied(env, token) {
+        return Err(ContractError::TokenNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Validates a fee tier schedule.
+///
+/// Thresholds must be non-negative and strictly ascending (which also rules
+/// out duplicates), and every rate must stay within the fee cap.
+pub fn validate_fee_tiers(env: &Env, tiers: &soroban_sdk::Vec<crate::FeeTier>) -> Result<(), ContractError> {
+    let mut previous: Option<i128> = None;
+    for tier in tiers.iter() {
+        validate_fee_within_cap(env, tier.fee_bps)?;
+        if tier.min_amount < 0 {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        if let Some(prev) = previous {
+            if tier.min_amount <= prev {
+                return Err(ContractError::InvalidFeeBps);
+            }
+        }
+        previous = Some(tier.min_amount);
+    }
+    Ok(())
+}
+
+/// Validates that a fee rate stays within the admin-configured ceiling.
+///
+/// Rates above 10000 bps fail with `InvalidFeeBps`; valid rates above the
+/// ceiling fail with `FeeAboveCap`.
+pub fn validate_fee_within_cap(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
+    validate_fee_bps(fee_bps)?;
+    if fee_bps > crate::get_max_allowed_fee_bps(env) {
+        return Err(ContractError::FeeAboveCap);
+    }
+    Ok(())
+}
+
 /// Validates that the contract is not paused.
 pub fn validate_not_paused(env: &Env) -> Result<(), ContractError> {
     if is_paused(env) {
@@ -75,6 +176,35 @@ pub fn validate_not_paused(env: &Env) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates that a specific operation is not paused.
+///
+/// `is_set` selects the operation's flag, e.g. `|flags| flags.confirm`.
+pub fn validate_operation_not_paused(
+    env: &Env,
+    is_set: fn(&PauseFlags) -> bool,
+) -> Result<(), ContractError> {
+    if is_set(&get_pause_flags(env)) {
+        return Err(ContractError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Validates that the contract is still accepting new remittances.
+pub fn validate_accepting_new(env: &Env) -> Result<(), ContractError> {
+    if !crate::is_accepting_new(env) {
+        return Err(ContractError::NotAcceptingRemittances);
+    }
+    Ok(())
+}
+
+/// Validates that `sender` may create remittances under the sender allowlist.
+pub fn validate_sender_allowed(env: &Env, sender: &Address) -> Result<(), ContractError> {
+    if crate::is_sender_allowlist_enabled(env) && !crate::is_sender_allowlisted(env, sender) {
+        return Err(ContractError::SenderNotAllowed);
+    }
+    Ok(())
+}
+
 /// Validates that a remittance exists and returns it.
 pub fn validate_remittance_exists(env: &Env, remittance_id: u64) -> Result<crate::Remittance, ContractError> {
     get_remittance(env, remittance_id)
@@ -88,17 +218,46 @@ pub fn validate_remittance_pending(remittance: &crate::Remittance) -> Result<(),
     Ok(())
 }
 
+/// Validates that a remittance can still be settled (pending or accepted by its agent).
+pub fn validate_remittance_settleable(remittance: &crate::Remittance) -> Result<(), ContractError> {
+    match remittance.status {
+        RemittanceStatus::Pending | RemittanceStatus::Accepted => Ok(()),
+        _ => Err(ContractError::InvalidStatus),
+    }
+}
+
+/// Validates that a conditional remittance's oracle condition has been met.
+pub fn validate_condition_met(env: &Env, remittance: &crate::Remittance) -> Result<(), ContractError> {
+    if remittance.conditional && !crate::is_condition_met(env, remittance.id) {
+        return Err(ContractError::ConditionNotMet);
+    }
+    Ok(())
+}
+
 /// Validates that a settlement has not expired.
+///
+/// Settlement stays open for the configured grace window after `expiry`.
 pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result<(), ContractError> {
     if let Some(expiry_time) = expiry {
         let current_time = env.ledger().timestamp();
-        if current_time > expiry_time {
+        if current_time > expiry_time.saturating_add(get_expiry_grace_seconds(env)) {
             return Err(ContractError::SettlementExpired);
         }
     }
     Ok(())
 }
 
+/// Returns whether the current time is past `expiry` but within the grace window.
+pub fn is_in_expiry_grace(env: &Env, expiry: Option<u64>) -> bool {
+    match expiry {
+        Some(expiry_time) => {
+            let now = env.ledger().timestamp();
+            now > expiry_time && now <= expiry_time.saturating_add(get_expiry_grace_seconds(env))
+        }
+        None => false,
+    }
+}
+
 /// Validates that a settlement has not been executed before (duplicate check).
 pub fn validate_no_duplicate_settlement(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
     if crate::has_settlement_hash(env, remittance_id) {
@@ -122,9 +281,14 @@ pub fn validate_initialize_request(
     token: &Address,
     fee_bps: u32,
 ) -> Result<(), ContractError> {
-    validate_address(admin)?;
-    validate_address(token)?;
+    validate_address(env, admin)?;
+    validate_address(env, token)?;
     validate_fee_bps(fee_bps)?;
+
+    // An admin that is also the token contract can never sign admin calls
+    if admin == token {
+        return Err(ContractError::InvalidAddress);
+    }
     
     // Check if already initialized
     if crate::has_admin(env) {
@@ -146,10 +310,20 @@ pub fn validate_create_remittance_request(
     agent: &Address,
     amount: i128,
 ) -> Result<(), ContractError> {
-    validate_address(sender)?;
-    validate_address(agent)?;
+    validate_operation_not_paused(env, |flags| flags.create)?;
+    validate_accepting_new(env)?;
+    validate_address(env, sender)?;
+    validate_address(env, agent)?;
+    validate_sender_allowed(env, sender)?;
     validate_amount(amount)?;
     validate_agent_registered(env, agent)?;
+    validate_agent_not_frozen(env, agent)?;
+    validate_agent_stake(env, agent, amount)?;
+    validate_agent_capacity(env, agent, 1)?;
+    validate_active_remittance_cap(env, 1)?;
+    let token = crate::get_usdc_token(env)?;
+    validate_token_not_denied(env, &token)?;
+    validate_agent_accepts_token(env, agent, &token)?;
     Ok(())
 }
 
@@ -158,23 +332,42 @@ pub fn validate_confirm_payout_request(
     env: &Env,
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
-    validate_not_paused(env)?;
+    validate_operation_not_paused(env, |flags| flags.confirm)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
-    validate_remittance_pending(&remittance)?;
+    validate_remittance_settleable(&remittance)?;
+    validate_condition_met(env, &remittance)?;
     validate_no_duplicate_settlement(env, remittance_id)?;
     validate_settlement_not_expired(env, remittance.expiry)?;
-    validate_address(&remittance.agent)?;
     Ok(remittance)
 }
 
+/// Validates that a remittance is old enough for its sender to cancel it.
+///
+/// Stops senders from spamming agents with rapid create/cancel cycles.
+pub fn validate_cancel_age(env: &Env, remittance: &crate::Remittance) -> Result<(), ContractError> {
+    let min_age = crate::storage::get_min_cancel_age_seconds(env);
+    if min_age == 0 {
+        return Ok(());
+    }
+
+    let age = env.ledger().timestamp().saturating_sub(remittance.created_at);
+    if age < min_age {
+        return Err(ContractError::CancelTooSoon);
+    }
+
+    Ok(())
+}
+
 /// Comprehensive validation for cancel_remittance request.
 pub fn validate_cancel_remittance_request(
     env: &Env,
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
+    validate_operation_not_paused(env, |flags| flags.cancel)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
     validate_remittance_pending(&remittance)?;
-    validate_address(&remittance.sender)?;
+    validate_cancel_age(env, &remittance)?;
+    validate_address(env, &remittance.sender)?;
     Ok(remittance)
 }
 
@@ -183,15 +376,16 @@ pub fn validate_withdraw_fees_request(
     env: &Env,
     to: &Address,
 ) -> Result<i128, ContractError> {
-    validate_address(to)?;
+    validate_operation_not_paused(env, |flags| flags.withdraw)?;
+    validate_recipient_address(env, to)?;
     let fees = crate::get_accumulated_fees(env)?;
     validate_fees_available(fees)?;
     Ok(fees)
 }
 
 /// Comprehensive validation for update_fee request.
-pub fn validate_update_fee_request(fee_bps: u32) -> Result<(), ContractError> {
-    validate_fee_bps(fee_bps)
+pub fn validate_update_fee_request(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
+    validate_fee_within_cap(env, fee_bps)
 }
 
 /// Comprehensive validation for admin operations.
@@ -200,8 +394,8 @@ pub fn validate_admin_operation(
     caller: &Address,
     target: &Address,
 ) -> Result<(), ContractError> {
-    validate_address(caller)?;
-    validate_address(target)?;
+    validate_address(env, caller)?;
+    validate_address(env, target)?;
     crate::require_admin(env, caller)?;
     Ok(())
 }
@@ -228,6 +422,154 @@ pub fn normalize_symbol(env: &Env, symbol: &soroban_sdk::String) -> Result<sorob
     Ok(soroban_sdk::String::from_bytes(env, &bytes))
 }
 
+/// Validates that a new transfer keeps the sender within every rolling window limit.
+///
+/// Sums the sender's recorded transfers that fall inside each configured
+/// trailing window and adds `amount`.
+///
+/// # Returns
+///
+/// * `Ok(())` - Sender stays within all configured windows
+/// * `Err(ContractError::RollingLimitExceeded)` - A window's ceiling would be exceeded
+/// * `Err(ContractError::Overflow)` - Arithmetic overflow while summing volume
+pub fn validate_rolling_limits(env: &Env, sender: &Address, amount: i128) -> Result<(), ContractError> {
+    let limits = get_rolling_limits(env);
+    if limits.is_empty() {
+        return Ok(());
+    }
+
+    let now = env.ledger().timestamp();
+    let transfers = get_user_transfers(env, sender);
+
+    for limit in limits.iter() {
+        let window_seconds = (limit.window_days as u64) * 86400;
+        let mut volume = amount;
+        for record in transfers.iter() {
+            if now.saturating_sub(record.timestamp) < window_seconds {
+                volume = checked_add_i128(volume, record.amount)?;
+            }
+        }
+        if volume > limit.limit {
+            return Err(ContractError::RollingLimitExceeded);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that an admin withdrawal leaves escrow, agent stake and the liquidity floor covered.
+///
+/// Staked collateral sits in the same token balance but belongs to agents,
+/// so it never counts towards the floor.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `balance` - Contract's current settlement token balance
+/// * `withdrawal` - Amount about to leave the contract
+///
+/// # Returns
+///
+/// * `Ok(())` - Remaining balance covers outstanding liability, total stake and the floor
+/// * `Err(ContractError::InsufficientContractBalance)` - Withdrawal would breach the floor
+pub fn validate_liquidity_floor(env: &Env, balance: i128, withdrawal: i128) -> Result<(), ContractError> {
+    let required = get_outstanding_liability(env)
+        .checked_add(get_total_staked(env))
+        .and_then(|required| required.checked_add(get_liquidity_floor(env)))
+        .ok_or(ContractError::Overflow)?;
+    let remaining = balance
+        .checked_sub(withdrawal)
+        .ok_or(ContractError::Overflow)?;
+    if remaining < required {
+        return Err(ContractError::InsufficientContractBalance);
+    }
+    Ok(())
+}
+
+/// Validates that a settlement's net payout meets the configured minimum.
+///
+/// The settlement token's own minimum applies when one is set, otherwise the
+/// global minimum. A minimum of zero disables the check.
+///
+/// # Returns
+///
+/// * `Ok(())` - Payout meets the minimum
+/// * `Err(ContractError::PayoutTooSmall)` - Payout is below the minimum
+pub fn validate_min_payout(env: &Env, payout_amount: i128) -> Result<(), ContractError> {
+    let token = crate::get_usdc_token(env)?;
+    let min_payout = crate::get_token_min_payout(env, &token).unwrap_or_else(|| get_min_payout(env));
+    if min_payout > 0 && payout_amount < min_payout {
+        return Err(ContractError::PayoutTooSmall);
+    }
+    Ok(())
+}
+
+/// Validates that an agent holds a stake when the amount exceeds the stake threshold.
+///
+/// A threshold of zero disables the check.
+///
+/// # Returns
+///
+/// * `Ok(())` - Amount is within the threshold or the agent is staked
+/// * `Err(ContractError::InsufficientStake)` - Agent has no stake for this amount
+pub fn validate_agent_stake(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let threshold = get_stake_threshold(env);
+    if threshold > 0 && amount > threshold && get_agent_stake(env, agent) <= 0 {
+        return Err(ContractError::InsufficientStake);
+    }
+    Ok(())
+}
+
+/// Returns whether settling now would miss the remittance's `settle_by` deadline.
+pub fn is_past_settle_by(env: &Env, remittance: &crate::Remittance) -> bool {
+    match remittance.settle_by {
+        Some(deadline) => env.ledger().timestamp() > deadline,
+        None => false,
+    }
+}
+
+/// Returns the default validity window for a new remittance of `amount`.
+///
+/// The highest validity band whose `min_amount` the amount reaches wins;
+/// amounts below the first band, or an empty band list, fall back to the
+/// global default validity.
+pub fn validity_seconds_for_amount(env: &Env, amount: i128) -> u64 {
+    let mut seconds = None;
+    for band in get_validity_bands(env).iter() {
+        if amount < band.min_amount {
+            break;
+        }
+        seconds = Some(band.seconds);
+    }
+    seconds.unwrap_or_else(|| get_default_validity_seconds(env))
+}
+
+/// Returns the expiry to store for a new remittance.
+///
+/// An explicit expiry is kept as-is. Without one, the default validity
+/// window for `amount` is applied from the current ledger time; a window of
+/// zero leaves the remittance without an expiry.
+pub fn resolve_expiry(
+    env: &Env,
+    expiry: Option<u64>,
+    amount: i128,
+) -> Result<Option<u64>, ContractError> {
+    if expiry.is_some() {
+        return Ok(expiry);
+    }
+
+    let validity = validity_seconds_for_amount(env, amount);
+    if validity == 0 {
+        return Ok(None);
+    }
+
+    env.ledger()
+        .timestamp()
+        .checked_add(validity)
+        .map(Some)
+        .ok_or(ContractError::Overflow)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,9 +578,27 @@ mod tests {
     #[test]
     fn test_validate_valid_address() {
         let env = Env::default();
+        let contract_id = env.register_contract(None, crate::SwiftRemitContract);
         let address = Address::generate(&env);
 
-        assert!(validate_address(&address).is_ok());
+        env.as_contract(&contract_id, || {
+            assert!(validate_address(&env, &address).is_ok());
+            assert!(validate_recipient_address(&env, &address).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_validate_contract_address_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::SwiftRemitContract);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(validate_address(&env, &contract_id), Err(ContractError::InvalidAddress));
+            assert_eq!(
+                validate_recipient_address(&env, &contract_id),
+                Err(ContractError::InvalidRecipientAddress)
+            );
+        });
     }
 
     #[test]